@@ -0,0 +1,68 @@
+//! Generates the tables of known names included by `src/prop/constants.rs` from the lists in `misc`
+//!
+//! Each list has one quoted name per line, written like a rust string literal, and lines starting with `#` are comments
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+/// Same as `hash::fnv1a`, the build script can't use the crate it builds
+fn fnv1a(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0x811C9DC5, |hash, &b| {
+        (hash ^ u32::from(b | 0x20)).wrapping_mul(0x01000193)
+    })
+}
+
+/// Undo the escapes in a name written like a rust string literal
+fn unescape(name: &str) -> String {
+    let mut result = String::new();
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(v @ ('\\' | '"')) => result.push(v),
+                v => panic!("unsupported escape {:?} in {}", v, name),
+            },
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Read the names in a list
+fn read_list(path: &str) -> Vec<String> {
+    println!("cargo:rerun-if-changed={}", path);
+    let list =
+        fs::read_to_string(path).unwrap_or_else(|e| panic!("failed to read {}: {}", path, e));
+    list.lines()
+        .filter(|l| !l.trim_start().starts_with('#'))
+        .filter_map(|l| {
+            let (start, end) = (l.find('"')?, l.rfind('"')?);
+            (end > start).then(|| unescape(&l[start + 1..end]))
+        })
+        .collect()
+}
+
+fn main() {
+    let names = read_list("misc/odf_key_list");
+    let class_labels = read_list("misc/class_label_list");
+
+    let mut hashes: HashMap<u32, &str> = HashMap::new();
+    let mut map = phf_codegen::Map::new();
+    for name in &names {
+        let hash = fnv1a(name.as_bytes());
+        if let Some(other) = hashes.insert(hash, name) {
+            panic!("{} and {} have the same hash {:#010X}", other, name, hash);
+        }
+        map.entry(hash, &format!("{:?}", name));
+    }
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("constants.rs");
+    let code = format!(
+        "pub(crate) static HASHVALUES: ::phf::Map<u32, &'static str> = {};\n\n\
+         pub(crate) const CLASSLABELS: &[&str] = &{:?};\n",
+        map.build(),
+        class_labels
+    );
+    fs::write(&path, code).unwrap_or_else(|e| panic!("failed to write {}: {}", path.display(), e));
+}
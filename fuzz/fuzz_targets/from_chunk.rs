@@ -0,0 +1,62 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libzeroengine::audio_data::AudioData;
+use libzeroengine::fourcc::FourCC;
+use libzeroengine::lvl::Level;
+use libzeroengine::mvs::Movie;
+use libzeroengine::prop::PropertyContainer;
+use libzeroengine::script::Script;
+use libzeroengine::tex::TextureContainer;
+use libzeroengine::ucfb::{ChunkHeader, ChunkLocation, ChunkRef, Endianness};
+
+// The first byte picks the decoder, the rest is the chunk data
+fuzz_target!(|data: &[u8]| {
+    let Some((&decoder, data)) = data.split_first() else {
+        return;
+    };
+    let name = match decoder % 9 {
+        0 => FourCC::SCRIPT,
+        1 => FourCC::MOVIE,
+        2 => FourCC::LEVEL,
+        3 => FourCC::TEXTURE,
+        4 => FourCC::GAME_OBJECT_CLASS,
+        5 => FourCC::EXPLOSION_CLASS,
+        6 => FourCC::ORDNANCE_CLASS,
+        7 => FourCC::WEAPON_CLASS,
+        _ => FourCC::AUDIO_DATA,
+    };
+    let chunk = ChunkRef {
+        header: ChunkHeader {
+            name,
+            size: data.len() as u32,
+            endianness: Endianness::Little,
+        },
+        location: ChunkLocation::default(),
+        data,
+    };
+    match decoder % 9 {
+        0 => {
+            let _ = Script::from_chunk_ref(&chunk);
+        }
+        1 => {
+            let _ = Movie::from_chunk_ref(&chunk);
+        }
+        2 => {
+            let _ = Level::from_chunk_ref(&chunk);
+        }
+        3 => {
+            if let Ok(texture) = TextureContainer::from_chunk_ref(&chunk) {
+                texture.get_formats_dds_vec();
+            }
+        }
+        4..=7 => {
+            if let Ok(properties) = PropertyContainer::from_chunk_ref(&chunk) {
+                properties.get_odf();
+            }
+        }
+        _ => {
+            let _ = AudioData::from_chunk_ref(&chunk);
+        }
+    }
+});
@@ -0,0 +1,33 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libzeroengine::stream::ChunkStream;
+use libzeroengine::ucfb::{UCFBFile, UCFBFileRef};
+use std::io::{Cursor, Read, Seek};
+
+fn walk<R: Read + Seek>(stream: &mut ChunkStream<R>) {
+    while let Some(Ok(entry)) = stream.next() {
+        if entry.header.is_known_container() {
+            walk(&mut stream.subchunks(&entry));
+        } else {
+            let _ = stream.read_body(&entry);
+        }
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut file) = UCFBFile::from_bytes(data) {
+        let _ = file.clone().visit_chunks();
+        file.visit_chunks_lenient();
+        let _ = file.to_bytes();
+    }
+    if let Ok(file) = UCFBFileRef::from_bytes(data) {
+        for chunk in file.chunks {
+            let _ = chunk.subchunks();
+        }
+    }
+    let mut reader = Cursor::new(data);
+    if let Ok(mut stream) = ChunkStream::new(&mut reader) {
+        walk(&mut stream);
+    }
+});
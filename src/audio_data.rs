@@ -1,3 +1,4 @@
+use crate::fourcc::FourCC;
 use crate::ucfb::*;
 /// Object that reperesents a chunk of audio data
 /// INCOMPLETE
@@ -15,10 +16,24 @@ pub enum AudioDataError {
     NotAAudioDataChunk,
 }
 
+impl std::fmt::Display for AudioDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AudioDataError::NotAAudioDataChunk => write!(f, "chunk does not store audio data"),
+        }
+    }
+}
+
+impl std::error::Error for AudioDataError {}
+
 impl AudioData {
     /// Deserialize audio data from chunk
     pub fn from_chunk(chunk: Chunk) -> Result<Self, AudioDataError> {
-        if chunk.header.name.as_bytes() != b"\x5C\xD9\xA0\x23" {
+        Self::from_chunk_ref(&chunk.as_chunk_ref())
+    }
+    /// Deserialize audio data from a borrowed chunk
+    pub fn from_chunk_ref(chunk: &ChunkRef) -> Result<Self, AudioDataError> {
+        if chunk.header.name != FourCC::AUDIO_DATA {
             return Err(AudioDataError::NotAAudioDataChunk);
         }
         Ok(AudioData {})
@@ -0,0 +1,243 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+use crate::hash::NameHash;
+use crate::prop::PropertyKey;
+use crate::ucfb::*;
+
+/// What a hash that couldn't be resolved names
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum HashKind {
+    /// A property key in a class
+    Property,
+    /// A level name
+    Level,
+    /// A chunk name that isn't plain ascii, such as `0x60701F2F`
+    ChunkName,
+}
+
+impl Display for HashKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HashKind::Property => write!(f, "property"),
+            HashKind::Level => write!(f, "level"),
+            HashKind::ChunkName => write!(f, "chunk name"),
+        }
+    }
+}
+
+/// A hash whose name isn't known
+#[derive(Debug, Clone)]
+pub struct UnresolvedHash {
+    /// The hash
+    pub hash: NameHash,
+    /// What the hash names, where it was first found
+    pub kind: HashKind,
+    /// How many times the hash was found
+    pub count: usize,
+}
+
+/// A name found for a hash that couldn't be resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrackedHash {
+    /// The hash
+    pub hash: NameHash,
+    /// A name with this hash, hashes are short so it may not be the original name
+    pub name: String,
+    /// What the hash names
+    pub kind: HashKind,
+}
+
+/// The variants of candidate names that are tried
+#[derive(Debug, Clone)]
+pub struct Variants {
+    /// Added before candidates, candidates are also tried without them
+    pub prefixes: Vec<String>,
+    /// Added after candidates, candidates are also tried without them
+    pub suffixes: Vec<String>,
+    /// Numbers up to this are added after candidates, directly and after `_`
+    pub max_number: Option<u32>,
+    /// Also try every pair of candidates, directly and joined by `_`
+    ///
+    /// The number of names tried grows with the square of the number of candidates
+    pub combine: bool,
+}
+
+impl Default for Variants {
+    fn default() -> Self {
+        Variants {
+            prefixes: vec![],
+            suffixes: vec![],
+            max_number: Some(9),
+            combine: false,
+        }
+    }
+}
+
+/// Hashes that couldn't be resolved in a set of files, and strings from the same files that may be their names
+///
+/// Only the hashes and candidate strings are kept, so a whole game's files can be harvested before cracking
+#[derive(Debug, Clone, Default)]
+pub struct HashHarvest {
+    unresolved: HashMap<NameHash, UnresolvedHash>,
+    /// Candidates by hash, names that only differ in case have the same hash so only the first is kept
+    candidates: HashMap<NameHash, String>,
+}
+
+/// Find the strings lua bytecode stores its string constants as, runs of printable ascii ending with a null
+fn lua_strings(bytecode: &[u8]) -> Vec<&str> {
+    bytecode
+        .split(|b| *b == 0)
+        .filter_map(|run| {
+            // Lengths and other data come before the string, keep the printable end of the run
+            let start = run
+                .iter()
+                .rposition(|b| !b.is_ascii_graphic() && *b != b' ')
+                .map_or(0, |p| p + 1);
+            std::str::from_utf8(&run[start..]).ok()
+        })
+        .filter(|s| s.len() >= 3)
+        .collect()
+}
+
+impl HashHarvest {
+    /// Create an empty harvest
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add the unresolved hashes and candidate strings in a visited file
+    ///
+    /// Hashes are resolved while visiting, so visit with a registry holding every name already known
+    pub fn add_file(&mut self, file: &UCFBFile) {
+        self.add_chunks(&file.chunks);
+    }
+    fn add_chunks(&mut self, chunks: &[Chunk]) {
+        for chunk in chunks {
+            let name = chunk.header.name;
+            if !name.is_readable() && name.known_name().is_none() {
+                self.add_hash(NameHash(name.to_u32_le()), HashKind::ChunkName);
+            }
+            match &chunk.deciphered_chunk {
+                Some(DecipheredChunk::UCFB(v)) => self.add_chunks(&v.chunks),
+                Some(DecipheredChunk::Level(v)) => {
+                    match &v.name {
+                        Some(name) => self.add_words(name),
+                        None => self.add_hash(NameHash(v.name_hash), HashKind::Level),
+                    }
+                    self.add_chunks(&v.chunks);
+                }
+                Some(DecipheredChunk::PropertyContainer(v)) => {
+                    self.add_words(&v.name);
+                    for class in v.class_label.iter().chain(&v.class_parent) {
+                        self.add_words(class);
+                    }
+                    for (key, value) in &v.properties {
+                        if let PropertyKey::Unknown(hash) = key {
+                            self.add_hash(NameHash(*hash), HashKind::Property);
+                        }
+                        // Values name other classes, geometry, textures and so on
+                        self.add_words(value);
+                    }
+                }
+                Some(DecipheredChunk::Script(v)) => {
+                    self.add_words(&v.name);
+                    for string in lua_strings(&v.body) {
+                        self.add_words(string);
+                    }
+                }
+                Some(DecipheredChunk::Texture(v)) => self.add_words(&v.name),
+                _ => {}
+            }
+        }
+    }
+    /// Add a hash to find a name for, such as one from a file this library can't decode
+    pub fn add_hash(&mut self, hash: NameHash, kind: HashKind) {
+        self.unresolved
+            .entry(hash)
+            .or_insert(UnresolvedHash {
+                hash,
+                kind,
+                count: 0,
+            })
+            .count += 1;
+    }
+    /// Add candidates from text, both the whole text and the words in it
+    ///
+    /// Words are split at anything that isn't a letter, digit or `_`, so `rep_inf.msh` gives `rep_inf` and `msh`
+    pub fn add_words(&mut self, text: &str) {
+        let text = text.trim();
+        let words = text.split(|c: char| !c.is_ascii_alphanumeric() && c != '_');
+        for word in std::iter::once(text).chain(words) {
+            if !word.is_empty() && word.len() <= 64 && word.bytes().all(|b| b.is_ascii_graphic()) {
+                self.candidates
+                    .entry(NameHash::new(word))
+                    .or_insert_with(|| word.to_string());
+            }
+        }
+    }
+    /// Get the hashes that couldn't be resolved, the most common first
+    pub fn unresolved(&self) -> Vec<&UnresolvedHash> {
+        let mut unresolved: Vec<&UnresolvedHash> = self.unresolved.values().collect();
+        unresolved.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.hash.cmp(&b.hash)));
+        unresolved
+    }
+    /// Get the candidates, sorted
+    pub fn candidates(&self) -> Vec<&str> {
+        let mut candidates: Vec<&str> = self.candidates.values().map(String::as_str).collect();
+        candidates.sort();
+        candidates
+    }
+    /// Hash the candidates and their variants, returning the names found for unresolved hashes sorted by hash
+    ///
+    /// Candidates are tried before their variants, and the first name found for a hash is kept
+    pub fn crack(&self, variants: &Variants) -> Vec<CrackedHash> {
+        let mut found: BTreeMap<NameHash, String> = BTreeMap::new();
+        let mut try_name = |name: String| {
+            let hash = NameHash::new(&name);
+            if self.unresolved.contains_key(&hash) {
+                found.entry(hash).or_insert(name);
+            }
+        };
+        // Plain candidates first so they win over variants with the same hash
+        let candidates = self.candidates();
+        for candidate in &candidates {
+            try_name(candidate.to_string());
+        }
+        let prefixes: Vec<&str> = std::iter::once("")
+            .chain(variants.prefixes.iter().map(String::as_str))
+            .collect();
+        let suffixes: Vec<&str> = std::iter::once("")
+            .chain(variants.suffixes.iter().map(String::as_str))
+            .collect();
+        for candidate in &candidates {
+            for prefix in &prefixes {
+                for suffix in &suffixes {
+                    let name = format!("{}{}{}", prefix, candidate, suffix);
+                    try_name(name.clone());
+                    if let Some(max_number) = variants.max_number {
+                        for number in 0..=max_number {
+                            try_name(format!("{}{}", name, number));
+                            try_name(format!("{}_{}", name, number));
+                        }
+                    }
+                }
+            }
+        }
+        if variants.combine {
+            for first in &candidates {
+                for second in &candidates {
+                    try_name(format!("{}{}", first, second));
+                    try_name(format!("{}_{}", first, second));
+                }
+            }
+        }
+        found
+            .into_iter()
+            .map(|(hash, name)| CrackedHash {
+                hash,
+                name,
+                kind: self.unresolved[&hash].kind,
+            })
+            .collect()
+    }
+}
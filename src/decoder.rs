@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::diagnostic::{describe_error, Diagnostic, DiagnosticKind};
+use crate::fourcc::FourCC;
+use crate::game::GameVersion;
+use crate::hash::HashDictionary;
+use crate::lvl::Level;
+use crate::mvs::Movie;
+use crate::prop::PropertyContainer;
+use crate::script::Script;
+use crate::tex::TextureContainer;
+use crate::ucfb::*;
+
+/// Something that can turn a chunk into a `DecipheredChunk`
+///
+/// Implement this to teach the library about chunks it doesn't know yet,
+/// closures with the same signature as `decode` can be registered directly
+pub trait ChunkDecoder: Send + Sync {
+    /// Decode the chunk, using the registry to visit any subchunks it contains
+    fn decode(
+        &self,
+        chunk: &Chunk,
+        registry: &DecoderRegistry,
+    ) -> Result<DecipheredChunk, VisitError>;
+}
+
+impl<F> ChunkDecoder for F
+where
+    F: Fn(&Chunk, &DecoderRegistry) -> Result<DecipheredChunk, VisitError> + Send + Sync,
+{
+    fn decode(
+        &self,
+        chunk: &Chunk,
+        registry: &DecoderRegistry,
+    ) -> Result<DecipheredChunk, VisitError> {
+        self(chunk, registry)
+    }
+}
+
+/// Decoder for lua scripts (`scr_`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScriptDecoder;
+
+impl ChunkDecoder for ScriptDecoder {
+    fn decode(&self, chunk: &Chunk, _: &DecoderRegistry) -> Result<DecipheredChunk, VisitError> {
+        Ok(DecipheredChunk::Script(
+            Script::from_chunk_ref(&chunk.as_chunk_ref()).map_err(VisitError::ScriptError)?,
+        ))
+    }
+}
+
+/// Decoder for in-game cutscenes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MovieDecoder;
+
+impl ChunkDecoder for MovieDecoder {
+    fn decode(&self, chunk: &Chunk, _: &DecoderRegistry) -> Result<DecipheredChunk, VisitError> {
+        Ok(DecipheredChunk::Movie(
+            Movie::from_chunk_ref(&chunk.as_chunk_ref()).map_err(VisitError::MovieError)?,
+        ))
+    }
+}
+
+/// Decoder for embedded ucfb files (`ucfb`), visits their subchunks
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UCFBDecoder;
+
+impl ChunkDecoder for UCFBDecoder {
+    fn decode(
+        &self,
+        chunk: &Chunk,
+        registry: &DecoderRegistry,
+    ) -> Result<DecipheredChunk, VisitError> {
+        let mut chunks = chunk.subchunks().map_err(VisitError::UCFBError)?;
+        registry
+            .visit(&mut chunks)
+            .map_err(|e| VisitError::UCFBSubchunkVisitationError(Box::new(e)))?;
+        Ok(DecipheredChunk::UCFB(UCFBFile {
+            header: UCFBHeader {
+                size: chunk.header.size,
+                endianness: chunk.header.endianness,
+            },
+            chunks,
+            game_version: None,
+        }))
+    }
+}
+
+/// Decoder for levels (`lvl_`), visits their subchunks
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LevelDecoder;
+
+impl ChunkDecoder for LevelDecoder {
+    fn decode(
+        &self,
+        chunk: &Chunk,
+        registry: &DecoderRegistry,
+    ) -> Result<DecipheredChunk, VisitError> {
+        let mut level =
+            Level::from_chunk_with(chunk, registry.dictionary()).map_err(VisitError::LevelError)?;
+        registry
+            .visit(&mut level.chunks)
+            .map_err(|e| VisitError::LevelSubchunkVisitationError(Box::new(e)))?;
+        Ok(DecipheredChunk::Level(level))
+    }
+}
+
+/// Decoder for textures (`tex_`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TextureDecoder;
+
+impl ChunkDecoder for TextureDecoder {
+    fn decode(&self, chunk: &Chunk, _: &DecoderRegistry) -> Result<DecipheredChunk, VisitError> {
+        Ok(DecipheredChunk::Texture(
+            TextureContainer::from_chunk_ref(&chunk.as_chunk_ref())
+                .map_err(VisitError::TextureVisitationError)?,
+        ))
+    }
+}
+
+/// Decoder for property containers/classes (`entc`, `expc`, `ordc` and `wpnc`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PropertyContainerDecoder;
+
+impl ChunkDecoder for PropertyContainerDecoder {
+    fn decode(
+        &self,
+        chunk: &Chunk,
+        registry: &DecoderRegistry,
+    ) -> Result<DecipheredChunk, VisitError> {
+        Ok(DecipheredChunk::PropertyContainer(
+            PropertyContainer::from_chunk_ref_with(&chunk.as_chunk_ref(), registry.dictionary())
+                .map_err(VisitError::PropertyContainerVisitError)?,
+        ))
+    }
+}
+
+/// How visitation handles chunks that fail to decode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VisitMode {
+    /// Stop at the first chunk that fails to decode
+    #[default]
+    Strict,
+    /// Leave chunks that fail to decode undeciphered, attach a diagnostic to them and carry on
+    Lenient,
+}
+
+/// Chunk decoders keyed by the identifier of the chunk they decode
+///
+/// `DecoderRegistry::default()` knows about every chunk this library can decode,
+/// `DecoderRegistry::new()` starts out empty
+#[derive(Clone)]
+pub struct DecoderRegistry {
+    decoders: HashMap<FourCC, Arc<dyn ChunkDecoder>>,
+    mode: VisitMode,
+    game_version: GameVersion,
+    dictionary: Arc<HashDictionary>,
+    #[cfg(feature = "parallel")]
+    parallel: bool,
+}
+
+impl DecoderRegistry {
+    /// Create a registry without any decoders
+    pub fn new() -> Self {
+        DecoderRegistry {
+            decoders: HashMap::new(),
+            mode: VisitMode::Strict,
+            game_version: GameVersion::Unknown,
+            dictionary: Arc::new(HashDictionary::new()),
+            #[cfg(feature = "parallel")]
+            parallel: false,
+        }
+    }
+    /// Create a registry with the decoders for every chunk this library knows
+    pub fn with_builtin_decoders() -> Self {
+        let mut registry = Self::new();
+        registry.register(FourCC::SCRIPT, ScriptDecoder);
+        registry.register(FourCC::MOVIE, MovieDecoder);
+        registry.register(FourCC::UCFB, UCFBDecoder);
+        registry.register(FourCC::LEVEL, LevelDecoder);
+        registry.register(FourCC::TEXTURE, TextureDecoder);
+        for name in [
+            FourCC::GAME_OBJECT_CLASS,
+            FourCC::EXPLOSION_CLASS,
+            FourCC::ORDNANCE_CLASS,
+            FourCC::WEAPON_CLASS,
+        ] {
+            registry.register(name, PropertyContainerDecoder);
+        }
+        registry
+    }
+    /// Register a decoder for chunks with the given name, returning the decoder it replaced
+    pub fn register<D: ChunkDecoder + 'static>(
+        &mut self,
+        name: FourCC,
+        decoder: D,
+    ) -> Option<Arc<dyn ChunkDecoder>> {
+        self.decoders.insert(name, Arc::new(decoder))
+    }
+    /// Remove the decoder for chunks with the given name
+    pub fn unregister(&mut self, name: FourCC) -> Option<Arc<dyn ChunkDecoder>> {
+        self.decoders.remove(&name)
+    }
+    /// Get the decoder for chunks with the given name
+    pub fn get(&self, name: FourCC) -> Option<&dyn ChunkDecoder> {
+        self.decoders.get(&name).map(|d| d.as_ref())
+    }
+    /// Get how visitation handles chunks that fail to decode
+    pub fn mode(&self) -> VisitMode {
+        self.mode
+    }
+    /// Set how visitation handles chunks that fail to decode
+    pub fn set_mode(&mut self, mode: VisitMode) {
+        self.mode = mode;
+    }
+    /// Get the game the chunks being decoded come from
+    ///
+    /// Decoders can check this for chunks whose layout differs between games
+    pub fn game_version(&self) -> GameVersion {
+        self.game_version
+    }
+    /// Set the game the chunks being decoded come from, see `GameVersion::detect`
+    pub fn set_game_version(&mut self, game_version: GameVersion) {
+        self.game_version = game_version;
+    }
+    /// Get the dictionary decoders look hashed names up in
+    pub fn dictionary(&self) -> &HashDictionary {
+        &self.dictionary
+    }
+    /// Set the dictionary decoders look hashed names up in, such as one with names loaded from word lists
+    pub fn set_dictionary(&mut self, dictionary: HashDictionary) {
+        self.dictionary = Arc::new(dictionary);
+    }
+    /// Set whether sibling chunks are decoded on rayon's thread pool
+    ///
+    /// The chunks stay in the same order. In strict mode decoding stops at the first error,
+    /// if several chunks fail the one reported may not be the first of them
+    #[cfg(feature = "parallel")]
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = parallel;
+    }
+    /// Decode a single chunk, returning `None` if there is no decoder for it
+    pub fn decode(&self, chunk: &Chunk) -> Result<Option<DecipheredChunk>, VisitError> {
+        match self.get(chunk.header.name) {
+            Some(decoder) => decoder
+                .decode(chunk, self)
+                .map(Some)
+                .map_err(|e| VisitError::AtLocation(chunk.location.clone(), Box::new(e))),
+            None => Ok(None),
+        }
+    }
+    /// Decode a single chunk and store the result and any diagnostics in it
+    pub fn visit_chunk(&self, chunk: &mut Chunk) -> Result<(), VisitError> {
+        chunk.diagnostics.clear();
+        chunk.deciphered_chunk = match self.decode(chunk) {
+            Ok(v) => v,
+            Err(VisitError::AtLocation(location, e)) if self.mode == VisitMode::Lenient => {
+                chunk.diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::DecodeFailed,
+                    location,
+                    message: describe_error(e.as_ref()),
+                });
+                None
+            }
+            Err(e) => return Err(e),
+        };
+        let skipped_parts = chunk
+            .deciphered_chunk
+            .as_ref()
+            .map_or(&[][..], DecipheredChunk::skipped_parts);
+        for part in skipped_parts {
+            chunk.diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::PartSkipped,
+                location: chunk.location.clone(),
+                message: part.clone(),
+            });
+        }
+        Ok(())
+    }
+    /// Try to figure out what the data stored in the chunks is and parse if possible
+    pub fn visit(&self, chunks: &mut [Chunk]) -> Result<(), VisitError> {
+        #[cfg(feature = "parallel")]
+        if self.parallel {
+            // Stops handing out chunks after the first error like the sequential path
+            return chunks
+                .par_iter_mut()
+                .try_for_each(|chunk| self.visit_chunk(chunk));
+        }
+        for chunk in chunks {
+            self.visit_chunk(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for DecoderRegistry {
+    fn default() -> Self {
+        Self::with_builtin_decoders()
+    }
+}
+
+impl Debug for DecoderRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.decoders.keys()).finish()
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::diff::decoded_name;
+use crate::fourcc::FourCC;
+use crate::prop::PropertyKey;
+use crate::ucfb::*;
+
+/// What copies of an asset are compared by
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum AssetContent {
+    /// A class, with its properties sorted
+    Class {
+        name: String,
+        class_label: Option<String>,
+        class_parent: Option<String>,
+        properties: Vec<(PropertyKey, String)>,
+    },
+    /// Any other asset
+    Data(Vec<u8>),
+}
+
+impl AssetContent {
+    fn from_chunk(chunk: &Chunk) -> Option<Self> {
+        match chunk.deciphered_chunk.as_ref()? {
+            DecipheredChunk::UCFB(_) | DecipheredChunk::Level(_) | DecipheredChunk::Custom(_) => {
+                None
+            }
+            DecipheredChunk::PropertyContainer(v) => {
+                let mut properties = v.properties.clone();
+                properties.sort();
+                Some(AssetContent::Class {
+                    name: v.name.clone(),
+                    class_label: v.class_label.clone(),
+                    class_parent: v.class_parent.clone(),
+                    properties,
+                })
+            }
+            _ => Some(AssetContent::Data(chunk.data.to_vec())),
+        }
+    }
+    fn hash(&self, name: FourCC) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        name.hash(&mut hasher);
+        Hash::hash(self, &mut hasher);
+        hasher.finish()
+    }
+}
+
+/// Hash the content of a decoded asset, such as a texture, class or script
+///
+/// Classes are hashed by their properties so the order they are stored in doesn't matter,
+/// other assets by their data. Containers, custom and undecoded chunks aren't assets and give `None`.
+/// Hashes can be compared within a run of the program, but shouldn't be stored
+pub fn content_hash(chunk: &Chunk) -> Option<u64> {
+    AssetContent::from_chunk(chunk).map(|c| c.hash(chunk.header.name))
+}
+
+/// Where a copy of an asset is
+#[derive(Debug, Clone)]
+pub struct Occurrence {
+    /// Name of the file the copy is in, as given to `ContentIndex::add_file`
+    pub file: String,
+    /// Names of the levels the copy is in, outermost first
+    pub levels: Vec<String>,
+    /// Where the copy is in the file
+    pub location: ChunkLocation,
+}
+
+/// An asset stored more than once
+#[derive(Debug, Clone)]
+pub struct Duplicate {
+    /// Chunk name
+    pub name: FourCC,
+    /// The name of the decoded chunk, such as the texture or class name
+    pub decoded_name: Option<String>,
+    /// Hash of the asset content, see `content_hash`
+    pub hash: u64,
+    /// Size of one copy including its chunk header and padding
+    pub size: usize,
+    /// Every copy of the asset, in the order they were added
+    pub occurrences: Vec<Occurrence>,
+}
+
+impl Duplicate {
+    /// Bytes that would be saved by storing the asset once
+    pub fn wasted_bytes(&self) -> usize {
+        self.size * (self.occurrences.len() - 1)
+    }
+    /// Names of the files the asset is in, without repeats
+    pub fn files(&self) -> Vec<&str> {
+        let mut files: Vec<&str> = vec![];
+        for occurrence in &self.occurrences {
+            if !files.contains(&occurrence.file.as_str()) {
+                files.push(&occurrence.file);
+            }
+        }
+        files
+    }
+}
+
+impl Display for Duplicate {
+    /// Write the asset on one line, the occurrences aren't included
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(name) = &self.decoded_name {
+            write!(f, " {}", name)?;
+        }
+        write!(
+            f,
+            " ({} copies, {} bytes wasted)",
+            self.occurrences.len(),
+            self.wasted_bytes()
+        )
+    }
+}
+
+impl Display for Occurrence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file)?;
+        for level in &self.levels {
+            write!(f, ";{}", level)?;
+        }
+        write!(f, " @ {}", self.location)
+    }
+}
+
+/// The assets in a set of files, indexed by content to find the ones stored more than once
+///
+/// Copies are grouped by chunk name, size and `content_hash`, then compared by content so assets
+/// whose hashes collide are kept apart. The content of the first copy of every asset is kept
+#[derive(Debug, Clone, Default)]
+pub struct ContentIndex {
+    assets: HashMap<(FourCC, usize, u64), Vec<(AssetContent, Duplicate)>>,
+}
+
+impl ContentIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add the decoded assets in a visited file, searching inside embedded ucfb files and levels
+    pub fn add_file(&mut self, file_name: &str, file: &UCFBFile) {
+        self.add_chunks(file_name, &[], &file.chunks);
+    }
+    fn add_chunks(&mut self, file_name: &str, levels: &[String], chunks: &[Chunk]) {
+        for chunk in chunks {
+            match &chunk.deciphered_chunk {
+                Some(DecipheredChunk::Level(v)) => {
+                    let mut levels = levels.to_vec();
+                    levels.push(v.display_name());
+                    self.add_chunks(file_name, &levels, &v.chunks);
+                }
+                Some(DecipheredChunk::UCFB(v)) => self.add_chunks(file_name, levels, &v.chunks),
+                _ => self.add_chunk(file_name, levels, chunk),
+            }
+        }
+    }
+    fn add_chunk(&mut self, file_name: &str, levels: &[String], chunk: &Chunk) {
+        let content = match AssetContent::from_chunk(chunk) {
+            Some(v) => v,
+            None => return,
+        };
+        let hash = content.hash(chunk.header.name);
+        let occurrence = Occurrence {
+            file: file_name.to_string(),
+            levels: levels.to_vec(),
+            location: chunk.location.clone(),
+        };
+        let assets = self
+            .assets
+            .entry((chunk.header.name, chunk.data.len(), hash))
+            .or_default();
+        match assets.iter_mut().find(|(c, _)| *c == content) {
+            Some((_, duplicate)) => duplicate.occurrences.push(occurrence),
+            None => assets.push((
+                content,
+                Duplicate {
+                    name: chunk.header.name,
+                    decoded_name: decoded_name(chunk),
+                    hash,
+                    size: 8 + ((chunk.data.len() + 3) & !3),
+                    occurrences: vec![occurrence],
+                },
+            )),
+        }
+    }
+    /// Get the assets stored more than once, the ones wasting the most bytes first
+    pub fn duplicates(&self) -> Vec<&Duplicate> {
+        let mut duplicates: Vec<&Duplicate> = self
+            .assets
+            .values()
+            .flatten()
+            .map(|(_, d)| d)
+            .filter(|d| d.occurrences.len() > 1)
+            .collect();
+        // Ties are sorted by name so the order doesn't depend on hash map order
+        duplicates.sort_by(|a, b| {
+            b.wasted_bytes()
+                .cmp(&a.wasted_bytes())
+                .then_with(|| (a.name, &a.decoded_name).cmp(&(b.name, &b.decoded_name)))
+                .then_with(|| a.hash.cmp(&b.hash))
+        });
+        duplicates
+    }
+}
@@ -0,0 +1,75 @@
+use crate::ucfb::*;
+use std::error::Error;
+
+/// Describe an error followed by everything that caused it
+///
+/// For example `failed to decode texture: texture is corrupt`
+pub fn describe_error(error: &dyn Error) -> String {
+    let mut description = error.to_string();
+    let mut source = error.source();
+    while let Some(e) = source {
+        description = format!("{}: {}", description, e);
+        source = e.source();
+    }
+    description
+}
+
+/// What went wrong with a chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum DiagnosticKind {
+    /// The chunk could not be decoded and was left undeciphered
+    DecodeFailed,
+    /// Part of the chunk could not be decoded and was skipped
+    PartSkipped,
+}
+
+/// A problem found with a chunk while visiting it
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct Diagnostic {
+    /// What went wrong
+    pub kind: DiagnosticKind,
+    /// Where the chunk is
+    pub location: ChunkLocation,
+    /// Description of the problem
+    pub message: String,
+}
+
+/// Every diagnostic attached to a tree of chunks, in the order the chunks appear
+#[derive(Debug, Clone, Default)]
+pub struct VisitReport {
+    /// The diagnostics
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl VisitReport {
+    /// Collect the diagnostics from the chunks and all of their subchunks
+    pub fn from_chunks(chunks: &[Chunk]) -> Self {
+        let mut report = VisitReport::default();
+        report.collect(chunks);
+        report
+    }
+    fn collect(&mut self, chunks: &[Chunk]) {
+        for chunk in chunks {
+            self.diagnostics.extend(chunk.diagnostics.iter().cloned());
+            if let Some(subchunks) = chunk
+                .deciphered_chunk
+                .as_ref()
+                .and_then(DecipheredChunk::subchunks)
+            {
+                self.collect(subchunks);
+            }
+        }
+    }
+    /// Whether nothing went wrong
+    pub fn is_clean(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+    /// Get the chunks that could not be decoded at all
+    pub fn failed(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.kind == DiagnosticKind::DecodeFailed)
+    }
+}
@@ -0,0 +1,275 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
+
+use crate::fourcc::FourCC;
+use crate::prop::{PropertyContainer, PropertyKey};
+use crate::ucfb::*;
+
+/// How a chunk changed between two files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Chunk is only in the new file
+    Added,
+    /// Chunk is only in the old file
+    Removed,
+    /// Chunk is in both files but its contents differ
+    Modified,
+}
+
+/// What changed inside a modified chunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Detail {
+    /// The raw data differs
+    Data {
+        /// Offset of the first byte that differs
+        first_difference: usize,
+        /// Size of the data in the old file
+        old_size: usize,
+        /// Size of the data in the new file
+        new_size: usize,
+    },
+    /// The class label or parent of a property container differs
+    BaseClass {
+        /// Base class in the old file
+        old: String,
+        /// Base class in the new file
+        new: String,
+    },
+    /// Property is only in the new file
+    PropertyAdded {
+        /// Property name
+        key: String,
+        /// Property value
+        value: String,
+    },
+    /// Property is only in the old file
+    PropertyRemoved {
+        /// Property name
+        key: String,
+        /// Property value
+        value: String,
+    },
+    /// Property has a different value
+    PropertyChanged {
+        /// Property name
+        key: String,
+        /// Value in the old file
+        old: String,
+        /// Value in the new file
+        new: String,
+    },
+}
+
+/// A chunk that differs between two files
+#[derive(Debug, Clone)]
+pub struct Change {
+    /// How the chunk changed
+    pub kind: ChangeKind,
+    /// Chunk name
+    pub name: FourCC,
+    /// The name of the decoded chunk, such as the texture or class name
+    pub decoded_name: Option<String>,
+    /// Where the chunk is, in the new file unless it was removed
+    pub location: ChunkLocation,
+    /// What changed, only filled in for modified chunks
+    pub details: Vec<Detail>,
+}
+
+impl Display for ChangeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChangeKind::Added => write!(f, "+"),
+            ChangeKind::Removed => write!(f, "-"),
+            ChangeKind::Modified => write!(f, "~"),
+        }
+    }
+}
+
+impl Display for Detail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Detail::Data {
+                first_difference,
+                old_size,
+                new_size,
+            } => write!(
+                f,
+                "data differs from byte {:#X} ({} -> {} bytes)",
+                first_difference, old_size, new_size
+            ),
+            Detail::BaseClass { old, new } => write!(f, "base class {} -> {}", old, new),
+            Detail::PropertyAdded { key, value } => write!(f, "+ {} = {}", key, value),
+            Detail::PropertyRemoved { key, value } => write!(f, "- {} = {}", key, value),
+            Detail::PropertyChanged { key, old, new } => {
+                write!(f, "~ {} = {} -> {}", key, old, new)
+            }
+        }
+    }
+}
+
+impl Display for Change {
+    /// Write the change on one line, the details aren't included
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.kind, self.location.path)?;
+        if let Some(name) = &self.decoded_name {
+            write!(f, " ({})", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Get the name of a decoded chunk that identifies it among its siblings
+pub fn decoded_name(chunk: &Chunk) -> Option<String> {
+    match chunk.deciphered_chunk.as_ref()? {
+        DecipheredChunk::Script(v) => Some(v.name.clone()),
+        DecipheredChunk::Texture(v) => Some(v.name.clone()),
+        DecipheredChunk::PropertyContainer(v) => Some(v.name.clone()),
+        DecipheredChunk::Level(v) => Some(v.display_name()),
+        _ => None,
+    }
+}
+
+/// Key chunks are matched by, chunks with the same name and decoded name are matched in order
+type MatchKey = (FourCC, Option<String>, usize);
+
+fn match_keys(chunks: &[Chunk]) -> Vec<MatchKey> {
+    let mut counts: HashMap<(FourCC, Option<String>), usize> = HashMap::new();
+    chunks
+        .iter()
+        .map(|c| {
+            let key = (c.header.name, decoded_name(c));
+            let count = counts.entry(key.clone()).or_insert(0);
+            *count += 1;
+            (key.0, key.1, *count - 1)
+        })
+        .collect()
+}
+
+fn base_class(properties: &PropertyContainer) -> String {
+    match (&properties.class_label, &properties.class_parent) {
+        (Some(v), _) | (None, Some(v)) => v.clone(),
+        (None, None) => String::new(),
+    }
+}
+
+fn property_details(old: &PropertyContainer, new: &PropertyContainer) -> Vec<Detail> {
+    let mut details: Vec<Detail> = vec![];
+    if base_class(old) != base_class(new) {
+        details.push(Detail::BaseClass {
+            old: base_class(old),
+            new: base_class(new),
+        });
+    }
+    // Keys can appear more than once, so values are matched by key and then by position
+    let mut values: BTreeMap<&PropertyKey, (Vec<&String>, Vec<&String>)> = BTreeMap::new();
+    for (key, value) in &old.properties {
+        values.entry(key).or_default().0.push(value);
+    }
+    for (key, value) in &new.properties {
+        values.entry(key).or_default().1.push(value);
+    }
+    for (key, (old_values, new_values)) in values {
+        for i in 0..old_values.len().max(new_values.len()) {
+            match (old_values.get(i), new_values.get(i)) {
+                (Some(o), Some(n)) if o != n => details.push(Detail::PropertyChanged {
+                    key: key.to_string(),
+                    old: o.to_string(),
+                    new: n.to_string(),
+                }),
+                (Some(o), None) => details.push(Detail::PropertyRemoved {
+                    key: key.to_string(),
+                    value: o.to_string(),
+                }),
+                (None, Some(n)) => details.push(Detail::PropertyAdded {
+                    key: key.to_string(),
+                    value: n.to_string(),
+                }),
+                _ => {}
+            }
+        }
+    }
+    details
+}
+
+fn data_details(old: &[u8], new: &[u8]) -> Vec<Detail> {
+    if old == new {
+        return vec![];
+    }
+    let first_difference = old
+        .iter()
+        .zip(new)
+        .position(|(o, n)| o != n)
+        .unwrap_or(old.len().min(new.len()));
+    vec![Detail::Data {
+        first_difference,
+        old_size: old.len(),
+        new_size: new.len(),
+    }]
+}
+
+fn change(kind: ChangeKind, chunk: &Chunk, details: Vec<Detail>) -> Change {
+    Change {
+        kind,
+        name: chunk.header.name,
+        decoded_name: decoded_name(chunk),
+        location: chunk.location.clone(),
+        details,
+    }
+}
+
+fn diff_chunk(old: &Chunk, new: &Chunk, changes: &mut Vec<Change>) {
+    let old_decoded = old.deciphered_chunk.as_ref();
+    let new_decoded = new.deciphered_chunk.as_ref();
+    // Containers report the changes to their subchunks instead of themselves
+    if let (Some(o), Some(n)) = (
+        old_decoded.and_then(DecipheredChunk::subchunks),
+        new_decoded.and_then(DecipheredChunk::subchunks),
+    ) {
+        diff_chunks_into(o, n, changes);
+        return;
+    }
+    let details = match (old_decoded, new_decoded) {
+        (
+            Some(DecipheredChunk::PropertyContainer(o)),
+            Some(DecipheredChunk::PropertyContainer(n)),
+        ) => property_details(o, n),
+        _ => data_details(&old.data, &new.data),
+    };
+    if !details.is_empty() {
+        changes.push(change(ChangeKind::Modified, new, details));
+    }
+}
+
+fn diff_chunks_into(old: &[Chunk], new: &[Chunk], changes: &mut Vec<Change>) {
+    let old_keys = match_keys(old);
+    let new_keys = match_keys(new);
+    let new_indices: HashMap<&MatchKey, usize> =
+        new_keys.iter().enumerate().map(|(i, k)| (k, i)).collect();
+    for (chunk, key) in old.iter().zip(&old_keys) {
+        match new_indices.get(key) {
+            Some(&i) => diff_chunk(chunk, &new[i], changes),
+            None => changes.push(change(ChangeKind::Removed, chunk, vec![])),
+        }
+    }
+    let old_keys: HashSet<&MatchKey> = old_keys.iter().collect();
+    for (chunk, key) in new.iter().zip(&new_keys) {
+        if !old_keys.contains(key) {
+            changes.push(change(ChangeKind::Added, chunk, vec![]));
+        }
+    }
+}
+
+/// Find the chunks that differ between two lists of chunks
+///
+/// Chunks are matched by name and decoded name, such as the texture, class or script name,
+/// so visit the chunks first to match by decoded name and compare properties and subchunks
+pub fn diff_chunks(old: &[Chunk], new: &[Chunk]) -> Vec<Change> {
+    let mut changes: Vec<Change> = vec![];
+    diff_chunks_into(old, new, &mut changes);
+    changes
+}
+
+/// Find the chunks that differ between two files, see `diff_chunks`
+pub fn diff(old: &UCFBFile, new: &UCFBFile) -> Vec<Change> {
+    diff_chunks(&old.chunks, &new.chunks)
+}
@@ -1,105 +1,316 @@
-use ddsfile::Dds;
 use image_dds::image_from_dds;
-use libzeroengine::ucfb::{Chunk, DecipheredChunk, UCFBFile};
-use std::{env, fs, path::Path, process::exit};
+use libzeroengine::crack::{HashHarvest, Variants};
+use libzeroengine::decoder::{DecoderRegistry, VisitMode};
+use libzeroengine::dedup::ContentIndex;
+use libzeroengine::diagnostic::describe_error;
+use libzeroengine::diff::diff;
+#[cfg(feature = "serialize")]
+use libzeroengine::export::{to_json_with_external_bodies, ExportError};
+use libzeroengine::hash::HashDictionary;
+use libzeroengine::pack::{file_name_part, Manifest, PackError};
+use libzeroengine::stream::ChunkStream;
+use libzeroengine::ucfb::{Chunk, DecipheredChunk, UCFBError, UCFBFile};
+use std::error::Error;
+use std::io::{Read, Seek};
+use std::{env, fs, fs::File, path::Path, process::exit};
 
-fn handle_chunks(chunks: Vec<Chunk>, prefix: &str) {
+/// Write a file, creating the directory it is in
+fn write_asset(path: &Path, data: &[u8]) -> Result<(), String> {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, data))
+        .map_err(|e| format!("{}: {}", path.display(), describe_error(&e)))
+}
+
+/// Convert a decoded script, texture or movie to files other tools can open
+fn extract_asset(i: usize, chunk: &Chunk, directory: &Path) -> Result<(), String> {
+    match &chunk.deciphered_chunk {
+        Some(DecipheredChunk::Script(v)) => {
+            // Only lua 5.0 bytecode can be converted, older scripts are written as they are
+            let bytecode = match v.lua_version() {
+                Some(0x50) => v
+                    .get_lua_51_bytecode_from_50()
+                    .map_err(|e| format!("failed to convert the lua bytecode: {:?}", e))?,
+                _ => v.body.clone(),
+            };
+            let path = directory.join(format!("{}.{}.luac", i, file_name_part(&v.name)));
+            write_asset(&path, &bytecode)
+        }
+        Some(DecipheredChunk::Texture(v)) => {
+            // The first format is usually the best looking one
+            let format = v
+                .get_formats_dds_vec()
+                .into_iter()
+                .next()
+                .ok_or("no texture format could be read")?;
+            let image = image_from_dds(&format, 0)
+                .map_err(|e| format!("failed to convert the texture: {:?}", e))?;
+            let path = directory.join(format!("{}.{}.tga", i, file_name_part(&v.name)));
+            fs::create_dir_all(directory)
+                .map_err(|e| format!("{}: {}", directory.display(), describe_error(&e)))?;
+            image
+                .save(&path)
+                .map_err(|e| format!("{}: {}", path.display(), e))
+        }
+        Some(DecipheredChunk::Movie(v)) => {
+            for (j, bink) in v.bink_files.iter().enumerate() {
+                write_asset(&directory.join(format!("{}.movie_{}.bik", i, j)), bink)?;
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Extract the assets in the visited chunks, in directories named like the ones `Manifest::unpack` writes
+fn extract_assets(chunks: &[Chunk], directory: &Path, filename: &str) {
     for (i, chunk) in chunks.iter().enumerate() {
-        chunk
-            .clone()
-            .deciphered_chunk
-            .and_then(|c| -> Option<DecipheredChunk> {
-                // TODO: make this print chunk type
-                println!("Found something at chunk #{}", /*c,*/ i);
-                match c {
-                    DecipheredChunk::Script(x) => {
-                        fs::create_dir_all(prefix).unwrap();
-                        fs::write(
-                            format!("{}{}.luac", prefix, x.name),
-                            x.get_lua_51_bytecode_from_50().unwrap(),
-                        )
-                        .unwrap();
-                    }
-                    DecipheredChunk::Movie(x) => {
-                        for (j, movie) in x.bink_files.iter().enumerate() {
-                            fs::create_dir_all(format!("{}/mvs_block_{}/", prefix, i)).unwrap();
-                            fs::write(format!("{}/mvs_block_{}/movie_{}.bik", prefix, i, j), movie)
-                                .unwrap();
-                        }
-                    }
-                    DecipheredChunk::UCFB(x) => {
-                        handle_chunks(x.chunks, format!("{}/ucfb_{}/", prefix, i).as_str());
-                    }
-                    DecipheredChunk::Level(x) => {
-                        handle_chunks(x.chunks, format!("{}/lvl_{}/", prefix, i).as_str());
-                    }
-                    DecipheredChunk::Texture(x) => {
-                        fs::create_dir_all(prefix).unwrap();
-                        let formats: Vec<Dds> = x.get_formats_dds_vec();
-                        /*for format in formats {
-                            let result =
-                                image_from_dds(&format, format.get_num_mipmap_levels()).unwrap();
-                            // TODO: allow user to choose model/image format?
-                            result.save(format!("{}/{}.tga", prefix, x.name)).unwrap();
-                        }*/
-                        // Idk, try the first one
-                        if formats.len() == 0 {
-                            println!("Couldn't find any usable textures for {}, skipping", x.name);
-                            return None;
-                        }
-                        let format = formats.get(0).unwrap();
-                        let result = match image_from_dds(
-                            &format, /*format.get_num_mipmap_levels() -1*/ 0,
-                        ) {
-                            Ok(v) => v,
-                            Err(e) => {
-                                println!("Texture {} failed with error {:?}, skipping", x.name, e);
-                                return None;
-                            }
-                        };
-                        // TODO: allow user to choose model/image format?
-                        result.save(format!("{}/{}.tga", prefix, x.name)).unwrap();
-                    }
-                    DecipheredChunk::PropertyContainer(x) => {
-                        fs::write(format!("{}/{}.odf", prefix, x.name), x.get_odf()).unwrap();
-                    }
-                };
-                None
-            });
+        match &chunk.deciphered_chunk {
+            Some(DecipheredChunk::UCFB(v)) => {
+                extract_assets(&v.chunks, &directory.join(format!("{}.ucfb", i)), filename)
+            }
+            Some(DecipheredChunk::Level(v)) => {
+                let name = format!("{}.{}.lvl", i, file_name_part(&v.display_name()));
+                extract_assets(&v.chunks, &directory.join(name), filename)
+            }
+            _ => {
+                if let Err(e) = extract_asset(i, chunk, directory) {
+                    println!("Error: {} @ {}: {}", filename, chunk.location, e);
+                }
+            }
+        }
     }
 }
 
+fn list_chunks<R: Read + Seek>(stream: &mut ChunkStream<R>, depth: usize) -> Result<(), UCFBError> {
+    while let Some(entry) = stream.next() {
+        let entry = entry?;
+        println!(
+            "{:#010X} {}{} ({} bytes){}",
+            entry.location.offset,
+            "  ".repeat(depth),
+            entry.header.display_name(),
+            entry.header.size,
+            // Hashed names can't be read, so say what they are
+            match entry.header.name.known_name() {
+                Some(v) if !entry.header.name.is_readable() => format!(" [{}]", v),
+                _ => String::new(),
+            }
+        );
+        if entry.header.is_known_container() {
+            list_chunks(&mut stream.subchunks(&entry), depth + 1)?;
+        }
+    }
+    Ok(())
+}
+
+/// Print the error with the file it happened in and exit
+fn exit_with_error(filename: &str, error: &dyn Error) -> ! {
+    println!("Error: {}: {}", filename, describe_error(error));
+    exit(1);
+}
+
+/// Open a file and visit its chunks with the decoders set up for the game it comes from
+fn open_and_visit(filename: &str, registry: &mut DecoderRegistry) -> Result<UCFBFile, UCFBError> {
+    let mut file = UCFBFile::new(filename.to_string())?;
+    registry.set_game_version(file.game_version());
+    // Chunks that fail to decode are reported through diagnostics
+    let _ = file.visit_chunks_with(registry);
+    Ok(file)
+}
+
 fn main() {
     let mut args: Vec<String> = env::args().collect();
     if args.len() == 1 {
+        let program = env::current_exe()
+            .ok()
+            .and_then(|p| Some(p.file_name()?.to_string_lossy().into_owned()))
+            .unwrap_or("dumpucfb".to_string());
         println!(
-            "Usage: {} [ucfb files]",
-            env::current_exe()
-                .unwrap()
-                .file_name()
-                .unwrap()
-                .to_str()
-                .unwrap()
+            "Usage: {} [list] [ucfb files]\n       {0} diff [old ucfb file] [new ucfb file]\n       {0} json [ucfb file] [bodies directory] (needs the serialize feature)\n       {0} pack [manifest] [output ucfb file]\n       {0} dedup [ucfb files]\n       {0} crack [ucfb files]\nAny of these can be preceded by --names [word list] to look up more hashed names",
+            program
         );
         exit(1);
     }
     let mut file: UCFBFile;
     args.remove(0);
-    for filename in args {
-        file = match UCFBFile::new(filename.clone()) {
+    let mut dictionary = HashDictionary::new();
+    while args.len() >= 2 && args[0] == "--names" {
+        if let Err(e) = dictionary.load_file(Path::new(&args[1])) {
+            exit_with_error(&args[1], &e);
+        }
+        args.drain(..2);
+    }
+    if args.is_empty() {
+        println!("Error: no files given");
+        exit(1);
+    }
+    if args[0] == "list" {
+        args.remove(0);
+        for filename in args {
+            println!("{}:", filename);
+            let result =
+                File::open(&filename)
+                    .map_err(UCFBError::IOError)
+                    .and_then(|mut le_file| {
+                        ChunkStream::new(&mut le_file).and_then(|mut s| list_chunks(&mut s, 1))
+                    });
+            if let Err(e) = result {
+                println!("Error: {}", describe_error(&e));
+            }
+        }
+        return;
+    }
+    if args[0] == "pack" {
+        if args.len() != 3 {
+            println!("Error: pack needs a manifest and an output file");
+            exit(1);
+        }
+        let manifest_path = Path::new(&args[1]);
+        let result = Manifest::from_file(manifest_path).and_then(|m| {
+            // Files in the manifest are relative to it
+            m.pack(manifest_path.parent().unwrap_or(Path::new("")))
+        });
+        file = match result {
             Ok(v) => v,
-            Err(e) => panic!("Error: {:?}", e),
+            Err(e) => exit_with_error(&args[1], &e),
         };
-
-        let extract_path = format!(
-            "./{}/",
-            Path::new(filename.as_str())
-                .file_name()
-                .unwrap()
-                .to_str()
-                .unwrap()
+        let result = File::create(&args[2])
+            .map_err(UCFBError::IOError)
+            .and_then(|mut output| file.write(&mut output));
+        if let Err(e) = result {
+            exit_with_error(&args[2], &e);
+        }
+        return;
+    }
+    let mut registry = DecoderRegistry::default();
+    registry.set_mode(VisitMode::Lenient);
+    registry.set_dictionary(dictionary);
+    #[cfg(feature = "parallel")]
+    registry.set_parallel(true);
+    if args[0] == "diff" {
+        if args.len() != 3 {
+            println!("Error: diff needs an old and a new file");
+            exit(1);
+        }
+        let mut files: Vec<UCFBFile> = vec![];
+        for filename in &args[1..] {
+            match open_and_visit(filename, &mut registry) {
+                Ok(v) => files.push(v),
+                Err(e) => exit_with_error(filename, &e),
+            }
+        }
+        for change in diff(&files[0], &files[1]) {
+            println!("{}", change);
+            for detail in change.details {
+                println!("    {}", detail);
+            }
+        }
+        return;
+    }
+    if args[0] == "dedup" {
+        let mut index = ContentIndex::new();
+        for filename in &args[1..] {
+            match open_and_visit(filename, &mut registry) {
+                Ok(file) => index.add_file(filename, &file),
+                Err(e) => exit_with_error(filename, &e),
+            }
+        }
+        for duplicate in index.duplicates() {
+            println!("{}", duplicate);
+            for occurrence in &duplicate.occurrences {
+                println!("    {}", occurrence);
+            }
+        }
+        return;
+    }
+    if args[0] == "crack" {
+        let mut harvest = HashHarvest::new();
+        for filename in &args[1..] {
+            match open_and_visit(filename, &mut registry) {
+                Ok(file) => harvest.add_file(&file),
+                Err(e) => exit_with_error(filename, &e),
+            }
+        }
+        // Written as a word list that can be passed back with --names
+        let cracked = harvest.crack(&Variants::default());
+        for hash in &cracked {
+            println!("{:?} // {} {:#010X}", hash.name, hash.kind, hash.hash.0);
+        }
+        println!(
+            "# {} of {} hashes cracked from {} candidates",
+            cracked.len(),
+            harvest.unresolved().len(),
+            harvest.candidates().len()
         );
-        file.visit_chunks().unwrap();
-        handle_chunks(file.chunks, extract_path.as_str());
+        for hash in harvest.unresolved() {
+            if !cracked.iter().any(|c| c.hash == hash.hash) {
+                println!(
+                    "# {:#010X} {} ({} times)",
+                    hash.hash.0, hash.kind, hash.count
+                );
+            }
+        }
+        return;
+    }
+    #[cfg(feature = "serialize")]
+    if args[0] == "json" {
+        if args.len() < 2 {
+            println!("Error: json needs a file");
+            exit(1);
+        }
+        file = match open_and_visit(&args[1], &mut registry) {
+            Ok(v) => v,
+            Err(e) => exit_with_error(&args[1], &e),
+        };
+        // Binary data is base64 encoded unless a directory is given to write it to
+        let json = match args.get(2) {
+            Some(directory) => fs::create_dir_all(directory)
+                .map_err(ExportError::IOError)
+                .and_then(|_| to_json_with_external_bodies(&file, Path::new(directory)))
+                .and_then(|v| {
+                    serde_json::to_string_pretty(&v).map_err(ExportError::SerializeError)
+                }),
+            None => serde_json::to_string_pretty(&file).map_err(ExportError::SerializeError),
+        };
+        match json {
+            Ok(v) => println!("{}", v),
+            Err(e) => exit_with_error(&args[1], &e),
+        }
+        return;
+    }
+    for filename in args {
+        file = match open_and_visit(&filename, &mut registry) {
+            Ok(v) => v,
+            Err(e) => {
+                println!("Error: {}: {}", filename, describe_error(&e));
+                continue;
+            }
+        };
+        let extract_path = match Path::new(&filename).file_name() {
+            Some(v) => Path::new(".").join(v),
+            None => {
+                println!("Error: {}: not a file", filename);
+                continue;
+            }
+        };
+        println!("{}: {}", filename, file.game_version());
+        for diagnostic in file.report().diagnostics {
+            println!(
+                "{:?}: {} @ {}: {}",
+                diagnostic.kind, filename, diagnostic.location, diagnostic.message
+            );
+        }
+        // Write what's needed to pack the file back up next to the extracted files
+        let manifest_path = extract_path.join("manifest.txt");
+        let result = Manifest::unpack(&file.chunks, &extract_path).and_then(|manifest| {
+            fs::write(&manifest_path, manifest.to_string())
+                .map_err(|e| PackError::IOError(manifest_path.clone(), e))
+        });
+        if let Err(e) = result {
+            println!("Error: {}: {}", filename, describe_error(&e));
+        }
+        // Scripts, textures and movies are also converted to files other tools can open
+        extract_assets(&file.chunks, &extract_path.join("assets"), &filename);
     }
 }
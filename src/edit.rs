@@ -0,0 +1,254 @@
+use std::fmt::Display;
+
+use crate::fourcc::FourCC;
+use crate::lvl::Level;
+use crate::query::{Query, QueryError, QueryNode};
+use crate::ucfb::*;
+
+/// Errors returned while editing a chunk tree
+#[derive(Debug)]
+pub enum EditError {
+    /// Path isn't a valid query
+    InvalidPath(QueryError),
+    /// No chunk matches the path
+    NotFound(String),
+    /// More than one chunk matches the path
+    Ambiguous(String, usize),
+    /// Failure while rebuilding the chunks containing the edited chunk
+    UCFBError(UCFBError),
+}
+
+impl Display for EditError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EditError::InvalidPath(_) => write!(f, "invalid chunk path"),
+            EditError::NotFound(path) => write!(f, "no chunk matches {}", path),
+            EditError::Ambiguous(path, count) => {
+                write!(f, "{} chunks match {}, expected one", count, path)
+            }
+            EditError::UCFBError(_) => write!(f, "failed to rebuild the edited chunks"),
+        }
+    }
+}
+
+impl std::error::Error for EditError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EditError::InvalidPath(e) => Some(e),
+            EditError::UCFBError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A chunk along with the indices leading to it from the root
+#[derive(Clone)]
+struct IndexedChunk<'a> {
+    chunk: &'a Chunk,
+    indices: Vec<usize>,
+}
+
+impl QueryNode for IndexedChunk<'_> {
+    fn header(&self) -> &ChunkHeader {
+        &self.chunk.header
+    }
+    fn children(&self) -> Vec<Self> {
+        QueryNode::children(&self.chunk)
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| {
+                let mut indices = self.indices.clone();
+                indices.push(i);
+                IndexedChunk { chunk, indices }
+            })
+            .collect()
+    }
+    fn has_fields(&self, fields: &[(&str, &str)]) -> bool {
+        self.chunk.has_fields(fields)
+    }
+    fn id(&self) -> usize {
+        self.chunk.id()
+    }
+}
+
+/// Find the indices leading to the only chunk matching the path
+fn resolve(root: FourCC, chunks: &[Chunk], path: &str) -> Result<Vec<usize>, EditError> {
+    let query = Query::new(path).map_err(EditError::InvalidPath)?;
+    let root_chunks: Vec<IndexedChunk> = chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| IndexedChunk {
+            chunk,
+            indices: vec![i],
+        })
+        .collect();
+    let mut matches = query.select_from(root, root_chunks);
+    match matches.len() {
+        0 => Err(EditError::NotFound(path.to_string())),
+        1 => Ok(matches.remove(0).indices),
+        n => Err(EditError::Ambiguous(path.to_string(), n)),
+    }
+}
+
+/// Run the edit on the list containing the chunk at the indices, then rebuild every chunk above it
+fn edit_at<T>(
+    chunks: &mut Vec<Chunk>,
+    indices: &[usize],
+    edit: impl FnOnce(&mut Vec<Chunk>, usize) -> Result<T, EditError>,
+) -> Result<T, EditError> {
+    let (&index, rest) = match indices.split_first() {
+        Some(v) => v,
+        None => return Err(EditError::NotFound(String::new())),
+    };
+    if rest.is_empty() {
+        return edit(chunks, index);
+    }
+    let parent = chunks
+        .get_mut(index)
+        .ok_or(EditError::NotFound(String::new()))?;
+    let subchunks = parent
+        .deciphered_chunk
+        .as_mut()
+        .and_then(DecipheredChunk::subchunks_mut)
+        .ok_or(EditError::NotFound(String::new()))?;
+    let result = edit_at(subchunks, rest, edit)?;
+    parent.rebuild().map_err(EditError::UCFBError)?;
+    Ok(result)
+}
+
+fn edit_path<T>(
+    root: FourCC,
+    chunks: &mut Vec<Chunk>,
+    path: &str,
+    edit: impl FnOnce(&mut Vec<Chunk>, usize) -> Result<T, EditError>,
+) -> Result<T, EditError> {
+    let indices = resolve(root, chunks, path)?;
+    edit_at(chunks, &indices, edit)
+}
+
+fn replace(
+    root: FourCC,
+    chunks: &mut Vec<Chunk>,
+    path: &str,
+    mut chunk: Chunk,
+) -> Result<Chunk, EditError> {
+    edit_path(root, chunks, path, |siblings, i| {
+        // New chunks are written in the byte order of the file
+        chunk
+            .set_endianness(siblings[i].header.endianness)
+            .map_err(EditError::UCFBError)?;
+        Ok(std::mem::replace(&mut siblings[i], chunk))
+    })
+}
+
+fn insert(
+    root: FourCC,
+    chunks: &mut Vec<Chunk>,
+    path: &str,
+    mut chunk: Chunk,
+    after: bool,
+) -> Result<(), EditError> {
+    edit_path(root, chunks, path, |siblings, i| {
+        chunk
+            .set_endianness(siblings[i].header.endianness)
+            .map_err(EditError::UCFBError)?;
+        siblings.insert(if after { i + 1 } else { i }, chunk);
+        Ok(())
+    })
+}
+
+fn remove(root: FourCC, chunks: &mut Vec<Chunk>, path: &str) -> Result<Chunk, EditError> {
+    edit_path(root, chunks, path, |siblings, i| Ok(siblings.remove(i)))
+}
+
+fn rename(
+    root: FourCC,
+    chunks: &mut Vec<Chunk>,
+    path: &str,
+    name: FourCC,
+) -> Result<(), EditError> {
+    edit_path(root, chunks, path, |siblings, i| {
+        let chunk = &mut siblings[i];
+        chunk.rebuild().map_err(EditError::UCFBError)?;
+        chunk.header.name = name;
+        // The chunk was decoded as the old type, so it has to be visited again
+        chunk.deciphered_chunk = None;
+        chunk.diagnostics.clear();
+        Ok(())
+    })
+}
+
+/// Size of the chunks when written out, including headers and padding
+fn written_size(chunks: &[Chunk]) -> Result<u32, EditError> {
+    chunks
+        .iter()
+        .map(|c| 8 + ((u64::from(c.header.size) + 3) & !3))
+        .sum::<u64>()
+        .try_into()
+        .map_err(|_| EditError::UCFBError(UCFBError::ChunkTooLarge))
+}
+
+impl UCFBFile {
+    /// Update the header size and the chunk locations after the chunks have been edited
+    fn edited<T>(&mut self, result: Result<T, EditError>) -> Result<T, EditError> {
+        let result = result?;
+        self.header.size = written_size(&self.chunks)?;
+        self.update_locations();
+        Ok(result)
+    }
+    /// Replace the chunk at the path, returning the old chunk
+    ///
+    /// The path must match exactly one chunk, see `Query`. Only visited ucfb and level chunks can be edited
+    /// inside, and every chunk containing the edited chunk has its data and size rebuilt.
+    /// New chunks are converted to the byte order of the chunks around them, see `Chunk::set_endianness`
+    pub fn replace(&mut self, path: &str, chunk: Chunk) -> Result<Chunk, EditError> {
+        let result = replace(FourCC::UCFB, &mut self.chunks, path, chunk);
+        self.edited(result)
+    }
+    /// Insert a chunk before the chunk at the path
+    pub fn insert_before(&mut self, path: &str, chunk: Chunk) -> Result<(), EditError> {
+        let result = insert(FourCC::UCFB, &mut self.chunks, path, chunk, false);
+        self.edited(result)
+    }
+    /// Insert a chunk after the chunk at the path
+    pub fn insert_after(&mut self, path: &str, chunk: Chunk) -> Result<(), EditError> {
+        let result = insert(FourCC::UCFB, &mut self.chunks, path, chunk, true);
+        self.edited(result)
+    }
+    /// Remove the chunk at the path, returning it
+    pub fn remove(&mut self, path: &str) -> Result<Chunk, EditError> {
+        let result = remove(FourCC::UCFB, &mut self.chunks, path);
+        self.edited(result)
+    }
+    /// Rename the chunk at the path, it will need to be visited again to be decoded as the new type
+    pub fn rename(&mut self, path: &str, name: FourCC) -> Result<(), EditError> {
+        let result = rename(FourCC::UCFB, &mut self.chunks, path, name);
+        self.edited(result)
+    }
+}
+
+impl Level {
+    /// Replace the chunk at the path, returning the old chunk
+    ///
+    /// Paths start at the level, such as `lvl_/tex_[2]`, see `UCFBFile::replace`. The level doesn't know
+    /// where it is stored, so call `UCFBFile::update_locations` on the file containing it afterwards
+    pub fn replace(&mut self, path: &str, chunk: Chunk) -> Result<Chunk, EditError> {
+        replace(FourCC::LEVEL, &mut self.chunks, path, chunk)
+    }
+    /// Insert a chunk before the chunk at the path
+    pub fn insert_before(&mut self, path: &str, chunk: Chunk) -> Result<(), EditError> {
+        insert(FourCC::LEVEL, &mut self.chunks, path, chunk, false)
+    }
+    /// Insert a chunk after the chunk at the path
+    pub fn insert_after(&mut self, path: &str, chunk: Chunk) -> Result<(), EditError> {
+        insert(FourCC::LEVEL, &mut self.chunks, path, chunk, true)
+    }
+    /// Remove the chunk at the path, returning it
+    pub fn remove(&mut self, path: &str) -> Result<Chunk, EditError> {
+        remove(FourCC::LEVEL, &mut self.chunks, path)
+    }
+    /// Rename the chunk at the path, it will need to be visited again to be decoded as the new type
+    pub fn rename(&mut self, path: &str, name: FourCC) -> Result<(), EditError> {
+        rename(FourCC::LEVEL, &mut self.chunks, path, name)
+    }
+}
@@ -0,0 +1,181 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::tex::TextureContainer;
+use crate::ucfb::*;
+
+/// Errors returned while exporting
+#[derive(Debug)]
+pub enum ExportError {
+    /// Value couldn't be serialized
+    SerializeError(serde_json::Error),
+    /// Binary data couldn't be written to its file
+    IOError(std::io::Error),
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportError::SerializeError(_) => write!(f, "failed to serialize"),
+            ExportError::IOError(_) => write!(f, "failed to write binary data"),
+        }
+    }
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::SerializeError(e) => Some(e),
+            ExportError::IOError(e) => Some(e),
+        }
+    }
+}
+
+/// Serialize to JSON with binary data written to files in a directory instead of being base64 encoded
+///
+/// The value is serialized first, then every `{"base64": "..."}` in the output is written to `body_N.bin`
+/// in the directory in the order they appear, and replaced with `{"file": "body_N.bin"}`
+pub fn to_json_with_external_bodies<T: Serialize>(
+    value: &T,
+    directory: &Path,
+) -> Result<Value, ExportError> {
+    let mut json = serde_json::to_value(value).map_err(ExportError::SerializeError)?;
+    let mut count = 0;
+    write_external_bodies(&mut json, directory, &mut count)?;
+    Ok(json)
+}
+
+/// Write the base64 encoded binary data in a JSON value to files, see `to_json_with_external_bodies`
+fn write_external_bodies(
+    json: &mut Value,
+    directory: &Path,
+    count: &mut usize,
+) -> Result<(), ExportError> {
+    match json {
+        Value::Array(v) => {
+            for value in v {
+                write_external_bodies(value, directory, count)?;
+            }
+        }
+        Value::Object(v) => {
+            let body = match (v.len(), v.get("base64")) {
+                (1, Some(Value::String(s))) => STANDARD.decode(s).ok(),
+                _ => None,
+            };
+            if let Some(body) = body {
+                let file = format!("body_{}.bin", count);
+                *count += 1;
+                std::fs::write(directory.join(&file), body).map_err(ExportError::IOError)?;
+                v.clear();
+                v.insert("file".to_string(), Value::String(file));
+                return Ok(());
+            }
+            for value in v.values_mut() {
+                write_external_bodies(value, directory, count)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Binary data, serialized as `{"base64": "..."}`
+struct Bytes<'a>(&'a [u8]);
+
+impl Serialize for Bytes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Bytes", 1)?;
+        state.serialize_field("base64", &STANDARD.encode(self.0))?;
+        state.end()
+    }
+}
+
+/// Serialize binary data, see `Bytes`
+pub(crate) fn serialize_bytes<S: Serializer>(
+    bytes: &[u8],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    Bytes(bytes).serialize(serializer)
+}
+
+/// Serialize a list of binary data, see `Bytes`
+pub(crate) fn serialize_byte_lists<S: Serializer>(
+    lists: &[Vec<u8>],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(lists.iter().map(|v| Bytes(v)))
+}
+
+/// Chunks decoded outside this library can't be serialized, so only their presence is recorded
+pub(crate) fn serialize_custom<S: Serializer, T>(_: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_unit()
+}
+
+/// Whether a decoded chunk holds everything in the chunk data, so the data doesn't need to be serialized too
+///
+/// Textures and movies only keep the parts they could read, such as the first mipmap or the bink files,
+/// so their data is always serialized
+fn holds_data(deciphered_chunk: &DecipheredChunk) -> bool {
+    match deciphered_chunk {
+        DecipheredChunk::Script(_) => true,
+        v => v.subchunks().is_some(),
+    }
+}
+
+impl Serialize for Chunk {
+    /// Serialize the chunk, leaving out the data of chunks whose decoded form holds it,
+    /// such as the subchunks of levels or the bytecode of scripts
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let holds_data = self.deciphered_chunk.as_ref().is_some_and(holds_data);
+        let mut state = serializer.serialize_struct("Chunk", 5)?;
+        state.serialize_field("header", &self.header)?;
+        state.serialize_field("location", &self.location)?;
+        if holds_data {
+            state.skip_field("data")?;
+        } else {
+            state.serialize_field("data", &Bytes(&self.data))?;
+        }
+        state.serialize_field("deciphered_chunk", &self.deciphered_chunk)?;
+        state.serialize_field("diagnostics", &self.diagnostics)?;
+        state.end()
+    }
+}
+
+/// A texture format, see `TextureContainer`
+#[derive(Serialize)]
+struct TextureFormat<'a> {
+    format: Option<String>,
+    width: u32,
+    height: u32,
+    depth: Option<u32>,
+    mip_map_count: Option<u32>,
+    data: Bytes<'a>,
+}
+
+impl Serialize for TextureContainer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let formats = self.get_formats_dds_vec();
+        let formats: Vec<TextureFormat> = formats
+            .iter()
+            .map(|dds| TextureFormat {
+                format: dds.get_d3d_format().map(|f| format!("{:?}", f)),
+                width: dds.header.width,
+                height: dds.header.height,
+                depth: dds.header.depth,
+                mip_map_count: dds.header.mip_map_count,
+                data: Bytes(&dds.data),
+            })
+            .collect();
+        let mut state = serializer.serialize_struct("TextureContainer", 3)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("formats", &formats)?;
+        state.serialize_field("skipped_formats", &self.skipped_formats)?;
+        state.end()
+    }
+}
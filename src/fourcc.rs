@@ -0,0 +1,159 @@
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+use crate::ucfb::UCFBError;
+
+/// Four byte identifier that names a ucfb chunk
+///
+/// Most identifiers are ascii, such as `lvl_`, but some are hashes that can't be printed
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct FourCC(pub [u8; 4]);
+
+impl FourCC {
+    /// ucfb file (`ucfb`)
+    pub const UCFB: FourCC = FourCC(*b"ucfb");
+    /// Level (`lvl_`)
+    pub const LEVEL: FourCC = FourCC(*b"lvl_");
+    /// Lua script (`scr_`)
+    pub const SCRIPT: FourCC = FourCC(*b"scr_");
+    /// Texture (`tex_`)
+    pub const TEXTURE: FourCC = FourCC(*b"tex_");
+    /// Format of a texture (`FMT_`)
+    pub const TEXTURE_FORMAT: FourCC = FourCC(*b"FMT_");
+    /// Face of a texture format (`FACE`)
+    pub const FACE: FourCC = FourCC(*b"FACE");
+    /// Mipmap level of a texture face (`LVL_`)
+    pub const MIP_LEVEL: FourCC = FourCC(*b"LVL_");
+    /// Game object class (`entc`)
+    pub const GAME_OBJECT_CLASS: FourCC = FourCC(*b"entc");
+    /// Explosion class (`expc`)
+    pub const EXPLOSION_CLASS: FourCC = FourCC(*b"expc");
+    /// Ordnance class (`ordc`)
+    pub const ORDNANCE_CLASS: FourCC = FourCC(*b"ordc");
+    /// Weapon class (`wpnc`)
+    pub const WEAPON_CLASS: FourCC = FourCC(*b"wpnc");
+    /// Name of the containing chunk (`NAME`)
+    pub const NAME: FourCC = FourCC(*b"NAME");
+    /// Information about the containing chunk (`INFO`)
+    pub const INFO: FourCC = FourCC(*b"INFO");
+    /// Data of the containing chunk (`BODY`)
+    pub const BODY: FourCC = FourCC(*b"BODY");
+    /// Base class of a property container (`BASE`)
+    pub const BASE: FourCC = FourCC(*b"BASE");
+    /// Name of a property container (`TYPE`)
+    pub const TYPE: FourCC = FourCC(*b"TYPE");
+    /// Property of a property container (`PROP`)
+    pub const PROPERTY: FourCC = FourCC(*b"PROP");
+    /// In-game cutscenes, this is a hash
+    pub const MOVIE: FourCC = FourCC([0x60, 0x70, 0x1F, 0x2F]);
+    /// Audio data, this is a hash
+    pub const AUDIO_DATA: FourCC = FourCC([0x5C, 0xD9, 0xA0, 0x23]);
+
+    /// Every chunk identifier this library knows, along with a description
+    pub const KNOWN: [(FourCC, &'static str); 19] = [
+        (FourCC::UCFB, "ucfb file"),
+        (FourCC::LEVEL, "level"),
+        (FourCC::SCRIPT, "lua script"),
+        (FourCC::TEXTURE, "texture"),
+        (FourCC::TEXTURE_FORMAT, "texture format"),
+        (FourCC::FACE, "texture face"),
+        (FourCC::MIP_LEVEL, "mipmap level"),
+        (FourCC::GAME_OBJECT_CLASS, "game object class"),
+        (FourCC::EXPLOSION_CLASS, "explosion class"),
+        (FourCC::ORDNANCE_CLASS, "ordnance class"),
+        (FourCC::WEAPON_CLASS, "weapon class"),
+        (FourCC::NAME, "name"),
+        (FourCC::INFO, "info"),
+        (FourCC::BODY, "body"),
+        (FourCC::BASE, "base class"),
+        (FourCC::TYPE, "class name"),
+        (FourCC::PROPERTY, "property"),
+        (FourCC::MOVIE, "movie"),
+        (FourCC::AUDIO_DATA, "audio data"),
+    ];
+
+    /// Create an identifier from the bytes as they are stored in the file
+    pub const fn new(bytes: [u8; 4]) -> Self {
+        FourCC(bytes)
+    }
+    /// Create an identifier from a little endian number, which is how hashed identifiers are stored
+    pub const fn from_u32_le(value: u32) -> Self {
+        FourCC(value.to_le_bytes())
+    }
+    /// Get the bytes as they are stored in the file
+    pub fn as_bytes(&self) -> &[u8; 4] {
+        &self.0
+    }
+    /// Get the identifier as a little endian number
+    pub fn to_u32_le(&self) -> u32 {
+        u32::from_le_bytes(self.0)
+    }
+    /// Whether the identifier is printable ascii that can be used in chunk paths as-is
+    pub fn is_readable(&self) -> bool {
+        self.0
+            .iter()
+            .all(|&c| c.is_ascii_graphic() && !b"/[]".contains(&c))
+    }
+    /// Get a description of the identifier if it is known, this also works for hashed identifiers
+    pub fn known_name(&self) -> Option<&'static str> {
+        FourCC::KNOWN
+            .iter()
+            .find(|(id, _)| id == self)
+            .map(|(_, name)| *name)
+    }
+}
+
+impl From<[u8; 4]> for FourCC {
+    fn from(bytes: [u8; 4]) -> Self {
+        FourCC(bytes)
+    }
+}
+
+impl FromStr for FourCC {
+    type Err = UCFBError;
+
+    /// Parse an identifier written the way `Display` writes it, either as ascii or as hex
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(hex) = s.strip_prefix("0x") {
+            if hex.len() == 8 {
+                let value =
+                    u32::from_str_radix(hex, 16).map_err(|_| UCFBError::InvalidChunkName)?;
+                // The hex is written in the order the bytes are stored in
+                return Ok(FourCC(value.to_be_bytes()));
+            }
+        }
+        let bytes: [u8; 4] = s
+            .as_bytes()
+            .try_into()
+            .map_err(|_| UCFBError::InvalidChunkName)?;
+        Ok(FourCC(bytes))
+    }
+}
+
+impl Display for FourCC {
+    /// Readable identifiers are written as ascii, everything else as hex such as `0x60701F2F`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_readable() {
+            // Readable identifiers are all ascii
+            self.0
+                .iter()
+                .try_for_each(|&c| write!(f, "{}", char::from(c)))
+        } else {
+            write!(f, "{:#010X}", u32::from_be_bytes(self.0))
+        }
+    }
+}
+
+impl Debug for FourCC {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FourCC({})", self)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for FourCC {
+    /// Serialize as the displayed name, such as `lvl_` or `0x60701F2F`, which can be parsed back
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
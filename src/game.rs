@@ -0,0 +1,115 @@
+use std::fmt::Display;
+
+use crate::fourcc::FourCC;
+use crate::script::Script;
+use crate::ucfb::*;
+
+/// ZeroEngine game a file was made for
+///
+/// Chunk layouts differ between games, so decoders can check this through `DecoderRegistry::game_version`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum GameVersion {
+    /// The game couldn't be detected
+    #[default]
+    Unknown,
+    /// Star Wars Battlefront (2004)
+    Battlefront,
+    /// Star Wars Battlefront II (2005)
+    Battlefront2,
+}
+
+impl Display for GameVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameVersion::Unknown => write!(f, "unknown game"),
+            GameVersion::Battlefront => write!(f, "Star Wars Battlefront"),
+            GameVersion::Battlefront2 => write!(f, "Star Wars Battlefront II"),
+        }
+    }
+}
+
+/// Chunks that only one game stores, such as Battlefront II's hero combos and space planets
+const SIGNATURE_CHUNKS: [(FourCC, GameVersion); 2] = [
+    (FourCC(*b"comb"), GameVersion::Battlefront2),
+    (FourCC(*b"plnp"), GameVersion::Battlefront2),
+];
+
+/// Get the size of the INFO chunk describing the first format of a texture
+fn texture_info_size(texture: &ChunkRef) -> Option<usize> {
+    let format = texture
+        .subchunks()
+        .ok()?
+        .into_iter()
+        .find(|c| c.header.name == FourCC::TEXTURE_FORMAT)?;
+    let info = format
+        .subchunks()
+        .ok()?
+        .into_iter()
+        .find(|c| c.header.name == FourCC::INFO)?;
+    Some(info.data.len())
+}
+
+impl GameVersion {
+    /// Get the game that compiles scripts with this lua version, such as `0x50` for lua 5.0
+    pub fn from_lua_version(version: u8) -> Self {
+        match version {
+            0x40 => GameVersion::Battlefront,
+            0x50 => GameVersion::Battlefront2,
+            _ => GameVersion::Unknown,
+        }
+    }
+    /// Get the game whose textures describe each format with an INFO chunk of this size
+    ///
+    /// Battlefront II added the detail bias after the dimensions and mipmap count
+    pub fn from_texture_info_size(size: usize) -> Self {
+        match size {
+            12 => GameVersion::Battlefront,
+            16 => GameVersion::Battlefront2,
+            _ => GameVersion::Unknown,
+        }
+    }
+    /// Guess the game from the chunk signatures in a list of chunks, searching inside every known container
+    ///
+    /// Scripts give the game away through the lua version they were compiled with, textures through the layout
+    /// of their format info, and some chunk types are only stored by one game. The first chunk that gives the
+    /// game away is used
+    pub fn detect(chunks: &[ChunkRef]) -> Self {
+        for chunk in chunks {
+            let version = match chunk.header.name {
+                FourCC::SCRIPT => Script::from_chunk_ref(chunk)
+                    .ok()
+                    .and_then(|s| s.lua_version())
+                    .map_or(GameVersion::Unknown, GameVersion::from_lua_version),
+                FourCC::TEXTURE => texture_info_size(chunk)
+                    .map_or(GameVersion::Unknown, GameVersion::from_texture_info_size),
+                _ if chunk.header.is_known_container() => {
+                    GameVersion::detect(&chunk.subchunks().unwrap_or_default())
+                }
+                name => SIGNATURE_CHUNKS
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .map_or(GameVersion::Unknown, |(_, v)| *v),
+            };
+            if version != GameVersion::Unknown {
+                return version;
+            }
+        }
+        GameVersion::Unknown
+    }
+}
+
+impl UCFBFile {
+    /// Guess the game the file was made for, see `GameVersion::detect`
+    pub fn detect_game_version(&self) -> GameVersion {
+        let chunks: Vec<ChunkRef> = self.chunks.iter().map(Chunk::as_chunk_ref).collect();
+        GameVersion::detect(&chunks)
+    }
+}
+
+impl UCFBFileRef<'_> {
+    /// Guess the game the file was made for, see `GameVersion::detect`
+    pub fn detect_game_version(&self) -> GameVersion {
+        GameVersion::detect(&self.chunks)
+    }
+}
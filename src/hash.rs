@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::prop::lookup_name;
+
+const FNV_OFFSET_BASIS: u32 = 0x811C9DC5;
+const FNV_PRIME: u32 = 0x01000193;
+
+/// Hash bytes the way the engine hashes names, FNV-1a 32 over every byte `| 0x20`
+///
+/// Setting bit 5 lowercases ascii letters so names hash the same whatever their case,
+/// it also changes some other characters, such as `_` to `0x7F`
+pub const fn fnv1a(bytes: &[u8]) -> u32 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= (bytes[i] | 0x20) as u32;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+/// Hash of a name, used for property names, level names and some chunk names
+///
+/// Hashes are stored in files as little endian numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct NameHash(pub u32);
+
+impl NameHash {
+    /// Hash a name
+    pub const fn new(name: &str) -> Self {
+        NameHash(fnv1a(name.as_bytes()))
+    }
+    /// Read a hash stored in a file
+    pub fn from_le_bytes(bytes: [u8; 4]) -> Self {
+        NameHash(u32::from_le_bytes(bytes))
+    }
+    /// Get the bytes the hash is stored as in a file
+    pub fn to_le_bytes(self) -> [u8; 4] {
+        self.0.to_le_bytes()
+    }
+    /// Get the name with this hash, if it is a known name
+    pub fn name(self) -> Option<&'static str> {
+        lookup_name(self.0)
+    }
+}
+
+impl From<&str> for NameHash {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<u32> for NameHash {
+    fn from(value: u32) -> Self {
+        NameHash(value)
+    }
+}
+
+impl FromStr for NameHash {
+    type Err = std::num::ParseIntError;
+
+    /// Parse a hash written the way `Display` writes it, either as a name or as hex such as `0x1234ABCD`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("0x") {
+            Some(hex) => Ok(NameHash(u32::from_str_radix(hex, 16)?)),
+            None => Ok(Self::new(s)),
+        }
+    }
+}
+
+impl Display for NameHash {
+    /// Known names are written as the name, everything else as hex such as `0x1234ABCD`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(v) => write!(f, "{}", v),
+            None => write!(f, "{:#010X}", self.0),
+        }
+    }
+}
+
+/// Names to look hashes up in, starting from the names this library knows
+///
+/// More names can be added with `insert` or loaded from word lists, such as names found in game scripts
+#[derive(Debug, Clone, Default)]
+pub struct HashDictionary {
+    names: HashMap<u32, String>,
+}
+
+/// Undo the escapes in a name quoted like a rust string literal, as in the lists in `misc`
+fn unescape(name: &str) -> String {
+    name.replace("\\\\", "\\").replace("\\\"", "\"")
+}
+
+impl HashDictionary {
+    /// Create a dictionary with the names this library knows
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add a name, returning its hash
+    pub fn insert(&mut self, name: &str) -> NameHash {
+        let hash = NameHash::new(name);
+        // Known names are already in the built-in table
+        if lookup_name(hash.0) != Some(name) {
+            self.names.insert(hash.0, name.to_string());
+        }
+        hash
+    }
+    /// Get the name with this hash, names that were added take priority over the built-in ones
+    pub fn get(&self, hash: NameHash) -> Option<&str> {
+        self.names
+            .get(&hash.0)
+            .map(String::as_str)
+            .or_else(|| hash.name())
+    }
+    /// Get the names that were added, not counting the built-in ones
+    pub fn added_names(&self) -> impl Iterator<Item = (NameHash, &str)> {
+        self.names.iter().map(|(k, v)| (NameHash(*k), v.as_str()))
+    }
+    /// Add the names in a word list, returning how many were read
+    ///
+    /// Each line is a name, optionally quoted like in the lists in `misc`,
+    /// and empty lines and lines starting with `#` are skipped
+    pub fn load_str(&mut self, list: &str) -> usize {
+        let mut count = 0;
+        for line in list.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let name = match (line.find('"'), line.rfind('"')) {
+                (Some(start), Some(end)) if end > start => unescape(&line[start + 1..end]),
+                _ => line.to_string(),
+            };
+            self.insert(&name);
+            count += 1;
+        }
+        count
+    }
+    /// Add the names in a word list file, see `load_str`
+    pub fn load_file(&mut self, path: &Path) -> Result<usize, std::io::Error> {
+        Ok(self.load_str(&std::fs::read_to_string(path)?))
+    }
+}
@@ -8,14 +8,41 @@
 #![deny(missing_docs)]
 /// Module representing audio data
 pub mod audio_data;
+/// Module representing the recovery of names for hashes that couldn't be resolved
+pub mod crack;
+/// Module representing the decoders that turn chunks into objects
+pub mod decoder;
+/// Module representing assets duplicated across files
+pub mod dedup;
+/// Module representing problems found while visiting chunks
+pub mod diagnostic;
+/// Module representing differences between chunk trees
+pub mod diff;
+/// Module representing edits to chunk trees
+pub mod edit;
+/// Module representing structured exports of chunk trees with serde
+#[cfg(feature = "serialize")]
+pub mod export;
+/// Module representing the identifiers that name chunks
+pub mod fourcc;
+/// Module representing the ZeroEngine games files come from
+pub mod game;
+/// Module representing the hash the engine uses for names
+pub mod hash;
 /// Module representing a level
 pub mod lvl;
 /// Module representing a in-game cutscene (mvs)
 pub mod mvs;
+/// Module representing manifests that pack files back into ucfb files
+pub mod pack;
 /// Module representing all game object property chunks
 pub mod prop;
+/// Module representing path-like queries over chunk trees
+pub mod query;
 /// Module representing a lua script from ZeroEngine
 pub mod script;
+/// Module representing a lazy stream of chunk headers
+pub mod stream;
 /// Module representing a texture
 pub mod tex;
 /// Module representing a ucfb file from ZeroEngine
@@ -1,7 +1,16 @@
+use crate::fourcc::FourCC;
+use crate::hash::{HashDictionary, NameHash};
 use crate::ucfb::*;
 /// Object that reperesents a level
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Level {
+    /// Hash of the level name, stored before the subchunks
+    ///
+    /// This is the name used to load sub-levels with `ReadDataFile("x.lvl;name")`
+    pub name_hash: u32,
+    /// The level name, if the hash is a known name
+    pub name: Option<String>,
     /// Chunks contained in level
     pub chunks: Vec<Chunk>,
 }
@@ -13,23 +22,130 @@ pub enum LevelError {
     NotALevel,
     /// Chunk Read failure
     ChunkError(UCFBError),
+    /// Level is too small to hold its name hash and size
+    CorruptLevel,
+}
+
+impl std::fmt::Display for LevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelError::NotALevel => write!(f, "chunk is not a level (lvl_)"),
+            LevelError::ChunkError(_) => write!(f, "failed to read the chunks in the level"),
+            LevelError::CorruptLevel => write!(f, "level is missing its name hash and size"),
+        }
+    }
+}
+
+impl std::error::Error for LevelError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LevelError::ChunkError(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 impl Level {
     /// Deserialize level from chunk
     pub fn from_chunk(chunk: Chunk) -> Result<Self, LevelError> {
-        if chunk.header.name != "lvl_" {
-            return Err(LevelError::NotALevel);
-        }
-        let mut data = chunk.data.clone();
-        // Remove stuff we don't need
-        data.drain(0..8);
-        let level_chunks = match extract_chunks_bytearray(&mut data) {
-            Ok(v) => v,
+        Self::from_chunk_with(&chunk, &HashDictionary::new())
+    }
+    /// Deserialize level from chunk, looking its name up in a dictionary
+    ///
+    /// The subchunks share the chunk data instead of copying it
+    pub fn from_chunk_with(chunk: &Chunk, dictionary: &HashDictionary) -> Result<Self, LevelError> {
+        let name_hash = Self::read_name_hash(&chunk.as_chunk_ref())?;
+        Ok(Level {
+            name_hash,
+            name: dictionary.get(NameHash(name_hash)).map(str::to_string),
+            chunks: chunk.subchunks().map_err(LevelError::ChunkError)?,
+        })
+    }
+    /// Deserialize level from a borrowed chunk
+    pub fn from_chunk_ref(chunk: &ChunkRef) -> Result<Self, LevelError> {
+        Self::from_chunk_ref_with(chunk, &HashDictionary::new())
+    }
+    /// Deserialize level from a borrowed chunk, looking its name up in a dictionary
+    ///
+    /// The subchunks are copied out of the borrowed data, use `from_chunk_with` to share it
+    pub fn from_chunk_ref_with(
+        chunk: &ChunkRef,
+        dictionary: &HashDictionary,
+    ) -> Result<Self, LevelError> {
+        let name_hash = Self::read_name_hash(chunk)?;
+        let level_chunks = match chunk.subchunks() {
+            Ok(v) => v.iter().map(ChunkRef::to_chunk).collect(),
             Err(e) => return Err(LevelError::ChunkError(e)),
         };
         Ok(Level {
+            name_hash,
+            name: dictionary.get(NameHash(name_hash)).map(str::to_string),
             chunks: level_chunks,
         })
     }
+    fn read_name_hash(chunk: &ChunkRef) -> Result<u32, LevelError> {
+        if chunk.header.name != FourCC::LEVEL {
+            return Err(LevelError::NotALevel);
+        }
+        // The name hash and the size of the subchunks come before the subchunks
+        if chunk.data.len() < 8 {
+            return Err(LevelError::CorruptLevel);
+        }
+        chunk
+            .header
+            .endianness
+            .read_u32_from(chunk.data)
+            .ok_or(LevelError::CorruptLevel)
+    }
+    /// Get the level name, or the hash written as hex if the name isn't known
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(v) => v.clone(),
+            None => format!("{:#010X}", self.name_hash),
+        }
+    }
+    /// Whether the level has the name, which can also be the hash written as hex
+    ///
+    /// Names are compared by hash, so this also finds levels whose name isn't known
+    pub fn has_name(&self, name: &str) -> bool {
+        name.parse::<NameHash>()
+            .is_ok_and(|h| h == NameHash(self.name_hash))
+    }
+    /// Get the visited levels stored directly in this level
+    pub fn sublevels(&self) -> impl Iterator<Item = &Level> {
+        levels_in(&self.chunks)
+    }
+    /// Find a visited level stored directly in this level by name
+    pub fn sublevel(&self, name: &str) -> Option<&Level> {
+        self.sublevels().find(|l| l.has_name(name))
+    }
+    /// Serialize the level data in the given byte order, recomputing the size of the subchunks
+    pub fn to_bytes(&self, endianness: Endianness) -> Result<Vec<u8>, UCFBError> {
+        let subchunks = chunks_to_bytes(&self.chunks)?;
+        let size = u32::try_from(subchunks.len()).map_err(|_| UCFBError::ChunkTooLarge)?;
+        let mut buffer: Vec<u8> = vec![];
+        buffer.extend_from_slice(&endianness.write_u32(self.name_hash));
+        buffer.extend_from_slice(&endianness.write_u32(size));
+        buffer.extend(subchunks);
+        Ok(buffer)
+    }
+}
+
+/// Get the visited levels in a list of chunks
+pub(crate) fn levels_in(chunks: &[Chunk]) -> impl Iterator<Item = &Level> {
+    chunks.iter().filter_map(|c| match &c.deciphered_chunk {
+        Some(DecipheredChunk::Level(v)) => Some(v),
+        _ => None,
+    })
+}
+
+impl UCFBFile {
+    /// Get the visited levels stored directly in the file, these are the sub-levels of a .lvl file
+    pub fn levels(&self) -> impl Iterator<Item = &Level> {
+        levels_in(&self.chunks)
+    }
+    /// Find a visited level stored directly in the file by name, like `ReadDataFile("x.lvl;name")` does
+    pub fn level(&self, name: &str) -> Option<&Level> {
+        self.levels().find(|l| l.has_name(name))
+    }
 }
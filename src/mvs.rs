@@ -1,8 +1,14 @@
+use crate::fourcc::FourCC;
 use crate::ucfb::*;
 /// Object that reperesents an in-game cutscene
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Movie {
     /// A list of bink cutscene files
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::export::serialize_byte_lists")
+    )]
     pub bink_files: Vec<Vec<u8>>,
 }
 
@@ -19,13 +25,28 @@ pub enum MovieError {
     CorruptedMovie,
 }
 
+impl std::fmt::Display for MovieError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MovieError::ChunkParseError => write!(f, "failed to parse the movie chunk"),
+            MovieError::NotAMovie => write!(f, "chunk is not a movie (0x60701F2F)"),
+            MovieError::CorruptedMovie => write!(f, "movie is corrupted"),
+        }
+    }
+}
+
+impl std::error::Error for MovieError {}
+
 impl Movie {
     /// Deserialize movie from chunk
     pub fn from_chunk(chunk: Chunk) -> Result<Self, MovieError> {
-        if chunk.header.name != "\x60\x70\x1F\x2F" {
+        Self::from_chunk_ref(&chunk.as_chunk_ref())
+    }
+    /// Deserialize movie from a borrowed chunk
+    pub fn from_chunk_ref(chunk: &ChunkRef) -> Result<Self, MovieError> {
+        if chunk.header.name != FourCC::MOVIE {
             return Err(MovieError::NotAMovie);
         }
-        let mut data = chunk.data.clone();
         // Jar Jar
         let mut binks: Vec<Vec<u8>> = vec![];
         // The structure of these files is odd
@@ -33,7 +54,7 @@ impl Movie {
         // 0x7F0 offset from the beginning of the chunk
         // 0x2F0 of it is padding and 0x500 is some header
         let mut offset = 0;
-        data.drain(0..0x7F0);
+        let data = chunk.data.get(0x7F0..).ok_or(MovieError::CorruptedMovie)?;
         while data.len() > offset {
             // Try to find the file
             // Should land on it immediately the first time
@@ -46,16 +67,21 @@ impl Movie {
                 continue;
             }
             // Extract the bik
-            let bik_size = u32::from_le_bytes(match data.get(offset + 4..offset + 8) {
-                Some(v) => v.try_into().unwrap(),
-                None => continue,
-            }) + 8;
+            let bik_size = match data.get(offset + 4..offset + 8) {
+                Some(v) => u32::from_le_bytes([v[0], v[1], v[2], v[3]]) as usize,
+                None => break,
+            }
+            .checked_add(8)
+            .ok_or(MovieError::CorruptedMovie)?;
+            let bik_end = offset
+                .checked_add(bik_size + 7)
+                .ok_or(MovieError::CorruptedMovie)?;
             binks.push(
-                data.get(offset..(offset + (bik_size as usize) + 7))
+                data.get(offset..bik_end)
                     .ok_or(MovieError::CorruptedMovie)?
                     .to_vec(),
             );
-            offset += (bik_size as usize) + 8;
+            offset += bik_size + 8;
         }
         Ok(Movie { bink_files: binks })
     }
@@ -0,0 +1,488 @@
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::fourcc::FourCC;
+use crate::hash::NameHash;
+use crate::lvl::Level;
+use crate::prop::{lookup_name, PropertyContainer, PropertyError};
+use crate::script::{Script, ScriptError};
+use crate::ucfb::*;
+
+/// A description of a chunk tree and the files its chunks are built from, the reverse of extracting a file
+///
+/// Each line is a chunk name followed by `key=value` pairs saying where its data comes from,
+/// and the chunks stored in it are on the lines below, indented further:
+/// ```text
+/// # Chunks in the ucfb file, comments start with #
+/// lvl_ name=0x1234ABCD
+///   scr_ script=0.0x1234ABCD.lvl/0.setup.luac name=setup info=1
+///   entc odf=0.0x1234ABCD.lvl/1.rep_inf_ep3_rifleman.odf name=rep_inf_ep3_rifleman
+///   tex_ file=0.0x1234ABCD.lvl/2.tex_.chunk
+/// ```
+/// - `file=PATH` uses the contents of the file as the chunk data
+/// - `script=PATH` builds a script from compiled lua bytecode, `name` defaults to the file name and `info` to 1
+/// - `odf=PATH` builds a class from an odf file, `name` defaults to the file name
+/// - `name=NAME` on a `lvl_` builds a level, the name is hashed unless it is a hash written as hex
+/// - anything else holds the chunks under it
+///
+/// Paths and names are relative to the directory the manifest is in and can't contain spaces.
+///
+/// Textures and movies can only be packed with `file=`. Their decoded forms keep just the first mipmap
+/// of each format or the bink files, so an image or a video can't build the chunk back
+#[derive(Debug, Clone, Default)]
+pub struct Manifest {
+    /// Chunks stored in the ucfb file
+    pub chunks: Vec<ManifestEntry>,
+}
+
+/// A chunk in a manifest
+#[derive(Debug, Clone)]
+pub struct ManifestEntry {
+    /// Chunk name
+    pub name: FourCC,
+    /// Where the chunk data comes from
+    pub source: ChunkSource,
+    /// Chunks stored in this chunk, for containers and levels
+    pub children: Vec<ManifestEntry>,
+    /// Line of the manifest the chunk is on, 0 for manifests that weren't parsed
+    pub line: usize,
+}
+
+/// Where the data of a chunk in a manifest comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkSource {
+    /// The chunks listed under it
+    Container,
+    /// A level holding the chunks listed under it, with the hash of its name
+    Level(u32),
+    /// The contents of a file, for chunks that can't be built from an editable file such as textures and movies
+    File(PathBuf),
+    /// A script built from compiled lua bytecode
+    Script {
+        /// File holding the bytecode
+        path: PathBuf,
+        /// Script name
+        name: String,
+        /// Script info byte, see `Script::info`
+        info: u8,
+    },
+    /// A class built from an odf file
+    Odf {
+        /// The odf file
+        path: PathBuf,
+        /// Class name, odf files don't store it
+        name: String,
+    },
+}
+
+/// Errors returned while reading a manifest or packing the chunks it describes
+#[derive(Debug)]
+pub enum PackError {
+    /// Line of the manifest that can't be read, with the reason
+    InvalidManifest(usize, String),
+    /// File can't be read or written
+    IOError(PathBuf, std::io::Error),
+    /// Failure while building a script
+    ScriptError(ScriptError),
+    /// Failure while building a class
+    PropertyError(PropertyError),
+    /// Failure while building the chunks
+    UCFBError(UCFBError),
+}
+
+impl Display for PackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PackError::InvalidManifest(line, reason) => {
+                write!(f, "invalid manifest line {}: {}", line, reason)
+            }
+            PackError::IOError(path, _) => write!(f, "failed to access {}", path.display()),
+            PackError::ScriptError(_) => write!(f, "failed to build a script"),
+            PackError::PropertyError(_) => write!(f, "failed to build a class"),
+            PackError::UCFBError(_) => write!(f, "failed to build the chunks"),
+        }
+    }
+}
+
+impl std::error::Error for PackError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PackError::IOError(_, e) => Some(e),
+            PackError::ScriptError(e) => Some(e),
+            PackError::PropertyError(e) => Some(e),
+            PackError::UCFBError(e) => Some(e),
+            PackError::InvalidManifest(..) => None,
+        }
+    }
+}
+
+/// Get the name of a file without its extension
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+impl ManifestEntry {
+    /// Parse a manifest line without its indentation
+    fn parse(line: &str, number: usize) -> Result<Self, PackError> {
+        let invalid = |reason: String| PackError::InvalidManifest(number, reason);
+        let mut words = line.split_whitespace();
+        let name: FourCC = match words.next().map(str::parse) {
+            Some(Ok(v)) => v,
+            _ => return Err(invalid(format!("invalid chunk name in {}", line))),
+        };
+        let (mut file, mut script, mut odf, mut chunk_name, mut info) =
+            (None, None, None, None, None);
+        for word in words {
+            let (key, value) = word
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("expected KEY=VALUE, found {}", word)))?;
+            let slot = match key {
+                "file" => &mut file,
+                "script" => &mut script,
+                "odf" => &mut odf,
+                "name" => &mut chunk_name,
+                "info" => &mut info,
+                _ => return Err(invalid(format!("unknown key {}", key))),
+            };
+            *slot = Some(value.to_string());
+        }
+        let source = match (file, script, odf) {
+            (Some(path), None, None) => ChunkSource::File(path.into()),
+            (None, Some(path), None) => {
+                let path = PathBuf::from(path);
+                ChunkSource::Script {
+                    name: chunk_name.take().unwrap_or_else(|| file_stem(&path)),
+                    info: match info.take() {
+                        Some(v) => v
+                            .parse()
+                            .map_err(|_| invalid(format!("invalid info {}", v)))?,
+                        None => 1,
+                    },
+                    path,
+                }
+            }
+            (None, None, Some(path)) => {
+                let path = PathBuf::from(path);
+                ChunkSource::Odf {
+                    name: chunk_name.take().unwrap_or_else(|| file_stem(&path)),
+                    path,
+                }
+            }
+            (None, None, None) if name == FourCC::LEVEL => {
+                let level_name = chunk_name
+                    .take()
+                    .ok_or_else(|| invalid("level without a name".to_string()))?;
+                ChunkSource::Level(
+                    level_name
+                        .parse::<NameHash>()
+                        .map_err(|_| invalid(format!("invalid level hash {}", level_name)))?
+                        .0,
+                )
+            }
+            (None, None, None) => ChunkSource::Container,
+            _ => return Err(invalid("more than one of file, script and odf".to_string())),
+        };
+        if let Some(key) = chunk_name.map(|_| "name").or(info.map(|_| "info")) {
+            return Err(invalid(format!("{} isn't used by this chunk", key)));
+        }
+        Ok(ManifestEntry {
+            name,
+            source,
+            children: vec![],
+            line: number,
+        })
+    }
+    /// Build the chunk, reading files relative to the directory
+    pub fn pack(&self, directory: &Path) -> Result<Chunk, PackError> {
+        let read = |path: &Path| {
+            let path = directory.join(path);
+            std::fs::read(&path).map_err(|e| PackError::IOError(path, e))
+        };
+        let children = || -> Result<Vec<Chunk>, PackError> {
+            self.children.iter().map(|c| c.pack(directory)).collect()
+        };
+        if !self.children.is_empty()
+            && !matches!(self.source, ChunkSource::Container | ChunkSource::Level(_))
+        {
+            return Err(PackError::InvalidManifest(
+                self.line,
+                "only containers and levels can hold chunks".to_string(),
+            ));
+        }
+        let mut chunk = match &self.source {
+            ChunkSource::Container if self.name == FourCC::UCFB => {
+                let mut chunk = Chunk::new(self.name, vec![]);
+                chunk.deciphered_chunk = Some(DecipheredChunk::UCFB(UCFBFile {
+                    header: UCFBHeader {
+                        size: 0,
+                        endianness: Endianness::Little,
+                    },
+                    chunks: children()?,
+                    game_version: None,
+                }));
+                chunk
+            }
+            ChunkSource::Container => Chunk::new(
+                self.name,
+                chunks_to_bytes(&children()?).map_err(PackError::UCFBError)?,
+            ),
+            ChunkSource::Level(hash) => {
+                let mut chunk = Chunk::new(self.name, vec![]);
+                chunk.deciphered_chunk = Some(DecipheredChunk::Level(Level {
+                    name_hash: *hash,
+                    name: lookup_name(*hash).map(str::to_string),
+                    chunks: children()?,
+                }));
+                chunk
+            }
+            ChunkSource::File(path) => Chunk::new(self.name, read(path)?),
+            ChunkSource::Script { path, name, info } => Script {
+                name: name.clone(),
+                info: *info,
+                body: read(path)?,
+            }
+            .to_chunk()
+            .map_err(PackError::ScriptError)?,
+            ChunkSource::Odf { path, name } => {
+                let odf = String::from_utf8(read(path)?).map_err(|_| {
+                    PackError::PropertyError(PropertyError::InvalidOdf(format!(
+                        "{} isn't utf-8",
+                        path.display()
+                    )))
+                })?;
+                PropertyContainer::from_odf(name, &odf)
+                    .and_then(|c| c.to_chunk())
+                    .map_err(PackError::PropertyError)?
+            }
+        };
+        if chunk.header.name != self.name {
+            return Err(PackError::InvalidManifest(
+                self.line,
+                format!("built a {} instead of a {}", chunk.header.name, self.name),
+            ));
+        }
+        chunk.rebuild().map_err(PackError::UCFBError)?;
+        Ok(chunk)
+    }
+}
+
+type IndentedLines = std::iter::Peekable<std::vec::IntoIter<(usize, ManifestEntry)>>;
+
+/// Turn indented lines into a tree, taking the lines indented more than `indent`
+fn nest(lines: &mut IndentedLines, indent: Option<usize>) -> Vec<ManifestEntry> {
+    let mut entries = vec![];
+    while let Some((line_indent, _)) = lines.peek() {
+        if indent.is_some_and(|i| *line_indent <= i) {
+            break;
+        }
+        let (line_indent, mut entry) = lines.next().unwrap();
+        entry.children = nest(lines, Some(line_indent));
+        entries.push(entry);
+    }
+    entries
+}
+
+impl FromStr for Manifest {
+    type Err = PackError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = vec![];
+        for (i, line) in s.lines().enumerate() {
+            let content = line.trim_start();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+            let indent = line.len() - content.len();
+            lines.push((indent, ManifestEntry::parse(content, i + 1)?));
+        }
+        Ok(Manifest {
+            chunks: nest(&mut lines.into_iter().peekable(), None),
+        })
+    }
+}
+
+impl Display for Manifest {
+    /// Write the manifest in the format it is parsed from
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_entries(
+            f: &mut std::fmt::Formatter<'_>,
+            entries: &[ManifestEntry],
+            depth: usize,
+        ) -> std::fmt::Result {
+            for entry in entries {
+                write!(f, "{}{}", "  ".repeat(depth), entry.name)?;
+                match &entry.source {
+                    ChunkSource::Container => {}
+                    // Names with spaces would be split when the manifest is read back
+                    ChunkSource::Level(hash) => match NameHash(*hash).name() {
+                        Some(v) if is_manifest_name(v) => write!(f, " name={}", v)?,
+                        _ => write!(f, " name={:#010X}", hash)?,
+                    },
+                    ChunkSource::File(path) => write!(f, " file={}", path.display())?,
+                    ChunkSource::Script { path, name, info } => {
+                        write!(f, " script={} name={} info={}", path.display(), name, info)?
+                    }
+                    ChunkSource::Odf { path, name } => {
+                        write!(f, " odf={} name={}", path.display(), name)?
+                    }
+                }
+                writeln!(f)?;
+                write_entries(f, &entry.children, depth + 1)?;
+            }
+            Ok(())
+        }
+        write_entries(f, &self.chunks, 0)
+    }
+}
+
+impl Manifest {
+    /// Read a manifest from a file
+    pub fn from_file(path: &Path) -> Result<Self, PackError> {
+        std::fs::read_to_string(path)
+            .map_err(|e| PackError::IOError(path.to_path_buf(), e))?
+            .parse()
+    }
+    /// Build the ucfb file the manifest describes, reading files relative to the directory
+    pub fn pack(&self, directory: &Path) -> Result<UCFBFile, PackError> {
+        let chunks = self
+            .chunks
+            .iter()
+            .map(|c| c.pack(directory))
+            .collect::<Result<Vec<Chunk>, PackError>>()?;
+        let size = chunks_to_bytes(&chunks)
+            .map_err(PackError::UCFBError)?
+            .len();
+        Ok(UCFBFile {
+            header: UCFBHeader {
+                size: u32::try_from(size)
+                    .map_err(|_| PackError::UCFBError(UCFBError::ChunkTooLarge))?,
+                endianness: Endianness::Little,
+            },
+            chunks,
+            game_version: None,
+        })
+    }
+    /// Write the visited chunks to files in the directory, returning the manifest that packs them back
+    ///
+    /// Embedded ucfb files and levels get a directory for their chunks. Classes are written as odf files
+    /// and scripts as their lua bytecode when those build the same chunk back, everything else is
+    /// written as raw data. Files and directories are named `INDEX.NAME.EXTENSION` after the position
+    /// of the chunk, with anything but letters, digits, `_` and `-` in names replaced by `_`
+    pub fn unpack(chunks: &[Chunk], directory: &Path) -> Result<Self, PackError> {
+        Ok(Manifest {
+            chunks: unpack_into(chunks, directory, Path::new(""))?,
+        })
+    }
+}
+
+/// Make a name from a file safe to use in a file name, so it can't leave the directory or break the manifest
+pub fn file_name_part(name: &str) -> String {
+    name.chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// Whether a name can be written in a manifest as it is
+fn is_manifest_name(name: &str) -> bool {
+    !name.is_empty() && !name.contains(char::is_whitespace)
+}
+
+/// Get the file an editable chunk is unpacked to, its contents and the source that reads it back
+///
+/// Classes and scripts whose names can't be written in a manifest, or that don't build the same chunk back
+/// such as classes with subchunks after their properties, aren't editable
+fn editable_file(
+    i: usize,
+    chunk: &Chunk,
+    relative: &Path,
+) -> Option<(String, Vec<u8>, ChunkSource)> {
+    match &chunk.deciphered_chunk {
+        Some(DecipheredChunk::PropertyContainer(v)) if is_manifest_name(&v.name) => {
+            let odf = v.get_odf();
+            let rebuilt = PropertyContainer::from_odf(&v.name, &odf).and_then(|c| c.to_chunk());
+            if !rebuilt.is_ok_and(|c| c.header.name == chunk.header.name && c.data == chunk.data) {
+                return None;
+            }
+            let file = format!("{}.{}.odf", i, file_name_part(&v.name));
+            let source = ChunkSource::Odf {
+                path: relative.join(&file),
+                name: v.name.clone(),
+            };
+            Some((file, odf.into_bytes(), source))
+        }
+        Some(DecipheredChunk::Script(v)) if is_manifest_name(&v.name) => {
+            if !v.to_chunk().is_ok_and(|c| c.data == chunk.data) {
+                return None;
+            }
+            let file = format!("{}.{}.luac", i, file_name_part(&v.name));
+            let source = ChunkSource::Script {
+                path: relative.join(&file),
+                name: v.name.clone(),
+                info: v.info,
+            };
+            Some((file, v.body.clone(), source))
+        }
+        _ => None,
+    }
+}
+
+fn unpack_into(
+    chunks: &[Chunk],
+    directory: &Path,
+    relative: &Path,
+) -> Result<Vec<ManifestEntry>, PackError> {
+    let output = directory.join(relative);
+    std::fs::create_dir_all(&output).map_err(|e| PackError::IOError(output.clone(), e))?;
+    let write = |file: String, data: &[u8]| {
+        let path = output.join(&file);
+        std::fs::write(&path, data).map_err(|e| PackError::IOError(path, e))?;
+        Ok(relative.join(file))
+    };
+    let mut entries = vec![];
+    for (i, chunk) in chunks.iter().enumerate() {
+        let (source, children) = match &chunk.deciphered_chunk {
+            Some(DecipheredChunk::UCFB(v)) => (
+                ChunkSource::Container,
+                unpack_into(&v.chunks, directory, &relative.join(format!("{}.ucfb", i)))?,
+            ),
+            Some(DecipheredChunk::Level(v)) => (
+                ChunkSource::Level(v.name_hash),
+                unpack_into(
+                    &v.chunks,
+                    directory,
+                    &relative.join(format!("{}.{}.lvl", i, file_name_part(&v.display_name()))),
+                )?,
+            ),
+            _ => match editable_file(i, chunk, relative) {
+                Some((file, data, source)) => {
+                    write(file, &data)?;
+                    (source, vec![])
+                }
+                None => (
+                    ChunkSource::File(write(
+                        format!(
+                            "{}.{}.chunk",
+                            i,
+                            file_name_part(&chunk.header.name.to_string())
+                        ),
+                        &chunk.data,
+                    )?),
+                    vec![],
+                ),
+            },
+        };
+        entries.push(ManifestEntry {
+            name: chunk.header.name,
+            source,
+            children,
+            line: 0,
+        });
+    }
+    Ok(entries)
+}
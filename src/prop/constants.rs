@@ -1,7 +1,7 @@
-// The arrays in this file are based off the ones here:
+// The tables in this file are generated by build.rs from the lists in misc, which are based off the ones here:
 // https://github.com/PrismaticFlower/swbf-unmunge/blob/master/src/swbf_fnv_hashes.cpp
-// and here
-// The following constant is based on this: https://github.com/PrismaticFlower/swbf-unmunge/blob/master/src/handle_object.cpp
+// and here (the class labels)
+// https://github.com/PrismaticFlower/swbf-unmunge/blob/master/src/handle_object.cpp
 // With this license:
 /*
 MIT License
@@ -27,3922 +27,4 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-use ::phf::{phf_map, Map};
-
-pub(crate) static HASHVALUES: Map<&[u8], &'static str> = phf_map! {
-    b"\x55\xB2\x1A\x1C" => "--AttachOdf",
-b"\x5C\x3B\x8B\x7E" => "--AttachToHardPoint",
-b"\xFF\x23\xCC\x5F" => "--DamageAttachPoint",
-b"\xD3\x11\x84\xBA" => "--DamageEffect",
-b"\xAB\xFB\x9C\xB6" => "--DamageEffectScale",
-b"\x92\x98\xD1\x27" => "--DamageInheritVelocity",
-b"\xE7\x5D\x61\x0E" => "--DamageStartPercent",
-b"\x8F\xF1\xB6\xC3" => "--DamageStopPercent",
-b"\x63\x43\xA2\x41" => "--UnbuiltGeometryName",
-b"\x13\x60\xDB\xB5" => "--UnbuiltHoloOdf",
-b"\x87\xB3\x91\xD1" => "!Back",
-b"\xEE\x44\x95\x67" => "!BackLeft",
-b"\xC3\x8F\xD8\xF6" => "!BackRight",
-b"\xD2\xAD\x92\x4F" => "!Crouch",
-b"\xA0\xD4\xD9\x19" => "!Fire",
-b"\xE2\x0B\x85\x01" => "!FireSecondary",
-b"\xB1\x62\x6E\x93" => "!Forward",
-b"\xD3\xB7\x24\x7A" => "!Jet",
-b"\x58\xDF\x39\x27" => "!Jump",
-b"\xFD\x96\xAF\x4D" => "!Left",
-b"\x2A\x89\xE9\xE0" => "!Prone",
-b"\x51\x88\xA6\x53" => "!Reload",
-b"\x76\x4D\x30\xEE" => "!Right",
-b"\x2F\xB9\xBF\xA8" => "!Roll",
-b"\xE0\xA4\x55\x6E" => "!Sprint",
-b"\x2C\x31\x37\xB8" => "!Stand",
-b"\x9E\xA4\x7F\xA3" => "!Thrust",
-b"\x55\x74\x80\xDD" => "\\\\ChunkGeometryName",
-b"\xC3\x7D\x6E\x8B" => "\\\\ChunkPhysics",
-b"\xDE\x13\x41\xC7" => "\\\\ChunkSmokeEffect",
-b"\x10\xD4\xDE\x7A" => "\\\\ChunkSmokeNodeName",
-b"\x4B\xA4\xE0\x88" => "\\\\ChunkTrailEffect",
-b"\xD0\xE4\x5C\x21" => "\\\\DeathAnimationExplosion",
-b"\x4B\x5B\x75\x19" => "\\\\IconTexture",
-b"\xAF\xA8\x0C\x35" => "0",
-b"\x1C\xA7\x0C\x34" => "1",
-b"\x26\x8B\x6B\x21" => "1Flag",
-b"\x8A\x83\x65\x78" => "1PlayerHud",
-b"\xD5\xAB\x0C\x37" => "2",
-b"\xA7\x7D\xB7\x9D" => "2Flag",
-b"\xD9\x07\x6A\x90" => "2PlayerHud",
-b"\x30\x49\xE3\xFB" => "3PlayerHud",
-b"\xFF\xB9\x4C\xCA" => "4PlayerHud",
-b"\x89\xA5\x0C\x33" => "6",
-b"\xF6\xA3\x0C\x32" => "7",
-b"\x47\xB5\x0C\x3D" => "8",
-b"\x2C\x29\x0C\xE4" => "A",
-b"\x3D\x77\x1A\xB1" => "aacylinder",
-b"\x0A\x3C\x21\xA4" => "Above",
-b"\xF0\x16\x9F\x9D" => "Accelerate",
-b"\x9D\xBA\xA0\x2F" => "acceleration",
-b"\x40\x00\x8A\x9F" => "Acceleraton",
-b"\x77\xE4\x9E\x39" => "AcquiredTargetSound",
-b"\x18\xCF\x70\x2C" => "ActiveRotateNode",
-b"\x73\x5A\x27\x18" => "ActiveSpinNode",
-b"\x62\xB2\x9A\x3F" => "AddHealth",
-b"\x40\xF3\xBD\x18" => "AddHitEffect",
-b"\xA5\xA0\xA6\x06" => "Additive",
-b"\x08\xE0\x23\xA4" => "AddonAttachJoint",
-b"\xBE\xC0\x8D\x2B" => "AddSample",
-b"\x15\x4C\xD9\xBA" => "AddShield",
-b"\x7A\xFA\x9D\x72" => "AddShieldOff",
-b"\x21\x77\x1B\x5F" => "AddSpringBody",
-b"\x74\x1C\x77\x09" => "AddToTop",
-b"\x81\x77\xD1\x03" => "adminpw",
-b"\xF6\xFE\x29\x89" => "AdrenalineHigh",
-b"\x1C\x4D\xC7\xAB" => "AdrenalineLow",
-b"\xAD\xD9\xC5\x33" => "AdrenalineMedium",
-b"\xE0\xC7\x1E\xD4" => "AffectEnemies",
-b"\xD9\x76\x13\xAB" => "AffectFriends",
-b"\x87\x34\xCD\x81" => "AfterburnerOffSound",
-b"\x55\x0F\x07\xC6" => "AfterburnerOnSound",
-b"\x76\xE5\x53\x16" => "AfterburnerSpeed",
-b"\x9F\xF7\x24\x44" => "AI",
-b"\xD8\xDF\x2C\x2B" => "ai_tactical_template",
-b"\x09\x79\xF3\xFA" => "AIBubbleCircle",
-b"\xC0\xCE\xD6\x41" => "AIBubbleScaleClamp",
-b"\xA6\x9A\xBB\x70" => "AIBubbleScaleDistDivider",
-b"\xC3\xD1\x0C\xE6" => "AIBubbleSizeMultiplier",
-b"\x4F\xF8\x17\x42" => "AICollisionRadius",
-b"\xFE\xFB\x86\x31" => "AimAzimuth",
-b"\x1D\x9D\xDB\xEC" => "AimDistance",
-b"\x35\xFB\x39\x83" => "AimElevation",
-b"\xC2\xAB\x82\xC1" => "AimerNodeName",
-b"\x1E\xB4\x56\xE8" => "AimerOffset",
-b"\xBD\x87\x64\x2D" => "AimerPitchLimits",
-b"\x3C\xD1\x5F\x0F" => "AimerPitchLimts",
-b"\x1F\x14\x06\x41" => "AimerPitchRate",
-b"\x3C\x08\x38\x3F" => "AimerRestDirection",
-b"\x5C\x67\xC3\xA9" => "AimerYawLimits",
-b"\x1B\x34\xC7\xB5" => "AimerYawLimts",
-b"\x46\x69\x6A\x48" => "AimerYawRate",
-b"\xE0\xBE\xF0\xA5" => "AimFactorMove",
-b"\x05\x82\x89\xC7" => "AimFactorPostureCrouch",
-b"\x17\xE7\xFF\x13" => "AimFactorPostureProne",
-b"\xCE\x75\x9C\x63" => "AimFactorPostureSpecial",
-b"\xA9\x03\x7C\x49" => "AimFactorPostureStand",
-b"\x02\x3C\xA8\x95" => "AimFactorStrafe",
-b"\xCC\x71\x31\x5E" => "AimTension",
-b"\x06\xA7\x31\x3D" => "AimType",
-b"\x77\xE5\xE1\xA5" => "AimValue",
-b"\xB1\x02\xBF\xBB" => "AINoRepair",
-b"\x51\x91\x99\xE0" => "AISCDriverGetInSound",
-b"\x92\xB9\x62\x3B" => "AISCDriverGetOutSound",
-b"\x41\x86\x98\x1D" => "AISCFieldFollowSound",
-b"\x9D\xB0\x18\x80" => "AISCFieldHoldSound",
-b"\xE9\x38\x0A\x15" => "AISCFieldMoveOutSound",
-b"\x95\x76\x98\x22" => "AISCGunnerAllClearSound",
-b"\xD0\x94\xF0\x7E" => "AISCGunnerGetInSound",
-b"\x61\x6F\x52\xDF" => "AISCGunnerGetOutSound",
-b"\x13\x5F\xEC\x1A" => "AISCGunnerSteadySound",
-b"\x05\x27\xD9\xF9" => "AISCPassengerGetInSound",
-b"\xAE\x2F\x91\x45" => "AISCPassengerGetOutSound",
-b"\x6D\xBB\x12\x25" => "AISCPassengerMoveOutSound",
-b"\xEA\xE5\x73\xA4" => "AISCPassengerStopSound",
-b"\x18\x12\x68\x60" => "AISCResponseNosirSound",
-b"\x44\x14\x13\x8E" => "AISCResponseYessirSound",
-b"\x06\x28\xF2\xAF" => "AISizeType",
-b"\xDA\x98\xEC\x3B" => "AISoldierMusic",
-b"\xC2\x82\x72\xE0" => "AISpawnWeight",
-b"\x39\x17\xFE\xCD" => "AITargetAll",
-b"\x46\x0E\xEE\xD6" => "AITargetAnimal",
-b"\xA6\x74\xF1\x08" => "AITargetBuilding",
-b"\x04\x99\x32\x60" => "AITargetBuildingDead",
-b"\x73\x77\x66\x64" => "AITargetBuildingUnbuilt",
-b"\x28\x58\xAD\x9B" => "AITargetDroid",
-b"\x89\x91\x7F\x6B" => "AITargetMine",
-b"\x6F\x5B\xE0\x48" => "AITargetPerson",
-b"\x3E\x74\xB4\x72" => "AITargetVehicle",
-b"\xA8\xA8\xE2\xEC" => "AIText",
-b"\x44\x4D\x45\x91" => "AIUseBubbleCircle",
-b"\xF9\x9A\xBC\x55" => "AIUseHoverPhysics",
-b"\x34\x07\xFC\x13" => "AIVisibilityFactor",
-b"\x14\x65\xAF\x1B" => "AlignedToThrust",
-b"\x44\x33\x87\xDB" => "AlignVertical",
-b"\xC4\x4B\x25\x13" => "All",
-b"\xBB\xC2\x14\x5F" => "all_halo",
-b"\x00\xA2\x6D\x12" => "all_hero_luke_jedi",
-b"\xC0\x3C\x08\x1D" => "Alliance",
-b"\xBF\xE9\x32\x42" => "AllMusic",
-b"\x9C\xDC\x12\xF4" => "AllowAIPickup",
-b"\x60\x2F\x42\xED" => "AllowDestruct",
-b"\x69\x41\xA9\xB3" => "AllowMiniMap",
-b"\x8B\x1D\xDB\xDF" => "AllowTeamPickup",
-b"\x3E\xFD\x36\x71" => "AllyCount",
-b"\xB0\x15\xD2\xDA" => "AllyPath",
-b"\xAB\x6D\x8B\x5D" => "Alpha",
-b"\x7D\x19\xE4\x7D" => "AlphaMin",
-b"\x21\x0C\x0C\x22" => "AlphaMinMax",
-b"\x0D\xE2\x4D\xB7" => "AlwaysScroll",
-b"\x4D\xAD\xBB\xAE" => "Ambience",
-b"\xEB\x40\x96\x1C" => "Ambient",
-b"\x4A\x8B\x10\x9A" => "Ambient2Sound",
-b"\x4A\xBB\x7E\x62" => "AmbientColor",
-b"\x2D\xAA\xAA\x6E" => "ambientemt_static_template",
-b"\x5E\xD1\xDE\x3E" => "ambientemt_template",
-b"\xD8\x56\x5E\x32" => "ambientfx_template",
-b"\xE3\x54\xA2\x92" => "AmbientScale",
-b"\xBA\x20\xA9\x65" => "AmbientSound",
-b"\xF9\x43\x87\x8D" => "AmmoGainEffect",
-b"\xF6\x90\x20\x22" => "AmmoPerShot",
-b"\x18\x44\x54\xAD" => "Angle",
-b"\xBD\xBC\x97\x71" => "AngleEnd",
-b"\x4E\xA0\x47\xF9" => "AngleFade",
-b"\xD4\x1E\xE5\x1E" => "AngleMin",
-b"\x24\x7E\x50\x04" => "AngleStart",
-b"\x5D\xE0\x38\xC2" => "Animal",
-b"\x97\xE3\xE8\xBF" => "AnimalAmmo",
-b"\xAD\x07\x05\xFB" => "AnimalEnergy",
-b"\xD7\x46\xD7\x60" => "AnimalHealth",
-b"\x49\x7A\xA1\x9A" => "AnimalScale",
-b"\xBC\xDF\x35\x6F" => "Animated",
-b"\x42\x8B\x88\xE8" => "AnimatedAddon",
-b"\x9C\xFB\x83\x8F" => "AnimatedBuilding",
-b"\xE9\xD2\x43\x2B" => "AnimatedMove",
-b"\x25\x20\xCE\x11" => "AnimatedPilotPosition",
-b"\x05\xC0\xF2\x15" => "AnimatedProp",
-b"\x5D\xEE\x45\xE1" => "Animation",
-b"\x45\xDE\xDC\xDD" => "AnimationAddon",
-b"\x9E\x7D\x83\x60" => "AnimationAmplitude0",
-b"\x31\x7F\x83\x61" => "AnimationAmplitude1",
-b"\x8F\x8F\x67\xA7" => "AnimationBank",
-b"\x28\x58\x5A\x7F" => "AnimationBlending",
-b"\x27\x4B\x71\x3A" => "AnimationExtraFile",
-b"\xE9\x36\x3B\x1F" => "AnimationFrequency",
-b"\x0D\x7E\xCC\x1E" => "AnimationLowRes",
-b"\x5E\x7A\x55\x98" => "AnimationName",
-b"\x3B\xB7\x56\x1D" => "AnimationTrigger",
-b"\x6A\xCC\x2E\x76" => "AnimationTriggerSound",
-b"\xA8\xB0\x55\x34" => "AnimChange",
-b"\x2C\x10\x42\x62" => "AnimDisable",
-b"\x4F\xD8\xBF\xBD" => "AnimEnable",
-b"\xE7\x3B\x14\xB0" => "Anti-Armor",
-b"\x4D\xF0\x29\x2C" => "Any",
-b"\x8C\x64\xB3\x39" => "ApplyOnOwnerIfOnOthers",
-b"\xA1\x89\x60\xEB" => "ApproachingTargetSound",
-b"\xDB\x2A\x6F\x3A" => "Arc",
-b"\x77\x0E\x4A\x89" => "AreaHeight",
-b"\x5A\xDF\x91\x38" => "AreaRadius",
-b"\xE9\x37\xFC\x2E" => "AreaRange",
-b"\x4C\x20\x78\xDA" => "AreaType",
-b"\x5C\x5F\xF7\x6A" => "ArmedBuilding",
-b"\xCD\x3A\xAA\x90" => "ArmedBuildingDynamic",
-b"\xD6\x1E\x49\x90" => "ArmName",
-b"\xCE\x60\xBB\xA0" => "ArmorScale",
-b"\x0C\x7E\x55\x66" => "Assault",
-b"\xAD\xF3\x78\x20" => "ATAT",
-b"\x28\xA0\xBC\xE1" => "AtmosphereColor",
-b"\x45\xC8\xE2\x51" => "AttachDynamic",
-b"\x65\x20\xF2\x94" => "AttachedFirePoint",
-b"\xCC\xCA\x0E\xE9" => "attachedmesh",
-b"\x0D\x7E\x6C\x6A" => "AttachEffect",
-b"\x23\x51\xBE\x18" => "AttachNodeName",
-b"\x8B\xD4\xD0\xA9" => "AttachOdf",
-b"\x0A\xB8\xE7\x3B" => "AttachToHardPoint",
-b"\x28\x66\x30\x72" => "AttachTrigger",
-b"\xFD\xB8\x95\x45" => "Attack",
-b"\xE5\x12\x88\xAF" => "AttackAnimation",
-b"\x67\x5C\xE7\x03" => "AttackBone",
-b"\x2F\x39\x9C\x38" => "AttackControls",
-b"\x68\xDD\x4A\xF3" => "AttackDamage",
-b"\x43\xB2\xF8\x49" => "AttackDamageDistance",
-b"\x21\xD1\x8A\x30" => "AttackDamageTime",
-b"\x55\x1F\x02\x49" => "AttackDuration",
-b"\x57\xA4\xE6\x04" => "AttackerOffScreen",
-b"\x6B\x16\xF1\xB1" => "AttackerOffScreenBehind",
-b"\x8B\xF2\x27\xEB" => "AttackerOnScreen",
-b"\x57\xC9\x34\x70" => "AttackerOnScreenBehind",
-b"\x48\x9F\x86\xE9" => "AttackId",
-b"\x72\x14\x17\x02" => "AttackPosition",
-b"\x19\xDD\x08\x72" => "AttackPositionVO",
-b"\x65\x3D\x9C\x52" => "AttackPush",
-b"\xDB\x10\x41\xCC" => "AttackRadius",
-b"\xCB\x10\xF4\x91" => "AttackRecoverBreakOutTime",
-b"\x47\x1A\x11\x1E" => "AttackRecoverDuration",
-b"\x7C\x7A\x53\xD0" => "AttackSound",
-b"\xDC\x62\xBF\x05" => "AttackTime",
-b"\x64\xB0\x7D\x24" => "AttenuateBus",
-b"\xF7\x0E\xA0\x5B" => "Attenuation",
-b"\xAA\x89\x60\xD1" => "AttenuationLeadIn",
-b"\x63\xD1\x47\xF2" => "AttenuationLeadOut",
-b"\x6F\x38\xBA\x8C" => "Attitude",
-b"\xDA\x21\x0D\xC9" => "AttractCameras",
-b"\x74\xA4\xCC\x54" => "AttractGame",
-b"\x2B\x07\xD9\xDF" => "AttractIngame",
-b"\xF8\xC2\x3E\x6F" => "AttractMap",
-b"\x29\x67\x27\x54" => "AttractTimeout",
-b"\xBD\xC9\xEA\x8C" => "AudioMaxChannels",
-b"\x6B\x93\x52\x06" => "AudioMixBuffer",
-b"\x38\x5C\x3F\x3C" => "AudionoEnhance",
-b"\x09\x11\xBD\xD0" => "AudioSampleRate",
-b"\xDB\x3A\xF6\x68" => "AutoAim",
-b"\xFA\x88\xBE\x7D" => "AutoAimSize",
-b"\x62\x5B\x7B\xF0" => "AutoAimYLimits",
-b"\xC6\x62\xF0\xC1" => "AutoBarrierEnable",
-b"\xE2\x19\xDE\x43" => "AutoBarrierRemoveOnDeath",
-b"\xD4\xCE\x2E\xF5" => "AutoBarrierSize",
-b"\x8A\xF6\x39\x9D" => "AutoFire",
-b"\xB9\x37\x39\xC2" => "autonet",
-b"\x00\xC1\xC7\xFB" => "AutoPitchScreenDist",
-b"\xAD\x5B\x42\x73" => "AutoRespawnEnable",
-b"\x1D\x7F\xAB\x5F" => "AutoTurnScreenDist",
-b"\x06\x3C\xFE\x0F" => "AvailableForAnyTeam",
-b"\x5A\x98\x80\xBA" => "B01",
-b"\xA2\x21\xB4\x5B" => "Back",
-b"\xF1\x5A\xF2\xCC" => "BackAngle",
-b"\x35\xE1\x44\x97" => "BackColor",
-b"\x0A\x26\xD2\xFA" => "BackDegree",
-b"\xF7\xF4\xC0\x93" => "Backdrop",
-b"\xF2\xAB\x49\x32" => "BackdropLarge",
-b"\x86\xAC\xC8\x08" => "BackdropSmall",
-b"\x72\xFD\x89\x84" => "BackdropSpawn",
-b"\x9D\xD8\xAB\x4B" => "Background",
-b"\xDD\x2F\x9D\xCE" => "BackgroundMaskShapeLarge",
-b"\xC1\x1E\x95\x55" => "BackgroundMaskShapeSmall",
-b"\x99\xA4\xFA\xB5" => "BackgroundMaskShapeSpawn",
-b"\x2F\xE2\x68\xDF" => "BackLeft",
-b"\x20\xB0\x73\x1C" => "BackRight",
-b"\xDB\x9A\xE2\xA0" => "BadName",
-b"\x38\x85\x6B\xDE" => "BallAcceleration",
-b"\x3C\x0E\x72\x8F" => "BallCollision",
-b"\x34\x39\x8D\x38" => "BallJumpForwardBoost",
-b"\x0B\x5A\xAA\x2D" => "BallJumpHeight",
-b"\x85\xAB\xD9\x19" => "BallLowResModel",
-b"\x5E\x8F\xDA\xD2" => "BallMaxLean",
-b"\xE0\x5E\x89\xED" => "BallMinMoveSpeed",
-b"\x0C\xDB\xAC\xAC" => "BallMoveSpeed",
-b"\x64\xA1\xA7\x09" => "BallMoveThreshold",
-b"\x7A\xBB\x95\x98" => "BallRadius",
-b"\x67\xDE\x60\x65" => "BallRollingFriction",
-b"\xD9\x4D\x52\xDC" => "BallSlippage",
-b"\xD6\x6E\xBB\xBE" => "BallSprintSpeedBoost",
-b"\xC2\x79\x5E\x8A" => "BallSprintTurnSpeed",
-b"\x3D\xE7\xA7\x12" => "BallStoppedTurnSpeed",
-b"\xE6\x6A\x8F\x57" => "BallTurnSpeed",
-b"\x31\x99\x22\xAC" => "BallWaterDamageHeight",
-b"\x72\x97\x73\x91" => "Bandwidth",
-b"\x7E\x9F\x7C\x6D" => "BankAngle",
-b"\xF1\x28\xDE\x35" => "BankFilter",
-b"\x91\x6D\xD4\x3B" => "BarBitmap",
-b"\xB2\x84\xCC\xFF" => "BarrageDelay",
-b"\x4B\xE4\xBA\x04" => "BarrageMax",
-b"\x35\xEC\xA5\x1A" => "BarrageMin",
-b"\xA9\x5E\x09\xEF" => "BarrelLength",
-b"\x12\x4B\x53\x1E" => "BarrelNodeName",
-b"\xEE\x58\xED\x78" => "BarrelOffset",
-b"\xCB\xD1\x29\x5A" => "BarrelRecoil",
-b"\xE8\xCA\x98\x22" => "BarrelSound",
-b"\x58\xC1\x4F\x67" => "BarrelSoundFadeInTime",
-b"\x43\xA1\x1C\x0F" => "BarrelSoundFadeOutTime",
-b"\x1A\xF3\x4B\x4B" => "BarSegmented",
-b"\xD8\x94\xDC\x3D" => "Base",
-b"\x42\xDE\xF8\xAB" => "Baseline",
-b"\x24\x93\xFF\xCF" => "battle_chatter_template",
-b"\xFC\x71\xD7\x74" => "battlechatter",
-b"\xC7\xE7\x09\xDD" => "bazarre_sprays",
-b"\x19\x60\x82\x5D" => "Beacon",
-b"\xC8\x0D\x00\x4F" => "BeadColor",
-b"\x04\xB4\xBF\xAD" => "BeadCount",
-b"\x5E\x31\xE8\x3F" => "BeadModulate",
-b"\x8C\xEF\x7B\xAD" => "BeadSize",
-b"\xD0\x2A\xBC\x52" => "BeadSpeed",
-b"\x64\x41\x0C\x35" => "BeadTexture",
-b"\xFA\x79\x52\xA7" => "Beam",
-b"\xCD\x3A\x09\xA3" => "BeamColor",
-b"\xA6\xE4\x57\xEE" => "BeamGlowColor",
-b"\x36\xC3\xBF\xC6" => "BeamGlowTexture",
-b"\xD9\x9A\x8B\x39" => "BeamGlowWidth",
-b"\xAB\x6F\x09\x75" => "BeamIntensity",
-b"\xED\x98\x9D\xFE" => "BeamTexture",
-b"\x46\xE1\x87\x74" => "BeamWidth",
-b"\x09\x8A\xED\xDC" => "Behind",
-b"\xF2\xC0\x53\x92" => "Below",
-b"\xB9\x79\xDD\x94" => "bendconstraint",
-b"\xC4\x7E\x46\xBA" => "Bias",
-b"\x00\x21\x4C\x04" => "Bidirectional",
-b"\xEF\xD9\x02\x5B" => "big_glow",
-b"\x44\x7D\x4C\xB9" => "bigartheap",
-b"\x64\xFA\x06\xE5" => "BILLBOARD",
-b"\x27\x88\x4F\x52" => "Binoculars",
-b"\xB2\x28\x71\x97" => "Bird",
-b"\xD0\x18\xB5\x6D" => "BirdScatter",
-b"\x9F\x2C\x42\x24" => "BirdScatterSound",
-b"\x99\xD3\xD0\x3C" => "BirdSound",
-b"\x26\x46\x54\x46" => "Bitmap",
-b"\xFB\x65\xCE\x47" => "BitmapHAlign",
-b"\x94\xA7\x5F\x62" => "BitmapRect",
-b"\xEB\x0E\x8F\xA2" => "BitmapStyle",
-b"\xBD\xC6\x47\x8F" => "BitmapVAlign",
-b"\x61\xD4\x7A\x96" => "BitmapWidthHeight",
-b"\xA6\xC9\x9E\x99" => "BlendInTime",
-b"\xAB\x4E\x78\xFA" => "BlendMode",
-b"\x7D\x7A\x1A\x8D" => "BlendOutTime",
-b"\x92\xFA\x68\xF6" => "BlendTimeIn",
-b"\xCB\x02\xB9\x66" => "BlendTimeOut",
-b"\x50\xD0\x39\x17" => "BlendTimeTo",
-b"\xAB\x17\x71\x6A" => "BlockSize",
-b"\xCD\xF5\xFB\x82" => "Blue",
-b"\x27\x80\x57\xD6" => "blue_blaster_bolt",
-b"\xFF\x69\x4A\x4A" => "blue_blaster_bolt_small",
-b"\x68\x13\x88\x33" => "Blue_halo",
-b"\x78\x8B\xFE\x25" => "BlueFlame",
-b"\x98\xD1\xFB\x6B" => "Blur",
-b"\x09\x29\x28\xB9" => "BlurEffect",
-b"\x57\x60\xD4\x1E" => "BlurEnable",
-b"\xA4\xB7\x0F\x9D" => "BlurLength",
-b"\xD6\xE4\x71\x79" => "BlurRes",
-b"\xA4\xC6\xAF\x04" => "BlurStart",
-b"\x29\xD2\x1F\xFC" => "BlurValue",
-b"\x15\x18\x34\x35" => "BobOrient",
-b"\x65\x43\x1C\x9C" => "BobRSlope",
-b"\x03\x44\x82\x49" => "BobSlope",
-b"\x75\x3F\x14\x37" => "BobTime",
-b"\x93\xC0\xEB\x74" => "BobVector",
-b"\x75\x79\xAA\xDB" => "Body",
-b"\xB4\xC1\xF6\x5D" => "body_movement_fall_template",
-b"\x76\x86\x7C\x7B" => "body_movement_metal_template",
-b"\x3A\xAE\x30\xFE" => "body_movement_template",
-b"\xEA\x0B\x0E\x32" => "body_movement_water_template",
-b"\xD0\x49\xDC\x70" => "BodyFall",
-b"\xED\x48\x34\xBF" => "BodyOmegaXDampFactor",
-b"\xBE\xEC\xA2\xA8" => "BodyOmegaXSpringFactor",
-b"\xEB\x1F\x04\x29" => "BodyOmegaZDampFactor",
-b"\xFC\x15\xBE\x29" => "BodyOmegaZSpringFactor",
-b"\xD0\x81\xFE\xEA" => "BodySpringLength",
-b"\x49\xD1\xD6\x77" => "BodySpringLengthRadiusMultiplier",
-b"\xDF\x70\xBF\xD3" => "BodyVelocityDampFactor",
-b"\x30\x14\x91\xF8" => "BodyVelocitySpringFactor",
-b"\x46\xDD\x96\xCE" => "Bolt",
-b"\xC9\x4D\x7A\x08" => "BonesPerTentacle",
-b"\x56\x16\x48\x97" => "BonusAmplification",
-b"\xAB\x3F\xDE\x21" => "BonusColor",
-b"\x20\x19\x00\x7B" => "BoostAcceleration",
-b"\xEF\x2A\x1B\x3A" => "BoostFOV",
-b"\x13\xD0\xE2\x79" => "BoostSound",
-b"\x07\x07\x90\xA5" => "BoostSpeed",
-b"\x68\x8E\x35\x2C" => "BoostTurnMultiplier",
-b"\xCE\xDA\x5A\x8F" => "BoostTurnSpeed",
-b"\xB3\xBE\x62\x5D" => "bootlagger",
-b"\x37\x32\x52\x1F" => "Border",
-b"\xC6\x8F\xB4\x44" => "BorderDiffuseColor",
-b"\x43\x5A\xA0\xB3" => "BorderedBox",
-b"\xC6\xAC\x50\x68" => "BorderOdf",
-b"\x77\xE0\x81\xE1" => "Bots",
-b"\x2A\x6B\xA7\x4E" => "Bottom",
-b"\xBD\x8C\xAA\x84" => "BottomColor",
-b"\x71\x76\x43\xBE" => "BottomDirectionalAmbientColor",
-b"\x6F\x70\x02\x5C" => "BottomDrop",
-b"\x97\x07\x78\x94" => "BottomRange",
-b"\x90\xC9\xD6\xB7" => "bound01",
-b"\x49\xCE\xD6\xBA" => "Bound02",
-b"\xB6\xCC\xD6\xB9" => "Bound03",
-b"\xA7\xCC\xEA\x54" => "Boundary",
-b"\xCC\x92\xFB\x8B" => "boundary01",
-b"\x85\x97\xFB\x8E" => "boundary02",
-b"\x22\x10\xA0\x43" => "boundary1",
-b"\x2B\xDF\xBF\xCB" => "BoundingRadius",
-b"\x3F\xC5\x6A\x4A" => "BowlingPush",
-b"\x32\x7E\xC6\x70" => "Box",
-b"\x3B\x39\xA4\x35" => "BoxSize",
-b"\x22\x05\x2B\xF2" => "BranchDifferent",
-b"\x26\xB3\xC9\xCE" => "BranchFactor",
-b"\x95\xF5\x09\xF6" => "BranchLength",
-b"\x1F\x96\xD3\x97" => "BranchPaths",
-b"\xFC\xBB\x42\x57" => "BranchProbability",
-b"\xFD\xBD\x54\x75" => "BranchRegion",
-b"\x69\x3A\x52\x43" => "BranchSpreadFactor",
-b"\x78\x81\x64\xC9" => "Break",
-b"\x93\x39\x81\x8F" => "BreakDistance",
-b"\x9E\x52\x50\xDB" => "BrightnessMin",
-b"\xDE\x37\x68\x73" => "BrightStarPercent",
-b"\x28\xE8\xF2\xD6" => "brnbeep1",
-b"\x01\x32\x00\x8B" => "BuffDefenseEffect",
-b"\x2E\xB6\x5F\xB2" => "BuffDefenseMult",
-b"\xC1\xFC\x05\x77" => "BuffDefenseTimer",
-b"\x37\x95\x83\x32" => "BuffHealthEffect",
-b"\x18\x96\x63\xB6" => "BuffHealthRate",
-b"\x3B\x14\x0B\x46" => "BuffHealthTimer",
-b"\x23\xDF\x84\x5B" => "BuffOffenseEffect",
-b"\x64\x2E\x53\xCE" => "BuffOffenseMult",
-b"\xAF\x04\xB2\x70" => "BuffOffenseTimer",
-b"\x7B\xA0\xD8\x2F" => "BuildAnimation",
-b"\x75\x03\xDF\x38" => "Building",
-b"\x5F\x86\x5F\xA2" => "BuildingAmmo",
-b"\x53\x7F\xCC\x09" => "BuildingBuild",
-b"\x3F\xF4\x28\x98" => "BuildingCollision",
-b"\x43\x95\xF2\x8C" => "BuildingCollisionPrim",
-b"\x05\x39\x2C\xDA" => "BuildingEnergy",
-b"\x9F\x9E\xC5\xA6" => "BuildingHealth",
-b"\x20\x62\xEC\x03" => "BuildingRebuild",
-b"\x61\x8F\x16\x02" => "BuildingScale",
-b"\x5C\x1A\x9B\xD8" => "BuildingSection",
-b"\xE4\x6F\xA1\xEB" => "BuildingSound",
-b"\x87\x51\x8B\x13" => "BuildModelOdf",
-b"\xAB\x05\x90\x61" => "BuildPoint",
-b"\xB5\xBB\xB4\x06" => "BuiltCollision",
-b"\x12\xB3\xAA\x66" => "BuiltSound",
-b"\x79\xA3\x94\xE8" => "Bullet",
-b"\xE1\xF4\xAC\xA9" => "BumpMap",
-b"\x39\x50\xD6\x11" => "BumpMapTextures",
-b"\x24\x6C\xAF\x08" => "BumpTexture",
-b"\x18\xB4\xCB\x33" => "BurstCount",
-b"\x14\x33\xFC\x78" => "BurstDelay",
-b"\xF9\x6D\xD5\x5F" => "Bus",
-b"\x71\x74\xB2\x43" => "Button",
-b"\x8C\xC9\xD3\xBF" => "ButtonsPressed",
-b"\x31\x39\x2B\x80" => "ButtonsReleased",
-b"\x35\x97\xCB\x4D" => "ByTime",
-b"\x52\x2C\x0C\xE6" => "C",
-b"\xB2\x46\xB7\xD9" => "CableLength",
-b"\xCA\x64\x45\x1C" => "CameraAxialVelocityScale",
-b"\xFC\xDC\xE3\xC3" => "CameraBlendTime",
-b"\x79\x9C\x36\x8F" => "CameraCrossVelocityScale",
-b"\x35\x3B\x2C\x4E" => "CameraDistance",
-b"\xE5\x5F\x20\x09" => "CameraHeight",
-b"\xF1\x6B\xCA\xBA" => "CAMERASECTION",
-b"\xB3\x23\xD0\xBE" => "Campaign",
-b"\xB9\xE6\x1D\x00" => "CampaignLayout",
-b"\xF8\xEA\x69\xD1" => "CampaignName",
-b"\xE0\x4C\xD1\xB0" => "CanDeflect",
-b"\x1C\xDF\xA2\x27" => "Cannon",
-b"\x25\x4E\x26\xE5" => "CAPITOL",
-b"\xE6\x02\x7A\xF1" => "CapturedSound",
-b"\x28\x47\x9B\x6B" => "CaptureMusic",
-b"\x20\x7C\x5F\x32" => "CapturePosts",
-b"\xF9\x61\xC1\xC6" => "CaptureRegion",
-b"\xF8\xCB\x1B\xEB" => "CaptureTime",
-b"\xA4\x4D\x2C\x3E" => "CargoNodeName",
-b"\xFC\x89\x0A\x91" => "CargoNodeOffset",
-b"\x56\x9F\x22\xCC" => "CarriedColorize",
-b"\xDE\xA8\xCE\x05" => "CarriedGeometryName",
-b"\x9E\x8A\xA4\x58" => "CarriedOffset",
-b"\xEE\x0A\xE9\xE7" => "CastShadow",
-b"\xBB\x62\x91\x48" => "CastSpecular",
-b"\x91\xEE\x27\x71" => "Catapult",
-b"\xDA\x2D\x5D\x1C" => "CenterDeadZoneRadius",
-b"\xF5\xB2\x1D\x33" => "Centered",
-b"\xC1\x78\x71\xC7" => "ChangeModeSound",
-b"\x57\x22\xA1\xCD" => "CharacterAmbientColor",
-b"\x71\x23\xCA\xC0" => "Charge",
-b"\x7B\xAC\x03\xF3" => "ChargeDelayHeavy",
-b"\x08\x9F\x6C\x51" => "ChargeDelayLight",
-b"\x16\x88\x46\xD8" => "ChargedSound",
-b"\xF4\xAE\x68\x26" => "ChargeMaxTime",
-b"\x6E\xAB\x93\xD1" => "ChargeMinStrength",
-b"\x8E\xBF\xDD\x60" => "ChargeRateHeavy",
-b"\x6D\x1D\x70\xF5" => "ChargeRateLight",
-b"\x08\x85\xE5\x4E" => "ChargeSound",
-b"\xAE\x6D\x58\x12" => "ChargeSoundPitch",
-b"\x30\x96\xA2\x96" => "ChargeSoundStop",
-b"\x5D\xF7\xC5\x18" => "ChargeUpEffect",
-b"\x3D\x0B\xA7\x4B" => "Chase",
-b"\xEC\x46\xF4\x18" => "cheating",
-b"\x88\x33\x0A\xA2" => "ChildAsteroid",
-b"\xAB\x68\x7B\xC3" => "ChildBreakDistance",
-b"\xF0\x1F\x27\x33" => "ChildColor",
-b"\xF1\xBE\x66\x83" => "ChildSpreadFactor",
-b"\xE5\x0B\xE4\x17" => "ChildTextureSize",
-b"\x27\x2F\xBB\x30" => "ChildWidth",
-b"\xD7\x03\x3A\x53" => "Choke",
-b"\xD2\x45\x92\xAD" => "ChokeEffect",
-b"\xAE\xB8\x0F\xDF" => "ChokeSound",
-b"\xC2\x3D\xBF\x80" => "CHUNK",
-b"\x89\x85\x0E\xA0" => "CHUNK1",
-b"\xD0\x80\x0E\x9D" => "CHUNK2",
-b"\x63\x82\x0E\x9E" => "CHUNK3",
-b"\x42\x8A\x0E\xA3" => "CHUNK4",
-b"\xD5\x8B\x0E\xA4" => "CHUNK5",
-b"\x1C\x87\x0E\xA1" => "CHUNK6",
-b"\xAF\x88\x0E\xA2" => "CHUNK7",
-b"\x8E\x90\x0E\xA7" => "CHUNK8",
-b"\x21\x92\x0E\xA8" => "CHUNK9",
-b"\x8E\xEC\x3B\x0E" => "ChunkAngularDamping",
-b"\xD5\xFF\xF5\xC2" => "ChunkBounciness",
-b"\x9B\xB7\x35\xED" => "ChunkDeathSpeed",
-b"\x14\x00\xB5\x43" => "ChunkFrequency",
-b"\x55\x84\x32\xDA" => "ChunkGeometryName",
-b"\x88\x9D\x25\xD0" => "ChunkGravity",
-b"\xAD\xA3\xA3\x10" => "ChunkInitialCollisionSound",
-b"\xF1\x3D\x4F\xC7" => "ChunkKeepSoldierCollision",
-b"\x11\xFC\x17\x4F" => "ChunkLifetime",
-b"\x07\xED\xB5\xFE" => "ChunkLinearDamping",
-b"\xFD\x14\xF0\xEB" => "ChunkNodeName",
-b"\x39\x69\x6F\x16" => "ChunkOmega",
-b"\xC3\x2D\xE3\xC8" => "ChunkPhysics",
-b"\xAD\xF1\xA4\xE2" => "ChunkScrapeCollisionSound",
-b"\x3D\xE0\x1D\xDA" => "CHUNKSECTION",
-b"\xC2\xEB\xDA\x1C" => "ChunkSimpleFriction",
-b"\xDE\xC3\x50\xD3" => "ChunkSmokeEffect",
-b"\x10\x04\x2E\x47" => "ChunkSmokeNodeName",
-b"\x15\x1D\x5D\x94" => "ChunkSpeed",
-b"\x01\xE4\x61\xCD" => "ChunkStartDistance",
-b"\x4E\xE3\x4A\xB9" => "ChunkStickiness",
-b"\xE2\x7B\x15\x0A" => "ChunkTerrainCollisions",
-b"\x26\x12\x8F\x04" => "ChunkTerrainEffect",
-b"\x4B\x54\xF0\x94" => "ChunkTrailEffect",
-b"\xE9\x54\xFB\xA8" => "ChunkTrailNodeName",
-b"\x10\xAE\x16\xC3" => "ChunkUpFactor",
-b"\x92\x4D\x57\xB3" => "ChunkVelocityFactor",
-b"\x89\x70\x21\x28" => "Circle",
-b"\x9D\xB1\x90\x04" => "CircleATATFrequency",
-b"\xE0\x23\x67\x02" => "Circular",
-b"\xD6\xC5\x87\xF3" => "CIS",
-b"\x59\xEC\x3A\x42" => "cis_halo",
-b"\xD3\xD4\x93\x54" => "cis_hero_countdooku",
-b"\x00\x7C\xA8\x0F" => "cis_hero_darthmaul",
-b"\xDC\x26\x47\x10" => "cis_hero_grievous",
-b"\x33\x89\x51\x5F" => "cis_sfx_droid_explosion",
-b"\x62\x92\x81\xA4" => "cis_sfx_droideka_explosion",
-b"\x6B\xF7\x0C\x56" => "cis_sfx_jetpack",
-b"\x4E\x4D\xCA\x7E" => "cis_sfx_sbd_missiles_red",
-b"\xED\x60\x8E\xAF" => "CISMusic",
-b"\xA7\xC0\x64\x10" => "ClankLeftRunSound",
-b"\x81\x39\x7F\xB7" => "ClankLeftWalkSound",
-b"\xE4\x91\xA9\x10" => "ClankRightRunSound",
-b"\x18\xFC\x89\xB9" => "ClankRightWalkSound",
-b"\xFF\x0B\x3E\xAB" => "Class",
-b"\x4A\x95\xAC\x60" => "Class1",
-b"\xB7\x93\xAC\x5F" => "Class2",
-b"\x24\x92\xAC\x5E" => "Class3",
-b"\x91\x90\xAC\x5D" => "Class4",
-b"\xFE\x8E\xAC\x5C" => "Class5",
-b"\xDC\xED\x29\xDD" => "ClassAllATK",
-b"\x61\x6B\x45\x2A" => "ClassAllDEF",
-b"\xC6\x09\xC9\xA2" => "ClassCISATK",
-b"\x03\x33\x97\x9E" => "ClassCISDEF",
-b"\x79\x7A\xC4\xE2" => "ClassHisATK",
-b"\xAC\xED\x36\x98" => "ClassHisDEF",
-b"\x8B\xFC\xD2\x54" => "ClassImpATK",
-b"\x06\x7F\xB7\x07" => "ClassImpDEF",
-b"\x27\x47\xD0\x92" => "ClassLabel",
-b"\x57\xD8\x18\xD1" => "ClassLocals",
-b"\xA7\x6C\x2C\xD9" => "ClassLocATK",
-b"\x12\x2A\xA9\xCA" => "ClassLocDEF",
-b"\x3E\x43\xDB\x8C" => "ClassNeutral",
-b"\x04\x50\xF7\x24" => "ClassRepATK",
-b"\x79\x02\xC3\x61" => "ClassRepDEF",
-b"\xCC\x7C\x1C\xC6" => "ClearLockOnFire",
-b"\x62\x45\xCF\x4F" => "CloseSound",
-b"\x0B\xFC\x25\x56" => "ClothingRustleSound",
-b"\xDA\x5E\xD2\xF4" => "ClothODF",
-b"\x98\x6D\x83\xCD" => "CloudCluster",
-b"\x53\xA0\xE6\x3C" => "CloudLayer",
-b"\x77\x7D\xA3\x64" => "CockedAngle",
-b"\x32\xA2\x3E\x49" => "Cockpit",
-b"\x22\xC8\xE0\x1F" => "Cockpit1stPersonSound",
-b"\xDF\x37\x13\x42" => "Cockpit3rdPersonSound",
-b"\x5F\x70\x78\x82" => "CockpitChatterStream",
-b"\xB8\xD1\x4C\x10" => "CockpitTension",
-b"\xD4\xA2\x0B\x6E" => "CodeInitialWidth",
-b"\xAE\xDB\xE9\x74" => "Collidable",
-b"\xF1\x24\xC5\xB8" => "CollisionFoliageSound",
-b"\xBA\x42\xAB\x33" => "CollisionHeadOffset",
-b"\xA1\xC3\x90\x4F" => "CollisionHeadScale",
-b"\x32\x37\xF0\x2E" => "CollisionInflict",
-b"\x8C\x31\x97\x53" => "collisionName",
-b"\x1A\x25\xC6\xED" => "CollisionOtherSound",
-b"\xAB\x53\x4A\xC6" => "CollisionRootScale",
-b"\xCF\xBA\x87\x1A" => "CollisionScale",
-b"\x2D\xCB\x6B\x75" => "CollisionShieldSound",
-b"\x96\x0C\x8F\xC2" => "CollisionSound",
-b"\x8A\x58\xDB\x2C" => "CollisionThreshold",
-b"\x7D\x5C\x3A\x46" => "CollisionTorsoOffset",
-b"\x20\xE3\x99\xB1" => "CollisionTorsoScale",
-b"\x7D\xE4\x97\x22" => "CollisionWaterSound",
-b"\x58\x62\x7E\x3D" => "Color",
-b"\x30\x6D\x65\x3C" => "ColorChange",
-b"\x72\x08\x66\xB4" => "ColorChangeRate",
-b"\x17\x13\x14\x70" => "ColorFriendly",
-b"\xC6\x14\x71\x93" => "ColorInterp",
-b"\x8A\x0F\xF5\x22" => "ColorizeByTeam",
-b"\x1B\x10\x59\xE4" => "ColorPulseRate",
-b"\xC0\xC5\x7E\x0D" => "ColorSaturation",
-b"\x5D\x47\xCE\xBB" => "ColorVariation",
-b"\x6A\xF0\xE6\x1B" => "com_icon_alliance",
-b"\x94\x0A\x3E\x07" => "com_icon_CIS",
-b"\x5A\x8C\x19\xF5" => "com_icon_imperial",
-b"\x17\xBD\xB9\x86" => "com_icon_republic",
-b"\xE8\x88\xC2\xD6" => "com_sfx_bif",
-b"\x37\x5E\x25\xEF" => "com_sfx_blammo",
-b"\x19\x30\x21\xC1" => "com_sfx_buff_defense",
-b"\xDF\x18\x2F\x70" => "com_sfx_buff_offense",
-b"\x9F\xE1\x3E\xB5" => "com_sfx_buff_poison",
-b"\x20\x8A\x6D\xF7" => "com_sfx_buff_regen",
-b"\xAC\xF3\xB9\x28" => "com_sfx_buffed_defense",
-b"\x1A\xB1\x7A\xC1" => "com_sfx_buffed_offense",
-b"\x18\x40\xA1\xCA" => "com_sfx_buffed_poison",
-b"\xE9\x84\x3E\xBB" => "com_sfx_buffed_regen",
-b"\x56\x1C\x08\x23" => "com_sfx_chargeup",
-b"\xEA\xF4\x62\x13" => "com_sfx_chunksmolder",
-b"\xEC\xD6\x40\x2F" => "com_sfx_chunktrail",
-b"\x39\x37\x55\x6A" => "com_sfx_chunktrail_sm",
-b"\x10\x74\x3F\x6C" => "com_sfx_chunktrail_xs",
-b"\xDF\xB9\x52\xD4" => "com_sfx_contrail",
-b"\x95\xBC\x53\x32" => "com_sfx_dirtspray_lg",
-b"\x70\x87\x51\x38" => "com_sfx_dirtspray_sm",
-b"\x07\x7B\xEB\xA6" => "com_sfx_dustwake",
-b"\x49\xBD\x25\x36" => "com_sfx_exhaust_cloudcar",
-b"\x37\xDA\x3D\xA7" => "com_sfx_exhaust_jedistarfighter",
-b"\x84\xC2\xD7\xFD" => "com_sfx_exhaust_jedistarfighter_blue",
-b"\x82\xAE\xF3\xE3" => "com_sfx_exhaust_kwing",
-b"\x37\xDF\x47\xF3" => "com_sfx_exhaust_xwing",
-b"\x6A\xF5\xC1\x37" => "com_sfx_explosion_lg",
-b"\x8B\x9C\xB0\x2D" => "com_sfx_explosion_sm",
-b"\x2B\xD2\xA3\x2E" => "com_sfx_explosion_xl",
-b"\xA2\xE6\xA3\x3B" => "com_sfx_explosion_xs",
-b"\xF0\x91\xD5\xE3" => "com_sfx_fireflies",
-b"\x66\x39\xD4\xEB" => "com_sfx_forcechoked",
-b"\xB4\x08\x0E\x44" => "com_sfx_forcepulled",
-b"\x97\x1C\x3F\x83" => "com_sfx_forcepushed",
-b"\xEA\xCB\xC3\x75" => "com_sfx_groundcrack",
-b"\x3D\x86\x7E\xBB" => "com_sfx_hackedvehicle",
-b"\x70\xCF\xBB\x72" => "com_sfx_inf_damage_disintergrate",
-b"\x54\x84\xA9\x0D" => "com_sfx_inf_damage_electricity",
-b"\x27\x9E\xB4\x7C" => "com_sfx_inf_damage_fire",
-b"\x8C\x47\x3A\x83" => "com_sfx_inf_damage_smoke",
-b"\xFC\x3F\x5A\xEA" => "com_sfx_inf_disintergrate",
-b"\x2D\x02\x1F\x95" => "com_sfx_landmine",
-b"\x57\x95\x9F\x11" => "com_sfx_lightning",
-b"\x07\x6D\x47\xA0" => "com_sfx_mineblink_blue",
-b"\xA1\x7E\x5D\xAB" => "com_sfx_mineblink_gold",
-b"\x52\x18\xE2\x86" => "com_sfx_mineblink_green",
-b"\x71\xA7\xFC\xBE" => "com_sfx_mineblink_purple",
-b"\x92\xB4\xBA\xFA" => "com_sfx_mineblink_red",
-b"\xE4\xED\xC6\x81" => "com_sfx_muzzleflash_lg",
-b"\xDE\xCB\xAF\x8F" => "com_sfx_ord_exp",
-b"\xEA\x90\xD2\xD5" => "com_sfx_ord_flame",
-b"\x0F\x86\xA9\xC3" => "com_sfx_pow",
-b"\xF2\xC2\x26\x9D" => "com_sfx_powerup_blue",
-b"\xC5\xA1\x96\x97" => "com_sfx_powerup_green",
-b"\x31\xAE\x72\x2A" => "com_sfx_powerup_red",
-b"\x36\xC0\x47\xFB" => "com_sfx_protontorpedo",
-b"\x72\x89\x6E\xC6" => "com_sfx_rainsplash",
-b"\x12\x89\x5E\x99" => "com_sfx_repair",
-b"\x29\xB9\x7B\x2E" => "com_sfx_sabredeflect",
-b"\x63\x35\x11\xA0" => "com_sfx_sabrehit",
-b"\xC5\x3F\x50\xB3" => "com_sfx_shieldimpact_sm",
-b"\x69\x1A\x39\xD4" => "com_sfx_shieldimpact_xl",
-b"\xF9\x98\xE5\x3F" => "com_sfx_smokeplume",
-b"\x7E\xA9\x82\x1F" => "com_sfx_timebomb",
-b"\x2D\x7B\xD1\xE7" => "com_sfx_vehicle_exp",
-b"\xA4\xEF\x86\x75" => "com_sfx_vehicleflame",
-b"\xCF\x6A\xDB\x35" => "com_sfx_vehicleflame_sc",
-b"\xCB\x49\x33\x68" => "com_sfx_vehicleflame_speeder",
-b"\x02\x92\x6C\x62" => "com_sfx_vehiclesmoke",
-b"\x2D\x0E\x5B\xB3" => "com_sfx_vehiclesmoke_sc",
-b"\x85\x61\x1E\xD2" => "com_sfx_vehiclesmoke_speeder",
-b"\x5E\x0D\xFB\x35" => "com_sfx_vehiclespark",
-b"\x59\x8A\x44\x74" => "com_sfx_vehiclespark_sc",
-b"\x59\x30\x6B\x65" => "com_sfx_vehiclespark_speeder",
-b"\xFE\x83\x54\x46" => "com_sfx_walkerstomp",
-b"\xCF\x2D\x93\x4C" => "com_sfx_waterbulletspray",
-b"\x17\x09\xEF\x8F" => "com_sfx_watersplash_lg",
-b"\x7B\x78\xF1\xAE" => "com_sfx_watersplash_md",
-b"\xE1\x89\x54\xA9" => "com_sfx_watersplash_ord",
-b"\x5F\x9C\x36\x6B" => "com_sfx_watersplash_ord_lg",
-b"\xC3\x0B\x39\x8A" => "com_sfx_watersplash_ord_md",
-b"\x6A\xBB\xF5\x89" => "com_sfx_watersplash_sm",
-b"\xFD\xA8\x81\xE2" => "com_sfx_watersplash_wade",
-b"\x78\x2A\xBE\xC8" => "com_sfx_watersplash_walker",
-b"\xA7\xAA\x0B\xA4" => "com_sfx_watersplash_xs",
-b"\x52\x38\x2B\x64" => "com_sfx_waterwake_lg",
-b"\x33\xE9\xF2\x69" => "com_sfx_waterwake_sm",
-b"\xE5\x4A\x2E\x7C" => "com_sfx_weap_arccaster",
-b"\xD8\x66\x22\xC3" => "com_sfx_weap_beam_exp",
-b"\x24\x0E\x01\x5D" => "com_sfx_weap_beam_exp_lg",
-b"\x34\xFD\xFE\x79" => "com_sfx_weap_beam_exp_md",
-b"\x5D\x58\x21\x57" => "com_sfx_weap_beam_exp_sm",
-b"\x11\xA3\x32\x58" => "com_sfx_weap_beam_exp_xl",
-b"\xC8\xB9\x7A\xF0" => "com_sfx_weap_bluebolt_exp_lg",
-b"\x18\x44\x78\xCD" => "com_sfx_weap_bluebolt_exp_md",
-b"\x59\xEC\xAF\xCA" => "com_sfx_weap_bluebolt_exp_sm",
-b"\x9D\x94\x98\xCB" => "com_sfx_weap_bluebolt_exp_xl",
-b"\xC4\xF5\x4F\x62" => "com_sfx_weap_bolt_exp_lg",
-b"\xD4\xE4\x4D\x7F" => "com_sfx_weap_bolt_exp_md",
-b"\xFD\x3F\x70\x5C" => "com_sfx_weap_bolt_exp_sm",
-b"\xB1\x8A\x81\x5D" => "com_sfx_weap_bolt_exp_xl",
-b"\x77\xCC\x7F\x09" => "com_sfx_weap_con_exp_lg",
-b"\xDB\x3B\x82\x28" => "com_sfx_weap_con_exp_md",
-b"\xCA\x11\x88\x03" => "com_sfx_weap_con_exp_sm",
-b"\x86\xD6\x9D\x02" => "com_sfx_weap_con_exp_xl",
-b"\x05\x97\x36\x7D" => "com_sfx_weap_detpack_exp",
-b"\xC5\xE4\x7E\x84" => "com_sfx_weap_emp_exp_lg",
-b"\x75\x5A\x81\xA7" => "com_sfx_weap_emp_exp_md",
-b"\x80\xCE\xCA\x8A" => "com_sfx_weap_emp_exp_sm",
-b"\xD0\xBB\xB0\xA9" => "com_sfx_weap_emp_exp_xl",
-b"\x4A\x22\x8B\xC4" => "com_sfx_weap_empmortar_ord",
-b"\xFE\x63\xC1\xC7" => "com_sfx_weap_flame_exp_lg",
-b"\x5A\x59\xBF\xE8" => "com_sfx_weap_flame_exp_md",
-b"\xF7\xE0\x73\xBD" => "com_sfx_weap_flame_exp_sm",
-b"\x67\xEB\x8F\xDE" => "com_sfx_weap_flame_exp_xl",
-b"\x75\x56\x31\x02" => "com_sfx_weap_flamethrower_ord",
-b"\x4B\xFA\x81\x18" => "com_sfx_weap_greenbolt_exp_lg",
-b"\x1F\x1E\x84\x07" => "com_sfx_weap_greenbolt_exp_md",
-b"\xD6\xB4\x4C\x32" => "com_sfx_weap_greenbolt_exp_sm",
-b"\x32\xDA\x63\x11" => "com_sfx_weap_greenbolt_exp_xl",
-b"\x4C\x00\x6D\x14" => "com_sfx_weap_grenade_blue",
-b"\x9E\x0F\xCF\xE8" => "com_sfx_weap_grenade_emp_exp",
-b"\xA6\x5C\x3E\x61" => "com_sfx_weap_grenade_gold",
-b"\xA3\x44\x9B\xEE" => "com_sfx_weap_grenade_green",
-b"\x6F\x2D\x2E\xF0" => "com_sfx_weap_grenade_radiation_exp",
-b"\x27\xF8\x84\x7B" => "com_sfx_weap_grenade_red",
-b"\x85\x8B\xF1\x10" => "com_sfx_weap_grenade_schrap_exp",
-b"\x6F\xEE\x95\x0B" => "com_sfx_weap_grenade_thermal",
-b"\xFF\xAC\x67\x6C" => "com_sfx_weap_grenade_thermal_exp",
-b"\xD7\xE3\xC2\x05" => "com_sfx_weap_incinerate_ord",
-b"\xC7\x31\x61\x5D" => "com_sfx_weap_incinerate_ord_exp",
-b"\xBF\x99\x29\x26" => "com_sfx_weap_mortartrail_blue",
-b"\x0A\xEF\xAE\x98" => "com_sfx_weap_mortartrail_red",
-b"\x4F\xDF\xF8\xB6" => "com_sfx_weap_mortartrail_yellow",
-b"\xF2\x1B\x24\xB9" => "com_sfx_weap_orangebolt_exp_lg",
-b"\x1E\xF8\x21\xCA" => "com_sfx_weap_orangebolt_exp_md",
-b"\xD3\xCC\xEB\xBE" => "com_sfx_weap_orangebolt_exp_sm",
-b"\x43\x44\x06\xE0" => "com_sfx_weap_orangebolt_exp_xl",
-b"\xE2\x61\x14\xB7" => "com_sfx_weap_purplebolt_exp_lg",
-b"\x0E\x3E\x12\xC8" => "com_sfx_weap_purplebolt_exp_md",
-b"\xA3\x31\x2A\xBD" => "com_sfx_weap_purplebolt_exp_sm",
-b"\x53\xD7\x45\x9E" => "com_sfx_weap_purplebolt_exp_xl",
-b"\x5E\xE7\xA6\x6B" => "com_sfx_weap_rad_exp_lg",
-b"\xBA\x49\xA3\x8C" => "com_sfx_weap_rad_exp_md",
-b"\x57\x64\x59\x61" => "com_sfx_weap_rad_exp_sm",
-b"\xC7\x6E\x75\x82" => "com_sfx_weap_rad_exp_xl",
-b"\xEB\x3F\x33\x6A" => "com_sfx_weap_redbolt_exp_lg",
-b"\xBF\x63\x35\x59" => "com_sfx_weap_redbolt_exp_md",
-b"\x76\x8D\xFF\x83" => "com_sfx_weap_redbolt_exp_sm",
-b"\xD2\x1F\x15\x63" => "com_sfx_weap_redbolt_exp_xl",
-b"\x0D\x4D\xCD\xF6" => "com_sfx_weap_remotedroid_exp",
-b"\xAD\x6E\x53\x23" => "com_sfx_weap_remotedroidcharge2",
-b"\x5D\x7E\x56\xD5" => "com_sfx_weap_rockettrail",
-b"\xFE\x22\x19\x73" => "com_sfx_weap_rockettrail_blue",
-b"\x24\x79\x73\xA0" => "com_sfx_weap_rockettrail_gold",
-b"\x11\xAA\xD7\xD2" => "com_sfx_weap_rockettrail_green",
-b"\x34\xAE\xD9\xBA" => "com_sfx_weap_rockettrail_purple",
-b"\xCF\x60\x20\x16" => "com_sfx_weap_sc_exp_lg",
-b"\x73\x6B\x22\xF5" => "com_sfx_weap_sc_exp_md",
-b"\xC2\xCE\x4F\x20" => "com_sfx_weap_sc_exp_sm",
-b"\x0E\x84\x3E\x1F" => "com_sfx_weap_sc_exp_xl",
-b"\x82\x17\x6F\xED" => "com_sfx_weap_seismiccharge",
-b"\x98\x65\xFF\xBC" => "com_sfx_weap_shiplaser_exp_lg",
-b"\xA8\x54\xFD\xD9" => "com_sfx_weap_shiplaser_exp_md",
-b"\x09\x4B\xE5\xD6" => "com_sfx_weap_shiplaser_exp_sm",
-b"\x4A\xBB\x14\x2A" => "com_sfx_weap_sonic_exp_sm",
-b"\x03\x6E\xD5\xF3" => "com_sfx_weap_spyrifle_ord",
-b"\x72\xC3\x89\x4F" => "com_sfx_weap_timebomb",
-b"\x78\x51\x0F\x3A" => "com_sfx_weap_yellowbolt_exp_lg",
-b"\x8A\x77\x16\x66" => "com_sfx_zap",
-b"\xC3\x1B\xBC\x8A" => "com_weap_overheat",
-b"\x43\xCE\x32\x2D" => "ComboAnimationBank",
-b"\x38\x2D\xC0\x45" => "ComboTime",
-b"\x4C\xFE\x57\x18" => "commandarmedanimatedbuilding",
-b"\x54\x22\x14\x47" => "CommandHover",
-b"\xB4\xCD\x4C\x84" => "CommandPost",
-b"\x0A\xD0\xB1\xD8" => "commandpost_amb_template",
-b"\x23\x6C\xF7\xA7" => "CommandPost_capturelost",
-b"\xC8\x53\xCB\xD8" => "CommandWalker",
-b"\x6A\x4B\x40\xDD" => "Cone",
-b"\x49\x3B\xF0\xA3" => "ConeAngle",
-b"\xA7\x35\x74\x85" => "ConeFadeFactor",
-b"\x80\xF6\x52\x2C" => "ConeFadeLength",
-b"\xA9\xA7\xAA\xEA" => "ConeHeight",
-b"\xE2\x54\xBB\x9C" => "ConeInitialWidth",
-b"\x4E\x84\x42\x93" => "ConeLength",
-b"\x16\x10\x7F\x6F" => "ConeWidth",
-b"\xF9\xCC\xE0\xAA" => "Connect",
-b"\x9B\xB2\xAB\x5A" => "Conquest",
-b"\xB8\x94\x11\x7E" => "ConstantBlend",
-b"\x63\xE9\xC7\x84" => "ContrailAttachOffset",
-b"\x76\x1B\xB8\x58" => "ContrailAttachPoint",
-b"\x58\xFA\x0D\x05" => "ContrailEffect",
-b"\x74\x95\xA0\x72" => "ContrailEffectMaxScale",
-b"\x49\x0F\xE6\x64" => "ContrailEffectMaxSpeed",
-b"\x0E\xF0\xCA\x86" => "ContrailEffectMinScale",
-b"\x9F\x59\xEE\x75" => "ContrailEffectMinSpeed",
-b"\x9A\xFC\x71\xB3" => "Controllable",
-b"\x8A\x1F\x5C\x80" => "ControlRegion",
-b"\xC1\x39\xBF\x47" => "ControlSpeed",
-b"\xB0\x6D\x7C\x44" => "ControlZone",
-b"\x07\x94\x0F\x94" => "CoolDownSound",
-b"\xDA\x88\x3D\x3A" => "CoolDownSoundEndPitch",
-b"\x8B\x48\x36\x72" => "CoolDownSoundFadeInTime",
-b"\x7A\x9F\x15\x4E" => "CoolDownSoundFadeOutTime",
-b"\xA1\xC3\xCA\x9E" => "CoolDownTime",
-b"\x65\x6E\xAE\x86" => "cor_movies",
-b"\xAA\x6D\xC7\xBA" => "cor1tran1",
-b"\x24\xD2\xF3\x1F" => "cormon01",
-b"\xDD\xD6\xF3\x22" => "cormon02",
-b"\xBE\x30\xBD\x8E" => "CorrectAimForSteering",
-b"\xF4\xDD\xB1\x39" => "Count",
-b"\x80\x04\xCD\xBA" => "Cover",
-b"\xC7\x8C\x83\xEF" => "CoverOdf",
-b"\x59\xCD\x0E\xBA" => "crashed",
-b"\xA3\x78\xEA\x72" => "crawl1",
-b"\x36\x7A\xEA\x73" => "crawl2",
-b"\x58\x28\x11\xDE" => "crossconstraint",
-b"\x8B\xA5\x4F\x4E" => "Crouch",
-b"\xD7\xC1\xBF\x67" => "CrouchAimSpread",
-b"\x79\x8E\xDE\xFB" => "CrouchMoveSpread",
-b"\x58\xC7\xFE\x81" => "CrouchStillSpread",
-b"\xF4\xB5\x48\xF6" => "CROUCHZOOM",
-b"\xE0\xCB\x2B\xE1" => "CTFDefense",
-b"\x9E\x1B\x8B\x9A" => "CTFOffense",
-b"\x5F\x9D\xBF\xF2" => "CurHealth",
-b"\x80\xFA\xBA\xCB" => "CurShield",
-b"\x6E\x65\x21\x5D" => "Cursing",
-b"\x82\x53\xC2\xA7" => "CustomAnimationBank",
-b"\x03\x18\x19\x0E" => "cw_music",
-b"\x70\xF6\x5A\x4D" => "CyclePlayback",
-b"\xEF\xBF\x1E\xAD" => "Cylinder",
-b"\x40\x4C\xE9\x59" => "Damage",
-b"\xDA\x94\x5C\xDD" => "DamageAttachOffset",
-b"\x81\x81\x69\xCA" => "DamageAttachPoint",
-b"\x6B\x2A\x92\xAF" => "DamageDeduction",
-b"\x1B\xF0\x81\xF0" => "DamageDistance",
-b"\x05\x02\xAF\xE2" => "DamageEdgeLength",
-b"\x0F\xAD\x56\x1F" => "DamageEdgeWidth",
-b"\xD1\xFD\x2B\xAE" => "DamageEffect",
-b"\x85\x6C\xCE\x57" => "DamageEffectScale",
-b"\xE8\x02\xCD\x27" => "DamageEffectSound",
-b"\xE9\x71\x5F\x95" => "DamageFinalDamage",
-b"\x7C\x0B\x6D\xD0" => "DamageInheritVelocity",
-b"\xDC\x11\xB3\xC3" => "DamageLength",
-b"\xA6\x98\x87\xB6" => "DamageRadius",
-b"\xF6\x0D\xD4\xDE" => "DamageRadiusInner",
-b"\x9D\x93\x3B\xA2" => "DamageRadiusOuter",
-b"\xBB\xC3\x41\x72" => "DamageRegionSound",
-b"\x85\x23\x18\x06" => "DamageStartPercent",
-b"\xA9\x5C\xFE\x37" => "DamageStopPercent",
-b"\x73\x44\xA7\xCC" => "DamageThreshold",
-b"\xF9\x99\xFB\x13" => "DamageTime",
-b"\xB2\x61\x45\xA7" => "DamageTransitionDelay",
-b"\xBA\xD1\x08\xEE" => "DamageTransitionPeriod",
-b"\xAC\x67\xB6\xE4" => "DamageWidth",
-b"\x64\x39\x13\xC1" => "Dampening",
-b"\xFC\xC3\xE5\x25" => "DarknessMax",
-b"\x5E\xC2\xFA\x13" => "DarknessMin",
-b"\xA5\xE2\x72\xD8" => "Data",
-b"\xAB\xD7\xF0\xDB" => "dea_movies",
-b"\x2C\x53\xC1\xF1" => "dea1tran1",
-b"\xFA\x48\x43\x37" => "deamon01",
-b"\x67\x47\x43\x36" => "deamon02",
-b"\xB5\x58\xAC\x5F" => "DeathAnimation",
-b"\xD0\x74\xFE\x07" => "DeathAnimationExplosion",
-b"\x69\xB1\x1E\x87" => "DeathAnimationExplosionTime",
-b"\x10\x01\x64\xAE" => "DeathDustDelay",
-b"\xFC\x16\x88\x7C" => "DeathDustEffect",
-b"\xE2\x07\x0C\x06" => "DeathDustOffset",
-b"\x5C\x01\x38\xF1" => "DeathEffect",
-b"\x27\x47\x82\x29" => "DeathEffectOffset",
-b"\x1F\x0A\x4A\x95" => "DeathFadeTimeEnd",
-b"\xCA\x2B\x2D\x5A" => "DeathFadeTimeStart",
-b"\x2E\x63\xF5\x73" => "Deathmatch",
-b"\xD0\x18\xD8\x3F" => "DeathOnCollision",
-b"\x70\xFC\x13\x0C" => "DeathShakeDelay",
-b"\x85\xD9\x80\xBB" => "DeathShakeDuration",
-b"\x8C\x53\xB9\x94" => "DeathShakeForce",
-b"\x8B\x75\xAF\xB3" => "DeathShakeRadius",
-b"\x2C\x50\x9D\x6A" => "DeathSound",
-b"\x0C\x36\xC6\xB4" => "DeathTime",
-b"\xB4\xA7\x34\x98" => "DebuffDamageRate",
-b"\xE7\x51\x5B\x80" => "DebuffDamageTimer",
-b"\x32\xEA\x3F\x1F" => "DebuffEffect",
-b"\xBA\xC3\x23\xF5" => "DebugAnims",
-b"\xD7\xCC\xBE\x9E" => "DebugCombos",
-b"\xAE\xF6\x15\xDE" => "Decal",
-b"\x7E\xB2\xD9\x80" => "DecayHFRatio",
-b"\x3C\x8B\x09\x1C" => "DecayTime",
-b"\x06\xC8\x12\x8B" => "Deceleration",
-b"\xDE\x5B\x3B\x93" => "Default",
-b"\x1F\xDA\x62\x99" => "DefaultSize",
-b"\xC1\x19\xED\x4F" => "Defend",
-b"\xAD\x8B\x5A\x33" => "DefendPositionVO",
-b"\xC0\x01\xE9\xE1" => "Deflect",
-b"\xF3\x6F\x6F\xE4" => "DeflectAngle",
-b"\xEE\x70\xAE\xD9" => "DeflectAnimation",
-b"\x51\x07\x82\xF1" => "DeflectEffect",
-b"\x6F\xFE\x0D\xC2" => "DeflectSound",
-b"\x69\xBE\xD3\x98" => "Degree",
-b"\xB6\xA3\x0D\xE5" => "delfriend",
-b"\xBD\x42\x93\x9B" => "DemoMode",
-b"\x9B\x05\xB9\x72" => "Density",
-b"\x06\xE6\x8F\x6D" => "DenyFlyerLand",
-b"\xEA\x9E\x75\xFE" => "Depth",
-b"\x69\x3B\x6F\x34" => "Description",
-b"\x45\x67\x5B\xC4" => "Destroy",
-b"\x17\xE9\xC6\x5D" => "DestroyedGeometryName",
-b"\xE7\x85\x8C\xEA" => "Destruct",
-b"\xB7\xA7\x31\x6F" => "DestructableBuilding",
-b"\x72\x52\x76\xF9" => "DestructChargeEffect",
-b"\x01\xE4\x1C\xE1" => "DestructTiltValue",
-b"\x71\x27\xE1\x83" => "DestructTrackOffset",
-b"\xAF\x0E\x2E\x3F" => "DetailTexture",
-b"\x6F\xB9\x21\x60" => "DetailTextureScale",
-b"\x03\x47\xDE\x5E" => "DetatchSound",
-b"\x93\xA9\x7F\xB5" => "Detonator",
-b"\xD1\x96\x62\xD8" => "Die",
-b"\xF0\xDC\xCF\x09" => "Difficulty",
-b"\x04\xE8\xA3\xC8" => "Diffusion",
-b"\xFF\x5D\x6C\x11" => "DirectGain",
-b"\xE1\x4A\xC5\x74" => "DirectHFGain",
-b"\x6A\xC7\x6D\xDF" => "Direction",
-b"\xB2\x59\x63\x99" => "DisableAIAutoBalance",
-b"\x94\x4B\xDA\xA8" => "DisabledEffect",
-b"\x34\x42\x01\x6B" => "DisableForCloneWars",
-b"\x43\x92\x1D\xA0" => "DisableLowRes",
-b"\x93\x99\x64\xCF" => "DisableOnEmpty",
-b"\xFA\x94\x16\x1C" => "DisableTime",
-b"\xB7\xF9\x83\x8B" => "Discharge",
-b"\x0E\xA9\xE4\x16" => "DischargeSound",
-b"\xC2\x9D\x07\xB5" => "Disguise",
-b"\xD2\x2E\x3C\x4E" => "DisguiseOwnerModel",
-b"\xA8\xE1\x61\x49" => "Dispenser",
-b"\xE4\x33\xA3\x01" => "DisplayRefire",
-b"\x14\x15\xEF\xDA" => "DisplayTime",
-b"\xAA\xAC\xCA\x12" => "DisputeSound",
-b"\x62\x14\xB3\x2E" => "Distance",
-b"\xE0\xD7\x54\x36" => "DistortionScale",
-b"\x0D\x45\x32\x16" => "DomeColor",
-b"\xC2\xB6\x5A\x18" => "DomeInfo",
-b"\x57\x10\x68\x82" => "DomeModel",
-b"\x98\x83\x64\x93" => "DopplerMode",
-b"\xAD\xEB\x6A\x48" => "DoubleTap",
-b"\x15\xB9\xB9\x3D" => "Down",
-b"\x87\x71\x6C\x7E" => "DownSizeFactor",
-b"\x43\x0D\x8C\xB8" => "Drag",
-b"\x13\xF4\x8B\xA8" => "Draw",
-b"\x4C\xC3\x12\x16" => "DrawDistance",
-b"\x39\x2A\xDB\xED" => "Droid",
-b"\xBB\x4D\xDA\x0F" => "DroidAmmo",
-b"\x7B\x02\x80\x4B" => "DroidekaAttachBone",
-b"\xE5\x47\x04\x05" => "DroidekaShield",
-b"\x79\x34\xE4\x3F" => "DroidEnergy",
-b"\x3B\xC7\x22\x96" => "DroidHealth",
-b"\xCD\x07\x8A\x33" => "DroidScale",
-b"\x75\x2B\x6D\x40" => "DropItemClass",
-b"\xAE\x6D\x99\x26" => "DropItemProbability",
-b"\x28\xDB\x97\x88" => "DropoffSound",
-b"\xBA\x0B\x07\xDA" => "DroppedColorize",
-b"\x60\x4B\x0C\x0E" => "DroppedInLandingZoneGeometryName",
-b"\x99\x53\xAF\x82" => "DropShadowSize",
-b"\xF3\x29\x2F\x5B" => "DropSound",
-b"\xD1\x95\x2A\xD6" => "dumpanimmaps",
-b"\x20\x4B\x5C\xF7" => "DumpMemory",
-b"\x0D\xFD\xA0\x2F" => "Duration",
-b"\x34\xC1\xE4\xC8" => "DustEffect",
-b"\xFA\x9E\x0C\x0E" => "DustParticleSpeed",
-b"\x12\x22\x07\x0A" => "DustVelocity",
-b"\xED\x35\xC1\xE6" => "DustWake",
-b"\x1B\x7A\x53\x05" => "Easy",
-b"\x3C\xD8\xF6\x56" => "Edge",
-b"\xCB\x9C\x0B\xCA" => "EditOnly",
-b"\xE1\x21\x7E\x74" => "editor_grasspatch",
-b"\x43\x01\x23\x50" => "editor_prop_bush",
-b"\x6A\x62\x55\x77" => "EditorGlobalDirIconSize",
-b"\x54\x8D\x6E\x6E" => "Effect",
-b"\x45\x8A\xEA\xD2" => "EffectDamage",
-b"\xFA\xB7\x5E\x3B" => "EffectFalloff",
-b"\xB8\x67\xF2\xA4" => "EffectRegion",
-b"\xBD\xAB\xA1\x53" => "EffectStrength",
-b"\xBC\xC8\x1F\x5C" => "EffectType",
-b"\x07\x3D\xAA\xA6" => "EjectResistance",
-b"\xC7\x53\x87\x8A" => "email",
-b"\xCD\x09\x6B\x57" => "Emitter",
-b"\x55\xEB\x7F\x94" => "EmitterOrdnance",
-b"\x65\x50\x5F\x2C" => "Empire",
-b"\x10\xF7\x38\xA7" => "empire_halo",
-b"\x86\x0A\xAD\x60" => "empire_halo2",
-b"\xEE\xBE\xA7\x18" => "Empty",
-b"\x6D\xCE\xE9\xFD" => "EmptyText",
-b"\xCE\xB8\x8B\xAF" => "Enable",
-b"\x75\x36\xFF\x02" => "EnableBottom",
-b"\x9E\xB3\xF3\x02" => "Enabled",
-b"\xA0\x9B\x4E\x3E" => "EnableDeathExplosions",
-b"\x60\x08\x5D\xC1" => "EnableFadeAdjustWithZoom",
-b"\x1E\xD9\xC8\xE3" => "EnableFlare",
-b"\x4D\xBC\x7A\xC8" => "EnableObject",
-b"\xAA\x75\x8E\x6A" => "End",
-b"\xF7\x54\x6F\x26" => "EndLaserGlowColor",
-b"\xB1\xCC\xE0\x96" => "EndLightColor",
-b"\x0A\x12\xE6\x82" => "EnemyColor",
-b"\x95\x50\xCC\x29" => "Energy",
-b"\xB4\x8B\x9D\x4F" => "EnergyAutoRestore",
-b"\x6A\x5D\x10\x97" => "EnergyBar",
-b"\xF6\x56\x5F\x64" => "EnergyBoostDrain",
-b"\x88\x17\x5B\x49" => "EnergyCost",
-b"\x18\xE1\x31\x24" => "EnergyCostBowling",
-b"\xD4\x7F\x4A\x0B" => "EnergyCostJump",
-b"\x28\x8F\x4B\xA4" => "EnergyCostJumpSprint",
-b"\x33\xCD\x24\xCA" => "EnergyCostRoll",
-b"\x29\x86\x66\x17" => "EnergyDrain",
-b"\x5B\x41\x09\x89" => "EnergyDrainRate",
-b"\xB9\x61\x43\x13" => "EnergyDrainSprint",
-b"\xE3\x0A\xA2\x80" => "EnergyGainEffect",
-b"\xBB\xFA\x3F\xF0" => "EnergyMinSprint",
-b"\xF5\xFB\x33\xA7" => "EnergyOverheat",
-b"\x01\xD3\x2E\x94" => "EnergyRestore",
-b"\x37\xAF\x2E\x08" => "EnergyRestoreIdle",
-b"\x53\x9E\xB7\x11" => "EnergyRestoreRate",
-b"\xE4\x2E\xC7\x89" => "EnergyTrickDrainDoubleTap",
-b"\x8F\xE1\xD7\x4C" => "EnergyTrickDrainSingleTap",
-b"\x6A\xEF\xBB\x84" => "EngineSound",
-b"\xBE\x2F\x1E\xB7" => "EntityLight",
-b"\x4F\xEB\x9A\xA9" => "EnvTexture",
-b"\x19\x3E\x46\xBE" => "ERDelay",
-b"\x89\x59\xC6\xF2" => "ERGain",
-b"\xC1\x55\xB3\x41" => "EventAlpha",
-b"\x64\xE3\xD8\x5C" => "EventBitmap",
-b"\xF6\x0E\x1B\x21" => "EventBlend",
-b"\xCD\x42\x1D\x0D" => "EventChanged",
-b"\xD4\x3B\x53\x0D" => "EventChangeMapMode",
-b"\xAE\x29\xF4\x49" => "EventColor",
-b"\xB1\x76\x5F\x7B" => "EventDisable",
-b"\xA8\x96\x30\xD2" => "EventEnable",
-b"\xBB\x3C\xE2\xB8" => "EventFadeOut",
-b"\xED\x18\xE7\xCC" => "EventInput",
-b"\xBE\xDD\x63\x9D" => "EventInputFactor",
-b"\xF2\xAD\x45\xF8" => "EventMesh",
-b"\xBE\x8D\xE6\x4E" => "EventNameFilter",
-b"\x9E\xDF\xC8\x3F" => "EventNumber",
-b"\x32\xED\x03\x69" => "EventOutput",
-b"\x82\xF6\x80\x3C" => "EventPlayerIndex",
-b"\x1C\xB0\xB5\x7E" => "EventPosition",
-b"\xE9\xD5\x95\x6D" => "EventPostHide",
-b"\xCA\x45\xD9\xCC" => "EventPulseRate",
-b"\xB8\x58\xFD\x6F" => "EventRefreshMarker",
-b"\x04\x41\x7B\x83" => "EventRefreshPost",
-b"\x43\x4F\x30\xA9" => "EventRefreshTarget",
-b"\xE6\xCE\xC1\x92" => "EventResetTargetCommon",
-b"\x5E\x21\x34\x9D" => "EventResetTargetPlayer",
-b"\x41\xDE\xF7\x36" => "EventRotation",
-b"\x1F\x16\xF1\x37" => "EventScale",
-b"\xE7\xC1\x61\xC0" => "EventStop",
-b"\xA8\x11\x13\x7E" => "EventText",
-b"\xEC\x39\x3D\x23" => "EventToggleMapMode",
-b"\x21\xBE\xB6\x4C" => "EventTrigger",
-b"\xD4\xAD\x00\x85" => "EventValue",
-b"\xCF\x22\x8B\x02" => "ExpireEffect",
-b"\x69\xC9\xFC\x98" => "ExpireTimeEnemy",
-b"\x7D\x6A\x7E\x93" => "ExpireTimeField",
-b"\xE0\x1F\xBB\x02" => "Explosion",
-b"\x9D\x91\xF8\x5C" => "explosion_distant_template",
-b"\xD3\x1A\xA7\xBF" => "explosion_template",
-b"\x71\x01\xC8\x04" => "explosion_verydistant_template",
-b"\x7F\xA8\x15\x0E" => "ExplosionBreak",
-b"\x78\x13\x0C\xCA" => "ExplosionClass",
-b"\xC1\x08\x4F\xE0" => "ExplosionCritical",
-b"\x42\x34\x72\xA2" => "ExplosionDeath",
-b"\x32\x3F\x32\x8A" => "ExplosionDestruct",
-b"\x71\x42\xE8\xD4" => "ExplosionEffect",
-b"\x1F\x08\x51\x2E" => "ExplosionExpire",
-b"\x12\xBB\xA8\x61" => "ExplosionImpact",
-b"\x63\xE7\x30\x9B" => "ExplosionName",
-b"\x83\x70\x63\xC5" => "ExplosionOffset",
-b"\x1C\x37\x5E\x6A" => "ExplosionTrigger",
-b"\x07\xB8\x8F\xA7" => "externlua",
-b"\x2C\xEA\x74\x84" => "ExtremeRange",
-b"\xE5\x20\x14\x2D" => "EyePointCenter",
-b"\x97\x8C\x56\x41" => "EyePointOffset",
-b"\x13\x84\x6D\xF1" => "Fade",
-b"\xE3\x1D\xBE\x08" => "FadeDist",
-b"\xBC\xA4\x39\xA9" => "FadeFactor",
-b"\x23\x29\x3E\x8E" => "FadeHoldTime",
-b"\xD9\xEC\x83\x50" => "FadeInTime",
-b"\xFB\x51\xC3\x20" => "FadeLength",
-b"\xD0\xCB\x8A\xA5" => "FadeLightColor",
-b"\x84\xC4\x8D\x79" => "FadeOutTime",
-b"\xCA\x77\xD1\x65" => "FadePeriod",
-b"\xF9\xA8\x48\xFF" => "FadeSustainTime",
-b"\x0A\x93\x0F\xA4" => "FadeTime",
-b"\x5D\x70\x00\xF3" => "FadeViewDistance",
-b"\x60\x07\x5A\xF8" => "fall",
-b"\xF0\xB3\xB9\x92" => "FarMultiScreenRange",
-b"\x7B\x2B\x19\x01" => "FarSceneRange",
-b"\x97\x05\xA2\x46" => "FarSplitScreenRange",
-b"\xD0\xF2\xD3\x5A" => "fatray",
-b"\x44\x45\xAF\xBC" => "fel_movies",
-b"\x2D\x9F\x0A\xC0" => "fel1tran1",
-b"\x5D\x56\x9F\xD3" => "felmon01",
-b"\xA4\x51\x9F\xD0" => "felmon02",
-b"\x82\x0F\x61\x79" => "FIGHTER",
-b"\x43\x57\xEA\xAA" => "File",
-b"\x97\xC7\xE0\xD4" => "FileInfo",
-b"\x77\x68\xE1\xC7" => "Filter",
-b"\x4E\xBA\xE1\x58" => "FinalExplosion",
-b"\xAD\xB2\xB7\xE1" => "FinalExplosionName",
-b"\xF9\x28\x77\xA4" => "FinalExplosionOffset",
-b"\x1C\xD3\x36\x1C" => "FinalFrame",
-b"\x39\x10\x67\xAA" => "FinalWidth",
-b"\x98\x3A\x56\xEF" => "FinAnimation",
-b"\xD9\x16\xAB\x8E" => "Fire",
-b"\xF1\x13\x55\x82" => "FIRE2",
-b"\xC4\x46\x02\x5C" => "FireAnim",
-b"\xB6\x9E\x6B\x98" => "FireBoth",
-b"\x79\xE3\xE1\x20" => "FireEmptySound",
-b"\x8E\x90\x3B\xB5" => "FireLoopSound",
-b"\xDA\x75\x53\x2E" => "FireNodeName",
-b"\x58\x89\x4F\x44" => "FireOutsideLimits",
-b"\x75\x3E\xF8\x0D" => "FirePoint",
-b"\x46\x8E\x9C\x38" => "FirePointExtraOffset",
-b"\x96\xFC\x74\x42" => "FirePointName",
-b"\xDD\x98\x0C\xA7" => "FireSecondary",
-b"\xC0\x2F\xDE\xCF" => "Firesound",
-b"\x58\x4B\x62\x22" => "FireSoundStop",
-b"\x9B\x7A\x15\x24" => "FireStateTime",
-b"\xA9\x58\x36\xC7" => "Firestop",
-b"\xD1\x80\xF3\x32" => "FireType",
-b"\xEE\x68\xD8\xF4" => "FireVelocity",
-b"\x74\xD7\x63\x63" => "FirstPerson",
-b"\xB7\xD6\x85\x47" => "FirstPersonFOV",
-b"\x1F\x2B\xC8\x9C" => "FirstRadius",
-b"\xBD\x1F\xDD\xF5" => "FixedFunction",
-b"\x2B\xBB\xC8\x7A" => "FixedRate",
-b"\xB0\x82\xAA\xE1" => "FlagOffScreen",
-b"\xAC\x5B\x18\x8A" => "FlagOffScreenBehind",
-b"\xAE\xE7\x56\x85" => "FlagOnScreen",
-b"\x52\xF2\x6A\xC9" => "FlagOnScreenBehind",
-b"\x9E\x3A\xE0\x5A" => "FlareAngle",
-b"\xD4\xD9\x3E\x63" => "FlareIntensity",
-b"\x78\x97\x60\x4D" => "FlareOutSize",
-b"\xAC\x30\x42\xEB" => "FlashColor",
-b"\x11\xD0\xB2\xB7" => "FlashLength",
-b"\x72\x4D\xE5\xF2" => "FlashLightColor",
-b"\x87\xD7\xBF\xA3" => "FlashLightDuration",
-b"\x41\xA0\xEE\x83" => "FlashLightRadius",
-b"\x77\xC0\xFD\x86" => "FlashRadius",
-b"\x03\x00\x65\x94" => "FlashWidth",
-b"\x03\xA7\xEB\x2D" => "FlashyDecFadeOutTime",
-b"\x8F\x90\x76\x2B" => "FlashyIncFadeOutTime",
-b"\xB2\x9F\x98\xF5" => "FlashyScale",
-b"\x71\x51\x6D\x4B" => "FlatCount",
-b"\x9C\x2B\x58\xF6" => "FlatFaceFactor",
-b"\x2E\xB8\x4D\x60" => "FlatGrassSwing",
-b"\x79\xCC\x1F\x23" => "FlatHeight",
-b"\x22\x62\x93\x4B" => "FlatInfo",
-b"\xE1\x63\x52\xC7" => "FlatShadowHeight",
-b"\x58\x49\xB1\xA9" => "FlatSizeMultiplier",
-b"\x2D\xAC\x8D\x26" => "FleeLikeAHero",
-b"\xF8\x3B\x35\x94" => "FleeSound",
-b"\x8F\x42\x7C\x0D" => "Flicker",
-b"\xB8\xB0\x1A\xE9" => "FlickerAmplitude",
-b"\x5E\xA2\x2E\xD9" => "FlickerPeriod",
-b"\x60\x25\x3D\xCC" => "FlickerTimeMax",
-b"\xEA\x8D\x29\xC2" => "FlickerTimeMin",
-b"\x77\xBB\x85\x36" => "FlickerType",
-b"\x66\x1C\xD7\xBA" => "Flip",
-b"\x35\xBA\xA7\x3D" => "FlipSound",
-b"\x12\x43\xE5\xD9" => "FloatFormat",
-b"\x0A\xB1\xB6\xEF" => "FloatsOnWater",
-b"\xE2\x62\xF1\xBF" => "fly",
-b"\xD5\x72\x04\x04" => "flyer",
-b"\xC6\x0B\x51\x6A" => "flyer_eng_hi-mid_template",
-b"\x8E\xC1\xB0\x3F" => "flyer_eng_low_template",
-b"\x04\xAD\x7C\x2D" => "flyer_foley_template",
-b"\xA1\x5C\x8F\xBE" => "flyer_shift_template",
-b"\xD0\x19\x57\xD0" => "flyer_trick_template",
-b"\xB1\xAD\x9C\xC5" => "flyer_weapon_template",
-b"\xF6\x84\xD9\x1C" => "FlyerBan",
-b"\x3C\x28\xBE\x4C" => "FlyerSection",
-b"\x1A\xE9\x3E\xD6" => "FlyerSpray",
-b"\xD6\xB7\x01\x71" => "flythrough_template",
-b"\xB7\xAC\xE7\x56" => "FoamTexture",
-b"\x88\x1B\xB9\xDC" => "FoamTile",
-b"\xCE\x1F\x3B\x02" => "FogColor",
-b"\x34\xD5\x28\x7E" => "FogFar",
-b"\xA1\xF1\x60\xCC" => "FogNear",
-b"\x17\x0E\xAA\xFA" => "FogRamp",
-b"\xD4\xA8\x49\xCF" => "FogRange",
-b"\x32\x1C\x4C\xE6" => "FoleyFX",
-b"\x22\x59\xD5\x99" => "FoleyFXClass",
-b"\xC5\xCB\xC9\xA1" => "FoleyFXGroup",
-b"\x48\xCB\x13\x7C" => "FoleyFXImpact",
-b"\x8C\x61\x98\xD0" => "FoleyFXSoldier",
-b"\x48\x99\x9B\x5E" => "FoleyFXWalker",
-b"\x9F\x1A\x71\xC6" => "foliage_collision",
-b"\x70\x4F\x6C\x5B" => "Follow",
-b"\x90\x12\x4E\x27" => "font",
-b"\xB2\xED\x82\xD3" => "FootBoneLeft",
-b"\x5F\x82\xF4\xA2" => "FootBoneRight",
-b"\x37\x21\xF8\xB8" => "FootSlideEffect",
-b"\xD0\x6E\xF5\x21" => "Footstep0Sound",
-b"\xA5\xEA\x7C\x93" => "Footstep1Sound",
-b"\x62\x33\xB2\x12" => "Footstep2Sound",
-b"\xFB\x6E\x0F\x64" => "FootstepLeftRun",
-b"\x02\x87\x9A\xB5" => "FootstepLeftStop",
-b"\x73\x66\x9F\x2A" => "FootstepLeftWalk",
-b"\x00\x1B\xDF\xA4" => "FootstepRightRun",
-b"\x1F\xAF\xE8\x54" => "FootstepRightStop",
-b"\xC2\xB7\x9E\xE7" => "FootstepRightWalk",
-b"\xF6\x7A\x15\x97" => "FootstepSound0",
-b"\x89\x7C\x15\x98" => "FootstepSound1",
-b"\xD0\x77\x15\x95" => "FootstepSound2",
-b"\x63\x79\x15\x96" => "FootstepSound3",
-b"\x42\x81\x15\x9B" => "FootstepSound4",
-b"\xD5\x82\x15\x9C" => "FootstepSound5",
-b"\xD6\xF0\x41\xD6" => "FootstepStride",
-b"\xEE\x66\x72\x05" => "FootWaterSplashEffect",
-b"\xE4\x24\x9A\x78" => "ForceBoot",
-b"\x86\x1D\x9C\x07" => "ForceFireAnimation",
-b"\x56\x1C\xCA\x41" => "ForceHiDetail",
-b"\xFC\x61\x50\x86" => "ForceLockAimerToParentAimer",
-b"\x07\x57\x1F\x9D" => "ForceMode",
-b"\x52\x85\x9D\xB9" => "Format",
-b"\x1A\xA8\x5F\xB4" => "Forward",
-b"\xBD\x34\x28\xD4" => "ForwardSpeed",
-b"\x84\x3C\xAB\x7E" => "ForwardTurnSpeed",
-b"\x60\xDE\xAA\x9A" => "fountain_halo",
-b"\xBC\xFE\x03\x67" => "FourSegmentLockOn",
-b"\xAB\xCA\x58\xD1" => "FOVEffect1st",
-b"\x5C\xD9\xA6\xAA" => "FOVEffect3rd",
-b"\x36\xFC\x0C\x8D" => "FOVEFFectMaxCamOffset3rd",
-b"\xB0\xAB\xBC\x5D" => "FOVEFFectMinCamOffset3rd",
-b"\x04\x15\x7B\x33" => "FPModelAttachedtoCam",
-b"\x67\xD8\xC8\x46" => "FPPosePointName",
-b"\xB4\x35\xB4\x05" => "FPWalkScale",
-b"\x51\xAB\xD8\xE3" => "FrameLock",
-b"\xE4\x1A\x11\x9B" => "FrameRate",
-b"\x4F\x32\x71\x7B" => "Frames",
-b"\xA7\x9E\xF5\xFA" => "FrameSize",
-b"\xF1\x91\x0A\xC8" => "FreeFlyTime",
-b"\x01\x1C\xB3\x2F" => "Frequency",
-b"\x82\xFA\x7C\x8F" => "FresnelMinMax",
-b"\xBB\xE2\x1B\xA5" => "Friction",
-b"\x8D\x9F\xA0\xCB" => "friend",
-b"\xE1\xED\xDA\x68" => "FriendlyColor",
-b"\xF1\x32\x08\xED" => "friendvoice",
-b"\xC0\x4E\xDB\x65" => "FromAnim",
-b"\xAC\x4C\xA7\xDD" => "FromEdge",
-b"\xBA\x22\x91\xFA" => "FromEnd",
-b"\x05\x32\xD5\xA3" => "FromSoldier",
-b"\x3C\xB3\x79\xFF" => "FULL",
-b"\x18\xA7\x3F\x8D" => "FullBody",
-b"\x78\x46\x23\xB5" => "FXName",
-b"\xFE\x26\x54\x1B" => "Gain",
-b"\x65\x64\x0B\xF6" => "GainDev",
-b"\xED\x61\xC0\x45" => "gal_sfx_assaultship_exp",
-b"\x57\x0A\xAE\x11" => "gal_sfx_cruiser_exp",
-b"\x42\xF6\x5B\xC5" => "gal_sfx_fedcruiser_exp",
-b"\x55\x80\xDB\xE0" => "gal_sfx_moncalamaricruiser_exp",
-b"\x5D\x25\x4B\xF4" => "gal_sfx_stardestroyer_exp",
-b"\x2E\x61\x4C\x72" => "GAL1",
-b"\x6B\x96\x9F\x0B" => "gam_inf_axe",
-b"\x22\x71\x4B\x8F" => "gamefont_small",
-b"\x04\x49\x0C\x4F" => "GameName",
-b"\x17\x5C\xBF\x98" => "GameSlice",
-b"\x01\x58\xA3\xCD" => "GammaBrightness",
-b"\x33\x92\xED\x79" => "GammaColorBalance",
-b"\x68\xDE\x5F\x49" => "GammaContrast",
-b"\x4E\x45\xAD\x22" => "GammaCorrection",
-b"\x44\x3B\x26\xD2" => "GammaHue",
-b"\xB4\xC1\xA7\x2A" => "gcw_music",
-b"\xD9\x1E\xA2\xB4" => "gcwinall1",
-b"\x5F\x9D\x37\x3D" => "GCwinCIS1",
-b"\xAA\x3B\xC3\x3B" => "GCwinIMP1",
-b"\x31\xC4\xE4\x72" => "GCwinREP1",
-b"\x9E\x24\x0D\xA9" => "geo_inf_acklay",
-b"\xD2\x40\x43\xB3" => "geo_movies",
-b"\x60\x50\x3F\x5B" => "geo_sfx_weap_blunderbluss_ord",
-b"\xB2\x8C\xF8\x2E" => "geo_sfx_weap_blunderbuss_ord",
-b"\x66\x01\xD0\xA2" => "geo_wingsfly",
-b"\x91\x85\x6D\x2A" => "Geometry",
-b"\x31\x23\x27\x94" => "GeometryAddon",
-b"\x34\xFE\xE7\xB2" => "GeometryColor",
-b"\xC2\xCF\xB1\x35" => "GeometryColorMax",
-b"\xC8\xC4\x9C\x3F" => "GeometryColorMin",
-b"\x76\x18\xFA\x44" => "GeometryHeight",
-b"\xA1\xEB\x90\xA4" => "GeometryLowRes",
-b"\x4A\x6B\xC8\x47" => "GeometryName",
-b"\x45\xA7\x40\x68" => "geometryScale",
-b"\xBB\x53\xD7\xE8" => "geomon01",
-b"\x4E\x55\xD7\xE9" => "geomon02",
-b"\xAE\x06\xFF\x1D" => "global",
-b"\x33\x8F\x54\xF7" => "GlobalLights",
-b"\xBC\x27\x38\x6A" => "Glow",
-b"\xE5\x10\x3E\xBA" => "GlowAlphaBase",
-b"\xA2\x84\x53\xDD" => "GlowAlphaScale",
-b"\x97\xEE\x9E\xAC" => "GlowFactor",
-b"\x10\xB5\xA8\xC3" => "GlowLength",
-b"\x97\x4D\xE8\x84" => "GlowScaleSize",
-b"\x1F\x9B\x2C\xB1" => "GlowThreshold",
-b"\x37\x05\x5B\xBA" => "GodRay",
-b"\xA0\x33\xD6\x29" => "GrapplingHook",
-b"\x34\x3E\x4A\x6B" => "GrapplingHookWeapon",
-b"\xA1\x70\x6B\x15" => "grasspatch",
-b"\x45\xFF\x09\xD2" => "Gravity",
-b"\xF1\xE0\xF2\x97" => "GravityScale",
-b"\xB3\xC6\x8D\xAE" => "GravityVelocityTarget",
-b"\xBC\xEC\x1D\x01" => "Green",
-b"\x4C\x22\xD3\x09" => "green_blaster_bolt",
-b"\xE0\x1E\xB3\x8A" => "green_blaster_bolt_small",
-b"\xFD\xD8\x88\xD9" => "grenade",
-b"\xA0\x14\x36\x3A" => "GroundedHeight",
-b"\xA6\x02\xD9\x14" => "GroundedSound",
-b"\x73\x94\x68\x51" => "GroundEffect",
-b"\x68\xC8\x5A\x88" => "GroundEffectsPerSec",
-b"\xA4\x97\x3D\xD2" => "GroundEffectSpread",
-b"\x8C\x1E\xB9\x5F" => "Group",
-b"\x0B\xC8\xE9\xC6" => "GuidedMissile",
-b"\xAF\x82\x30\xBB" => "GuidedMissileBoostExplosionSpeedThreshold",
-b"\x8A\x23\x6B\x37" => "GuidedMissileDamage",
-b"\x29\x23\x7B\x48" => "GuidedMissileDamageBoost",
-b"\x79\xF4\x5B\x32" => "GuidedMissileDeathCamMinDistance",
-b"\x90\xA4\xDF\x9B" => "GuidedMissileDeathCamPause",
-b"\x45\x07\x2E\x96" => "GuidedMissileDeathCamPullbackSpeed",
-b"\xF4\xE9\x66\xAE" => "hailfire_wake",
-b"\x3C\xCB\x28\x4A" => "HAlign",
-b"\x9F\xBD\xBE\xCF" => "Halo",
-b"\x5A\xA0\x07\x88" => "HaloFadeFactor",
-b"\xB9\x99\x5D\xC3" => "HaloFadeLength",
-b"\x9F\x75\xBE\x9E" => "HaloInnerRing",
-b"\x28\xAC\x71\x56" => "HaloMiddleRing",
-b"\xCE\x07\xB4\x59" => "HaloOutterRing",
-b"\x1D\x89\x3A\x66" => "HaloRadius",
-b"\xDC\x5B\xC3\xE4" => "hard",
-b"\xE1\x15\xAF\x5C" => "HardEdge",
-b"\x92\x26\x2C\x4E" => "Hardpoint",
-b"\xB4\x75\x76\x03" => "Haywire",
-b"\x9A\xE5\x1B\x93" => "hcenter",
-b"\xD7\xC5\xB6\xF0" => "hdd",
-b"\xC3\x4B\x69\x32" => "Head",
-b"\xD9\xC2\xC9\x68" => "HealthGainEffect",
-b"\xCF\x70\x79\xC3" => "HealthScale",
-b"\x2E\x8C\xCF\xF7" => "HealthSScale",
-b"\x8E\x46\xB7\x0D" => "HealthTexture",
-b"\x77\x16\x82\xE8" => "HealthType",
-b"\x78\xBB\xF3\x82" => "HealthTypeForLockOn",
-b"\x80\x47\xC5\xD3" => "HeardEnemySound",
-b"\x1A\xFD\xCF\x67" => "HeatPerShot",
-b"\xA3\xBB\xE7\x57" => "HeatRecoverRate",
-b"\x1E\x51\x21\x56" => "HeatThreshold",
-b"\x40\x9D\x9F\x11" => "Heavy",
-b"\x42\xBB\xBD\xD5" => "Height",
-b"\x78\x37\xAA\xCA" => "HeightScale",
-b"\x8C\x8D\xB4\xF4" => "her",
-b"\x59\x5C\x3B\x1B" => "hero",
-b"\xE1\x5F\x2F\x3E" => "Hero_battle_chatter_template",
-b"\xBB\x6E\x77\x56" => "HeroDefeated",
-b"\x05\xC1\xC4\xA8" => "heroes",
-b"\x5C\xFE\x3D\x4F" => "HeroesUnlocked",
-b"\x1E\x70\xD7\xEC" => "HeroRulePlayer",
-b"\x95\xDA\xC8\x3E" => "HeroRuleRespawn",
-b"\x3A\xB7\x5D\xC1" => "HeroRuleRespawnValue",
-b"\xFE\xDA\x46\xF5" => "HeroRuleTeam",
-b"\x17\xB2\x2D\x20" => "HeroRuleUnlock",
-b"\x0C\xC9\x16\xAC" => "HeroRuleUnlockValue",
-b"\x25\xDC\x31\x7D" => "HeroSpawned",
-b"\xA8\x99\xA0\xE1" => "HFReference",
-b"\xD8\xA7\x21\xE9" => "HideHealthBar",
-b"\x39\xD2\x65\xDD" => "HideOnEmpty",
-b"\x4C\xD4\x78\x55" => "HideOnFire",
-b"\x77\x99\x68\x0D" => "HideUnbuiltModel",
-b"\x75\xB6\x07\xC0" => "HidingSound",
-b"\x1E\x80\x7E\x40" => "HierarchyLevel",
-b"\xDD\x85\x31\x8B" => "HighResGeometry",
-b"\x7B\x71\xE9\xBE" => "HintFlagOffScreen",
-b"\xC7\x46\xEF\x6B" => "HintFlagOffScreenBehind",
-b"\xAF\xDE\x67\x20" => "HintFlagOnScreen",
-b"\x13\x81\x34\x83" => "HintFlagOnScreenBehind",
-b"\x39\xC4\x91\x78" => "HintOffScreen",
-b"\xE5\x21\xC9\xA0" => "HintOffScreenBehind",
-b"\x95\x13\x06\x15" => "HintOnScreen",
-b"\x99\x4F\x30\xBB" => "HintOnScreenBehind",
-b"\x77\x94\x92\xC0" => "HitEffect",
-b"\x79\x74\xD7\xCE" => "HitLocation",
-b"\x41\xD7\xBD\xC5" => "HitSound",
-b"\xB8\x49\xC6\xAD" => "Hold",
-b"\x75\x8F\x9C\x38" => "HoldRelease",
-b"\xA6\x06\xE2\x1D" => "HoloBeamInitialWidth",
-b"\x5F\x74\x1C\xF3" => "HoloBeamIntensity",
-b"\xE5\x71\xE9\x9F" => "HoloFadeInTime",
-b"\x28\xAC\xEC\x4C" => "HoloFlareIntensity",
-b"\x79\xD2\x10\x87" => "HoloFlickerAlphaMax",
-b"\xB7\x34\xFD\x78" => "HoloFlickerAlphaMin",
-b"\x29\x3D\xF1\xA0" => "HoloFlickerRate",
-b"\x42\x17\x9D\x17" => "hologram",
-b"\x4E\x13\x61\xB7" => "HoloHeight",
-b"\xD2\x10\x42\xB6" => "HoloImageGeometry",
-b"\xAF\x22\x37\x54" => "HoloImageIntensity",
-b"\xE0\x6D\xAC\xF0" => "HoloLightIntensity",
-b"\x11\x07\x7B\x1D" => "HoloLightRadius",
-b"\x9C\xED\x83\x6A" => "HoloOdf",
-b"\x86\x45\x6A\x18" => "HoloPopRate",
-b"\x8C\xF9\xF1\xAF" => "HoloRotateRate",
-b"\xC8\xBC\x60\x3D" => "HoloSize",
-b"\x68\xF6\xF3\x4B" => "HoloTurnOnDistance",
-b"\x9E\x0E\x0C\x63" => "HoloTurnOnTime",
-b"\xD1\x5B\x56\x02" => "HoloType",
-b"\xC2\x26\xF1\x09" => "HoloVisibleDistance",
-b"\x5A\xE2\x83\x0D" => "HomeRegion",
-b"\xB1\x14\x6B\xFC" => "HorizonAngleMinMax",
-b"\xC2\x90\xB0\x26" => "HorizontalSpread",
-b"\xD7\x6C\x27\x99" => "hot_inf_wampa",
-b"\x70\x1D\xFD\x60" => "hot_movies",
-b"\xD5\x1B\xDC\x1D" => "hot_sfx_weap_pawsmash",
-b"\x87\x6E\xA1\x3F" => "hot_sfx_weap_smash",
-b"\x75\xF0\x78\x0E" => "hot1cam1",
-b"\xBC\xEB\x78\x0B" => "hot1cam2",
-b"\xF9\xC9\x3E\xEE" => "hot1tran1",
-b"\x59\x63\x89\xFF" => "hotmon01",
-b"\xA0\x5E\x89\xFC" => "hotmon02",
-b"\x2D\xAB\xCE\x70" => "hover",
-b"\xEE\x17\xFC\x58" => "HoverBan",
-b"\x3E\x12\xB7\x99" => "HrPlayer",
-b"\x35\xBC\xCF\xD0" => "HrRespawn",
-b"\x5A\x00\x63\xA5" => "HrRespawnValue",
-b"\x1E\xC3\xF6\x69" => "HrTeam",
-b"\x37\x54\x0D\xCD" => "HrUnlock",
-b"\xAC\xA0\x18\xB4" => "HrUnlockValue",
-b"\xC6\x61\x8D\xE2" => "hud",
-b"\xEA\xDA\xBC\x66" => "HUDIndex",
-b"\x70\xB0\x69\xC0" => "HUDIndexDisplay",
-b"\xB3\xAE\x8F\x8F" => "HUDModel",
-b"\xD6\x40\xF8\x9F" => "HUDTag",
-b"\xF7\x46\xAA\xEE" => "hudtransforms",
-b"\x59\x63\x8D\xE3" => "Hue",
-b"\xE7\x90\x80\xB0" => "HugeBan",
-b"\x18\x78\x69\xF3" => "HurtFallSound",
-b"\x2F\x3B\xE1\x0B" => "HurtOwner",
-b"\xF7\x1A\x98\x72" => "HurtSound",
-b"\xD4\x0F\x08\x08" => "HydraulicLowerHeight",
-b"\x4A\xFD\x79\x03" => "HydraulicLowerSound",
-b"\xE5\x34\x91\x06" => "HydraulicSound",
-b"\x62\x14\xE9\x8A" => "HydraulicSoundHeight",
-b"\xC4\x35\x0C\xEC" => "I",
-b"\x5D\x1A\x68\xEF" => "i3dl2reverb",
-b"\x16\x68\xD3\xAB" => "I3DL2ReverbPreset",
-b"\x4B\xEB\xDB\x8D" => "IconTexture",
-b"\xEB\xC6\x7B\x29" => "IdleAnimation",
-b"\x82\x10\x70\xFF" => "IdleDelay",
-b"\x8B\x8C\xE6\xED" => "IdleRotateSpeed",
-b"\xAB\x94\x48\x04" => "IdleWaitTime",
-b"\xD9\xAF\xE0\x19" => "IdleWobbleFactor",
-b"\x83\x9C\x1B\x1C" => "IdleWobbleLeftFoot",
-b"\xEA\x62\x5B\x1B" => "IdleWobbleNode",
-b"\x98\xB9\xEF\x6E" => "IdleWobbleRightFoot",
-b"\x06\x6E\x38\x39" => "If",
-b"\x89\xC1\xDF\x31" => "ifs.movies.corintro.line01",
-b"\xD0\xBC\xDF\x2E" => "ifs.movies.corintro.line02",
-b"\x63\xBE\xDF\x2F" => "ifs.movies.corintro.line03",
-b"\x42\xC6\xDF\x34" => "ifs.movies.corintro.line04",
-b"\xD5\xC7\xDF\x35" => "ifs.movies.corintro.line05",
-b"\x1C\xC3\xDF\x32" => "ifs.movies.corintro.line06",
-b"\xAF\xC4\xDF\x33" => "ifs.movies.corintro.line07",
-b"\x8E\xCC\xDF\x38" => "ifs.movies.corintro.line08",
-b"\x21\xCE\xDF\x39" => "ifs.movies.corintro.line09",
-b"\xB6\xAE\xB5\x11" => "ifs.movies.coroutro.line01",
-b"\x23\xAD\xB5\x10" => "ifs.movies.coroutro.line02",
-b"\x90\xAB\xB5\x0F" => "ifs.movies.coroutro.line03",
-b"\x95\xB6\xB5\x16" => "ifs.movies.coroutro.line04",
-b"\x02\xB5\xB5\x15" => "ifs.movies.coroutro.line05",
-b"\x6F\xB3\xB5\x14" => "ifs.movies.coroutro.line06",
-b"\xDC\xB1\xB5\x13" => "ifs.movies.coroutro.line07",
-b"\xE1\xBC\xB5\x1A" => "ifs.movies.coroutro.line08",
-b"\x23\x52\x6C\x11" => "ifs.movies.deaintro.line01",
-b"\xB6\x53\x6C\x12" => "ifs.movies.deaintro.line02",
-b"\x49\x55\x6C\x13" => "ifs.movies.deaintro.line03",
-b"\xDC\x56\x6C\x14" => "ifs.movies.deaintro.line04",
-b"\x6F\x58\x6C\x15" => "ifs.movies.deaintro.line05",
-b"\x02\x5A\x6C\x16" => "ifs.movies.deaintro.line06",
-b"\x95\x5B\x6C\x17" => "ifs.movies.deaintro.line07",
-b"\xE8\x50\x1B\x0B" => "ifs.movies.deaoutro.line01",
-b"\xA1\x55\x1B\x0E" => "ifs.movies.deaoutro.line02",
-b"\x0E\x54\x1B\x0D" => "ifs.movies.deaoutro.line03",
-b"\xC7\x58\x1B\x10" => "ifs.movies.deaoutro.line04",
-b"\x34\x57\x1B\x0F" => "ifs.movies.deaoutro.line05",
-b"\xED\x5B\x1B\x12" => "ifs.movies.deaoutro.line06",
-b"\x5A\x5A\x1B\x11" => "ifs.movies.deaoutro.line07",
-b"\x16\x13\xE4\xCA" => "ifs.movies.felintro.line01",
-b"\x83\x11\xE4\xC9" => "ifs.movies.felintro.line02",
-b"\xF0\x0F\xE4\xC8" => "ifs.movies.felintro.line03",
-b"\xF5\x1A\xE4\xCF" => "ifs.movies.felintro.line04",
-b"\x62\x19\xE4\xCE" => "ifs.movies.felintro.line05",
-b"\xCF\x17\xE4\xCD" => "ifs.movies.felintro.line06",
-b"\x3C\x16\xE4\xCC" => "ifs.movies.felintro.line07",
-b"\x41\x21\xE4\xD3" => "ifs.movies.felintro.line08",
-b"\xAE\x1F\xE4\xD2" => "ifs.movies.felintro.line09",
-b"\xA0\xCC\xE1\xC5" => "ifs.movies.felintro.line10",
-b"\x33\xCE\xE1\xC6" => "ifs.movies.felintro.line11",
-b"\xC6\xCF\xE1\xC7" => "ifs.movies.felintro.line12",
-b"\xC5\x14\x33\x88" => "ifs.movies.feloutro.line01",
-b"\x0C\x10\x33\x85" => "ifs.movies.feloutro.line02",
-b"\x9F\x11\x33\x86" => "ifs.movies.feloutro.line03",
-b"\xE6\x0C\x33\x83" => "ifs.movies.feloutro.line04",
-b"\xEC\xF3\x22\xED" => "ifs.movies.geointro.line01",
-b"\xA5\xF8\x22\xF0" => "ifs.movies.geointro.line02",
-b"\x12\xF7\x22\xEF" => "ifs.movies.geointro.line03",
-b"\x33\xEF\x22\xEA" => "ifs.movies.geointro.line04",
-b"\xA0\xED\x22\xE9" => "ifs.movies.geointro.line05",
-b"\x59\xF2\x22\xEC" => "ifs.movies.geointro.line06",
-b"\xC6\xF0\x22\xEB" => "ifs.movies.geointro.line07",
-b"\xAB\xB4\x50\x38" => "ifs.movies.geooutro.line01",
-b"\x3E\xB6\x50\x39" => "ifs.movies.geooutro.line02",
-b"\x7E\x0F\x07\x99" => "ifs.movies.hotintro.line01",
-b"\xEB\x0D\x07\x98" => "ifs.movies.hotintro.line02",
-b"\x58\x0C\x07\x97" => "ifs.movies.hotintro.line03",
-b"\x5D\x17\x07\x9E" => "ifs.movies.hotintro.line04",
-b"\xCA\x15\x07\x9D" => "ifs.movies.hotintro.line05",
-b"\x37\x14\x07\x9C" => "ifs.movies.hotintro.line06",
-b"\xA4\x12\x07\x9B" => "ifs.movies.hotintro.line07",
-b"\x79\x04\x07\x92" => "ifs.movies.hotintro.line08",
-b"\xE6\x02\x07\x91" => "ifs.movies.hotintro.line09",
-b"\x9D\x16\xA9\xE6" => "ifs.movies.hotoutro.line01",
-b"\xE4\x11\xA9\xE3" => "ifs.movies.hotoutro.line02",
-b"\x77\x13\xA9\xE4" => "ifs.movies.hotoutro.line03",
-b"\xBE\x0E\xA9\xE1" => "ifs.movies.hotoutro.line04",
-b"\x51\x10\xA9\xE2" => "ifs.movies.hotoutro.line05",
-b"\x98\x0B\xA9\xDF" => "ifs.movies.hotoutro.line06",
-b"\x2B\x0D\xA9\xE0" => "ifs.movies.hotoutro.line07",
-b"\x72\x08\xA9\xDD" => "ifs.movies.hotoutro.line08",
-b"\x05\x0A\xA9\xDE" => "ifs.movies.hotoutro.line09",
-b"\x6A\x24\xA9\x42" => "ifs.movies.kamintro.line01",
-b"\xD7\x22\xA9\x41" => "ifs.movies.kamintro.line02",
-b"\xB1\x1F\xA9\x3F" => "ifs.movies.kamintro.line04",
-b"\x1E\x1E\xA9\x3E" => "ifs.movies.kamintro.line05",
-b"\x8B\x1C\xA9\x3D" => "ifs.movies.kamintro.line06",
-b"\xF8\x1A\xA9\x3C" => "ifs.movies.kamintro.line07",
-b"\x61\x4E\x8A\xBD" => "ifs.movies.kamoutro.line01",
-b"\xA8\x49\x8A\xBA" => "ifs.movies.kamoutro.line02",
-b"\x3B\x4B\x8A\xBB" => "ifs.movies.kamoutro.line03",
-b"\x1A\x53\x8A\xC0" => "ifs.movies.kamoutro.line04",
-b"\xAD\x54\x8A\xC1" => "ifs.movies.kamoutro.line05",
-b"\xF4\x4F\x8A\xBE" => "ifs.movies.kamoutro.line06",
-b"\x87\x51\x8A\xBF" => "ifs.movies.kamoutro.line07",
-b"\x90\x86\x17\x8E" => "ifs.movies.kasintro.line01",
-b"\x49\x8B\x17\x91" => "ifs.movies.kasintro.line02",
-b"\xB6\x89\x17\x90" => "ifs.movies.kasintro.line03",
-b"\x6F\x8E\x17\x93" => "ifs.movies.kasintro.line04",
-b"\xDC\x8C\x17\x92" => "ifs.movies.kasintro.line05",
-b"\x95\x91\x17\x95" => "ifs.movies.kasintro.line06",
-b"\x02\x90\x17\x94" => "ifs.movies.kasintro.line07",
-b"\xBB\x94\x17\x97" => "ifs.movies.kasintro.line08",
-b"\x28\x93\x17\x96" => "ifs.movies.kasintro.line09",
-b"\xFA\xA8\x14\x29" => "ifs.movies.kasintro.line10",
-b"\x8D\xAA\x14\x2A" => "ifs.movies.kasintro.line11",
-b"\xD4\xA5\x14\x27" => "ifs.movies.kasintro.line12",
-b"\x67\xA7\x14\x28" => "ifs.movies.kasintro.line13",
-b"\xAE\xA2\x14\x25" => "ifs.movies.kasintro.line14",
-b"\x77\x8E\x76\x99" => "ifs.movies.kasoutro.line01",
-b"\x0A\x90\x76\x9A" => "ifs.movies.kasoutro.line02",
-b"\x9D\x91\x76\x9B" => "ifs.movies.kasoutro.line03",
-b"\x98\x86\x76\x94" => "ifs.movies.kasoutro.line04",
-b"\x2B\x88\x76\x95" => "ifs.movies.kasoutro.line05",
-b"\x62\x6C\x70\xA7" => "ifs.movies.musintro.line01",
-b"\xCF\x6A\x70\xA6" => "ifs.movies.musintro.line02",
-b"\x3C\x69\x70\xA5" => "ifs.movies.musintro.line03",
-b"\xA9\x67\x70\xA4" => "ifs.movies.musintro.line04",
-b"\x16\x66\x70\xA3" => "ifs.movies.musintro.line05",
-b"\x83\x64\x70\xA2" => "ifs.movies.musintro.line06",
-b"\xF0\x62\x70\xA1" => "ifs.movies.musintro.line07",
-b"\x8D\x7A\x70\xB0" => "ifs.movies.musintro.line08",
-b"\xFA\x78\x70\xAF" => "ifs.movies.musintro.line09",
-b"\x69\x02\x84\x49" => "ifs.movies.musoutro.line01",
-b"\xB0\xFD\x83\x46" => "ifs.movies.musoutro.line02",
-b"\x43\xFF\x83\x47" => "ifs.movies.musoutro.line03",
-b"\x22\x07\x84\x4C" => "ifs.movies.musoutro.line04",
-b"\xFA\x38\x0C\x89" => "ifs.movies.mygintro.line01",
-b"\x67\x37\x0C\x88" => "ifs.movies.mygintro.line02",
-b"\xD4\x35\x0C\x87" => "ifs.movies.mygintro.line03",
-b"\x41\x34\x0C\x86" => "ifs.movies.mygintro.line04",
-b"\xAE\x32\x0C\x85" => "ifs.movies.mygintro.line05",
-b"\x1B\x31\x0C\x84" => "ifs.movies.mygintro.line06",
-b"\x88\x2F\x0C\x83" => "ifs.movies.mygintro.line07",
-b"\xF5\x2D\x0C\x82" => "ifs.movies.mygintro.line08",
-b"\x62\x2C\x0C\x81" => "ifs.movies.mygintro.line09",
-b"\x84\xF2\x09\x84" => "ifs.movies.mygintro.line10",
-b"\x17\xF4\x09\x85" => "ifs.movies.mygintro.line11",
-b"\x3D\xF7\x09\x87" => "ifs.movies.mygintro.line13",
-b"\x38\xEC\x09\x80" => "ifs.movies.mygintro.line14",
-b"\xCB\xED\x09\x81" => "ifs.movies.mygintro.line15",
-b"\x11\xE6\x50\xEE" => "ifs.movies.mygoutro.line01",
-b"\x58\xE1\x50\xEB" => "ifs.movies.mygoutro.line02",
-b"\xEB\xE2\x50\xEC" => "ifs.movies.mygoutro.line03",
-b"\xCA\xEA\x50\xF1" => "ifs.movies.mygoutro.line04",
-b"\x5D\xEC\x50\xF2" => "ifs.movies.mygoutro.line05",
-b"\xE0\xE7\x67\x2B" => "ifs.movies.nabintro.line01",
-b"\x99\xEC\x67\x2E" => "ifs.movies.nabintro.line02",
-b"\x06\xEB\x67\x2D" => "ifs.movies.nabintro.line03",
-b"\xBF\xEF\x67\x30" => "ifs.movies.nabintro.line04",
-b"\x2C\xEE\x67\x2F" => "ifs.movies.nabintro.line05",
-b"\xE5\xF2\x67\x32" => "ifs.movies.nabintro.line06",
-b"\x52\xF1\x67\x31" => "ifs.movies.nabintro.line07",
-b"\x0B\xF6\x67\x34" => "ifs.movies.nabintro.line08",
-b"\x78\xF4\x67\x33" => "ifs.movies.nabintro.line09",
-b"\x4A\x0A\x65\xC6" => "ifs.movies.nabintro.line10",
-b"\xDD\x0B\x65\xC7" => "ifs.movies.nabintro.line11",
-b"\x24\x07\x65\xC4" => "ifs.movies.nabintro.line12",
-b"\xB7\x08\x65\xC5" => "ifs.movies.nabintro.line13",
-b"\xFE\x03\x65\xC2" => "ifs.movies.nabintro.line14",
-b"\x91\x05\x65\xC3" => "ifs.movies.nabintro.line15",
-b"\x27\x05\x90\xF4" => "ifs.movies.naboutro.line01",
-b"\xBA\x06\x90\xF5" => "ifs.movies.naboutro.line02",
-b"\x4D\x08\x90\xF6" => "ifs.movies.naboutro.line03",
-b"\x48\xFD\x8F\xEF" => "ifs.movies.naboutro.line04",
-b"\xDB\xFE\x8F\xF0" => "ifs.movies.naboutro.line05",
-b"\x6E\x00\x90\xF1" => "ifs.movies.naboutro.line06",
-b"\xBE\xE0\x19\xAD" => "ifs.movies.polintro.line01",
-b"\x2B\xDF\x19\xAC" => "ifs.movies.polintro.line02",
-b"\x98\xDD\x19\xAB" => "ifs.movies.polintro.line03",
-b"\x9D\xE8\x19\xB2" => "ifs.movies.polintro.line04",
-b"\x0A\xE7\x19\xB1" => "ifs.movies.polintro.line05",
-b"\x77\xE5\x19\xB0" => "ifs.movies.polintro.line06",
-b"\xE4\xE3\x19\xAF" => "ifs.movies.polintro.line07",
-b"\xB9\xD5\x19\xA6" => "ifs.movies.polintro.line08",
-b"\x26\xD4\x19\xA5" => "ifs.movies.polintro.line09",
-b"\x48\x9A\x17\xA8" => "ifs.movies.polintro.line10",
-b"\xDB\x9B\x17\xA9" => "ifs.movies.polintro.line11",
-b"\xDD\x75\x5D\x40" => "ifs.movies.poloutro.line01",
-b"\x24\x71\x5D\x3D" => "ifs.movies.poloutro.line02",
-b"\xB7\x72\x5D\x3E" => "ifs.movies.poloutro.line03",
-b"\x39\xD5\xCC\x3A" => "ifs.movies.sb1intro.line01",
-b"\x80\xD0\xCC\x37" => "ifs.movies.sb1intro.line02",
-b"\x13\xD2\xCC\x38" => "ifs.movies.sb1intro.line03",
-b"\xF2\xD9\xCC\x3D" => "ifs.movies.sb1intro.line04",
-b"\x85\xDB\xCC\x3E" => "ifs.movies.sb1intro.line05",
-b"\xCC\xD6\xCC\x3B" => "ifs.movies.sb1intro.line06",
-b"\x5F\xD8\xCC\x3C" => "ifs.movies.sb1intro.line07",
-b"\x3E\xE0\xCC\x41" => "ifs.movies.sb1intro.line08",
-b"\xD1\xE1\xCC\x42" => "ifs.movies.sb1intro.line09",
-b"\x2F\xE5\xCF\xBF" => "ifs.movies.sb1intro.line10",
-b"\x9C\xE3\xCF\xBE" => "ifs.movies.sb1intro.line11",
-b"\x86\x0B\x22\x12" => "ifs.movies.sb1outro.line01",
-b"\xF3\x09\x22\x11" => "ifs.movies.sb1outro.line02",
-b"\x60\x08\x22\x10" => "ifs.movies.sb1outro.line03",
-b"\x65\x13\x22\x17" => "ifs.movies.sb1outro.line04",
-b"\x90\x58\xA5\x26" => "ifs.movies.sb2intro.line01",
-b"\x49\x5D\xA5\x29" => "ifs.movies.sb2intro.line02",
-b"\xB6\x5B\xA5\x28" => "ifs.movies.sb2intro.line03",
-b"\x6F\x60\xA5\x2B" => "ifs.movies.sb2intro.line04",
-b"\xDC\x5E\xA5\x2A" => "ifs.movies.sb2intro.line05",
-b"\x77\x60\x04\x32" => "ifs.movies.sb2outro.line01",
-b"\x0A\x62\x04\x33" => "ifs.movies.sb2outro.line02",
-b"\x9D\x63\x04\x34" => "ifs.movies.sb2outro.line03",
-b"\x98\x58\x04\x2D" => "ifs.movies.sb2outro.line04",
-b"\x5B\xCC\x66\x4D" => "ifs.movies.sb3intro.line01",
-b"\xEE\xCD\x66\x4E" => "ifs.movies.sb3intro.line02",
-b"\x81\xCF\x66\x4F" => "ifs.movies.sb3intro.line03",
-b"\x14\xD1\x66\x50" => "ifs.movies.sb3intro.line04",
-b"\xA7\xD2\x66\x51" => "ifs.movies.sb3intro.line05",
-b"\x3A\xD4\x66\x52" => "ifs.movies.sb3intro.line06",
-b"\xCD\xD5\x66\x53" => "ifs.movies.sb3intro.line07",
-b"\x30\xBE\x66\x44" => "ifs.movies.sb3intro.line08",
-b"\xC3\xBF\x66\x45" => "ifs.movies.sb3intro.line09",
-b"\xD1\x12\x69\x52" => "ifs.movies.sb3intro.line10",
-b"\x10\xB6\x9A\x5B" => "ifs.movies.sb3outro.line01",
-b"\xC9\xBA\x9A\x5E" => "ifs.movies.sb3outro.line02",
-b"\x36\xB9\x9A\x5D" => "ifs.movies.sb3outro.line03",
-b"\x92\x13\xAE\x21" => "ifs.movies.sb4intro.line01",
-b"\xFF\x11\xAE\x20" => "ifs.movies.sb4intro.line02",
-b"\x6C\x10\xAE\x1F" => "ifs.movies.sb4intro.line03",
-b"\xD9\x0E\xAE\x1E" => "ifs.movies.sb4intro.line04",
-b"\x46\x0D\xAE\x1D" => "ifs.movies.sb4intro.line05",
-b"\xB3\x0B\xAE\x1C" => "ifs.movies.sb4intro.line06",
-b"\x20\x0A\xAE\x1B" => "ifs.movies.sb4intro.line07",
-b"\xBD\x21\xAE\x2A" => "ifs.movies.sb4intro.line08",
-b"\x2A\x20\xAE\x29" => "ifs.movies.sb4intro.line09",
-b"\x79\x0E\xCB\x94" => "ifs.movies.sb4outro.line01",
-b"\xC0\x09\xCB\x91" => "ifs.movies.sb4outro.line02",
-b"\x53\x0B\xCB\x92" => "ifs.movies.sb4outro.line03",
-b"\x32\x13\xCB\x97" => "ifs.movies.sb4outro.line04",
-b"\x66\xD5\x4E\x29" => "ifs.movies.tanintro.line01",
-b"\xD3\xD3\x4E\x28" => "ifs.movies.tanintro.line02",
-b"\x40\xD2\x4E\x27" => "ifs.movies.tanintro.line03",
-b"\x45\xDD\x4E\x2E" => "ifs.movies.tanintro.line04",
-b"\xB2\xDB\x4E\x2D" => "ifs.movies.tanintro.line05",
-b"\x1F\xDA\x4E\x2C" => "ifs.movies.tanintro.line06",
-b"\x8C\xD8\x4E\x2B" => "ifs.movies.tanintro.line07",
-b"\x91\xE3\x4E\x32" => "ifs.movies.tanintro.line08",
-b"\xFE\xE1\x4E\x31" => "ifs.movies.tanintro.line09",
-b"\xF0\x8E\x4C\x24" => "ifs.movies.tanintro.line10",
-b"\xB5\x27\x3C\x75" => "ifs.movies.tanoutro.line01",
-b"\xFC\x22\x3C\x72" => "ifs.movies.tanoutro.line02",
-b"\x8F\x24\x3C\x73" => "ifs.movies.tanoutro.line03",
-b"\xD6\x1F\x3C\x70" => "ifs.movies.tanoutro.line04",
-b"\x69\x21\x3C\x71" => "ifs.movies.tanoutro.line05",
-b"\xB0\x1C\x3C\x6E" => "ifs.movies.tanoutro.line06",
-b"\x1D\xEA\xC5\x1D" => "ifs.movies.training.line01",
-b"\x64\xE5\xC5\x1A" => "ifs.movies.training.line02",
-b"\xF7\xE6\xC5\x1B" => "ifs.movies.training.line03",
-b"\x3E\xE2\xC5\x18" => "ifs.movies.training.line04",
-b"\xD1\xE3\xC5\x19" => "ifs.movies.training.line05",
-b"\x18\xDF\xC5\x16" => "ifs.movies.training.line06",
-b"\xAB\xE0\xC5\x17" => "ifs.movies.training.line07",
-b"\xF2\xDB\xC5\x14" => "ifs.movies.training.line08",
-b"\x85\xDD\xC5\x15" => "ifs.movies.training.line09",
-b"\xB3\xC7\xC8\x82" => "ifs.movies.training.line10",
-b"\x20\xC6\xC8\x81" => "ifs.movies.training.line11",
-b"\xD9\xCA\xC8\x84" => "ifs.movies.training.line12",
-b"\x46\xC9\xC8\x83" => "ifs.movies.training.line13",
-b"\xFF\xCD\xC8\x86" => "ifs.movies.training.line14",
-b"\x6C\xCC\xC8\x85" => "ifs.movies.training.line15",
-b"\x25\xD1\xC8\x88" => "ifs.movies.training.line16",
-b"\x92\xCF\xC8\x87" => "ifs.movies.training.line17",
-b"\x4B\xD4\xC8\x8A" => "ifs.movies.training.line18",
-b"\xB8\xD2\xC8\x89" => "ifs.movies.training.line19",
-b"\x78\x58\xC1\x10" => "ifs.movies.training.line20",
-b"\xD9\xB5\x24\x04" => "ifs.movies.utaintro.line01",
-b"\x20\xB1\x24\x01" => "ifs.movies.utaintro.line02",
-b"\xB3\xB2\x24\x02" => "ifs.movies.utaintro.line03",
-b"\x92\xBA\x24\x07" => "ifs.movies.utaintro.line04",
-b"\x25\xBC\x24\x08" => "ifs.movies.utaintro.line05",
-b"\x6C\xB7\x24\x05" => "ifs.movies.utaintro.line06",
-b"\xFF\xB8\x24\x06" => "ifs.movies.utaintro.line07",
-b"\xDE\xC0\x24\x0B" => "ifs.movies.utaintro.line08",
-b"\xA6\x3D\xA4\xDA" => "ifs.movies.utaoutro.line01",
-b"\x13\x3C\xA4\xD9" => "ifs.movies.utaoutro.line02",
-b"\x80\x3A\xA4\xD8" => "ifs.movies.utaoutro.line03",
-b"\x85\x45\xA4\xDF" => "ifs.movies.utaoutro.line04",
-b"\xF1\x3A\xC2\xC3" => "ifs.movies.yavintro.line01",
-b"\x38\x36\xC2\xC0" => "ifs.movies.yavintro.line02",
-b"\xCB\x37\xC2\xC1" => "ifs.movies.yavintro.line03",
-b"\xAA\x3F\xC2\xC6" => "ifs.movies.yavintro.line04",
-b"\x3D\x41\xC2\xC7" => "ifs.movies.yavintro.line05",
-b"\x84\x3C\xC2\xC4" => "ifs.movies.yavintro.line06",
-b"\x17\x3E\xC2\xC5" => "ifs.movies.yavintro.line07",
-b"\xCE\x65\xFD\xB2" => "ifs.movies.yavoutro.line01",
-b"\x3B\x64\xFD\xB1" => "ifs.movies.yavoutro.line02",
-b"\xA8\x62\xFD\xB0" => "ifs.movies.yavoutro.line03",
-b"\x04\x9F\x3A\xC3" => "IgnorableCollision",
-b"\x6B\x3E\xEE\x4A" => "IgnorableCollsion",
-b"\xE7\x19\x88\x84" => "IgnoreHintNodes",
-b"\x0F\xF1\xA8\x62" => "ImmuneToMines",
-b"\xA1\x3C\xE7\x93" => "imp",
-b"\xDA\x31\xC0\xE4" => "imp_hero_darthvader",
-b"\x85\x00\x1A\xA1" => "imp_hero_emperor",
-b"\x59\x19\x70\xA9" => "imp_sfx_atatexplosion",
-b"\xD5\xAC\x62\xFD" => "imp_sfx_atatfinalexplosion",
-b"\xE0\x97\xA6\x66" => "imp_sfx_jetpack",
-b"\x58\xAE\x75\xF4" => "imp_sfx_jetpack_darktrooper",
-b"\xB6\x10\x39\x2D" => "imp_sfx_weap_sonicblast_ord",
-b"\xFE\x9D\x77\xCC" => "imp_sfx_weap_wristrocket_gold",
-b"\x97\xB2\x71\x1E" => "Impact",
-b"\xC2\x48\xDE\x7D" => "impact_template",
-b"\xC6\x0F\x14\x7A" => "ImpactDecalStatic",
-b"\xB1\x32\xBD\x2B" => "ImpactDecalTerrain",
-b"\x92\x7A\x72\x11" => "ImpactEffect",
-b"\x69\x12\x09\xA3" => "ImpactEffectRigid",
-b"\x2F\xC2\xC6\x4B" => "ImpactEffectShield",
-b"\x2A\xC5\x3E\xF7" => "ImpactEffectShieldLarge",
-b"\xBE\x9E\xC7\x8F" => "ImpactEffectShieldSmall",
-b"\xE6\x0F\x22\xD6" => "ImpactEffectSoft",
-b"\xF0\xA2\x7E\x08" => "ImpactEffectStatic",
-b"\x73\x42\x55\x5A" => "ImpactEffectTerrain",
-b"\x9D\xA5\xAA\xEC" => "ImpactEffectWater",
-b"\x88\x7E\xDC\xCE" => "ImpMusic",
-b"\x54\x50\xBB\x4C" => "Impulse",
-b"\x2C\x0E\x1C\xC9" => "InDirectionFactor",
-b"\x71\x6E\x4E\x7A" => "InfiniteDashes",
-b"\x24\x7F\xEB\x76" => "InFlyer",
-b"\x54\x8F\x5C\x98" => "ingame",
-b"\xF0\x17\x9A\xCA" => "ingame_movies",
-b"\x1C\x0D\x93\xFA" => "ingame_spawnducked",
-b"\xF7\xF7\x56\x65" => "ingame_template",
-b"\xAF\x42\x99\x4D" => "ingamemusic",
-b"\xE0\xEF\x04\xCA" => "Inherit",
-b"\x75\x85\xEA\x36" => "InheritMeshInfo",
-b"\x7C\x15\xA4\xB9" => "InheritVelocityFactor",
-b"\x70\xB5\x68\x59" => "InHover",
-b"\xE3\x1D\x25\x2B" => "Initial",
-b"\xB4\x59\xC6\xE4" => "InitialCableLength",
-b"\xA7\x2B\x93\xB4" => "InitialEnergyDrain",
-b"\x4D\x9C\x96\xED" => "InitialFlareOutAlpha",
-b"\x9D\x8D\x38\xC8" => "InitialSalvoDelay",
-b"\xCD\xBF\xF0\x36" => "InitialSalvoDelayProne",
-b"\x20\x8F\x70\x2A" => "InitialSalvoDelayUnderhand",
-b"\x95\xBF\x14\x14" => "InitialWidth",
-b"\x74\x9A\x3D\xF7" => "InputFactor",
-b"\x54\x00\x4F\xF9" => "InputLock",
-b"\xA0\xE1\x63\x84" => "Install",
-b"\x95\xC5\x7B\xC5" => "InstantPlayFireAnim",
-b"\xD2\x77\x6E\x40" => "IntegerFormat",
-b"\x0A\xE5\x63\x85" => "Intensity",
-b"\xE8\xB6\x2B\x9B" => "InterpolationSpeed",
-b"\xF8\x3C\x99\x1C" => "InTurret",
-b"\x2C\xBC\x43\xF4" => "invincible",
-b"\x75\x52\x48\x9B" => "InvincibleEffect",
-b"\xC4\xDE\x77\x5D" => "InvisibilityDecRate",
-b"\x14\x38\x24\xB6" => "InvisibilityIncRate",
-b"\xD0\x63\x43\xE5" => "InvisibilityMax",
-b"\xBA\x6B\x2E\xFB" => "InvisibilityMin",
-b"\x27\xFD\x91\xA5" => "InvisibilityPersistTime",
-b"\x96\xDF\x19\x2F" => "InvisibilityTrigger",
-b"\xFC\xDF\xE5\x1E" => "InWalker",
-b"\x28\xB9\x72\xC3" => "IsAcklay",
-b"\xBE\xE3\x85\xBC" => "IsCollidable",
-b"\x45\xAE\xDD\xEE" => "IsFlyer",
-b"\x89\x82\x46\x6B" => "IsHero",
-b"\xDD\x7E\x84\x5D" => "IsHover",
-b"\x63\xDA\xD5\x9D" => "IsLocked",
-b"\x0B\x82\x2D\x1B" => "IsNotTargetableByPlayer",
-b"\x17\xE2\x60\x2B" => "IsPilotExposed",
-b"\x15\x32\x18\x3B" => "IsProne",
-b"\x65\xD8\x9B\xB7" => "IsRocket",
-b"\x26\xE9\xC0\xB0" => "IsSniper",
-b"\x67\x34\x0F\x71" => "IsTurret",
-b"\xD1\xA6\xA8\x6C" => "IsVisible",
-b"\xB7\x8B\x66\x91" => "IsWalker",
-b"\x73\x9E\x6E\xE1" => "IsZoomed",
-b"\xF4\x30\xE3\xD7" => "Jet",
-b"\x08\x29\xAC\x8A" => "JetAcceleration",
-b"\x3C\x90\xE3\x10" => "JetDuration",
-b"\xDD\xD0\xC4\x69" => "JetEffect",
-b"\xEA\x25\x24\x0C" => "JetEnergyDrain",
-b"\x4D\xDE\xD9\x36" => "JetFuelCost",
-b"\x41\x58\xBB\x1E" => "JetFuelInitialCost",
-b"\x8A\x7C\xA3\xF8" => "JetFuelMinBorder",
-b"\xBF\xC1\x25\x08" => "JetFuelRechargeRate",
-b"\xD3\xDA\x5E\xBC" => "JetIdleEffect",
-b"\x20\xCB\xB2\xC4" => "JetJump",
-b"\x90\x5E\xB7\xF0" => "JetPush",
-b"\xEE\xAB\xC3\xAF" => "JetShowHud",
-b"\x5C\xBB\xFA\x91" => "JetType",
-b"\x53\x98\x04\x02" => "Jitter",
-b"\x0D\x5C\x3F\xA7" => "Jump",
-b"\x74\x5E\x0D\xFC" => "JumpDeduction",
-b"\xA1\x36\x1E\x39" => "JumpEnergyPerSec",
-b"\xCC\xFD\xAE\x4A" => "JumpForce",
-b"\x1C\x45\x61\x07" => "JumpForwardSpeedBoost",
-b"\xF6\x1E\xF4\x8E" => "JumpForwardSpeedFactor",
-b"\x3A\xD2\x15\xC4" => "JumpHeight",
-b"\xBA\x0A\xF2\xF0" => "JumpMinSpeed",
-b"\x50\xFE\x4D\x53" => "JumpMinSpeedMult",
-b"\xEC\xC4\x0D\x58" => "JumpSound",
-b"\x12\x54\x50\x32" => "JumpStrafeSpeedFactor",
-b"\x6A\x38\xDD\xD5" => "JumpTimeMax",
-b"\x80\x9D\xF0\xBF" => "JumpTimeMin",
-b"\x0F\x05\x50\x5D" => "jungle1",
-b"\xA2\x06\x50\x5E" => "jungle2",
-b"\x35\x08\x50\x5F" => "jungle3",
-b"\x30\xFD\x4F\x58" => "jungle4",
-b"\xC3\xFE\x4F\x59" => "jungle5",
-b"\x56\x00\x50\x5A" => "jungle6",
-b"\x10\xCC\xCC\x98" => "kam_movies",
-b"\x99\xAF\xEF\xAF" => "kam1tran1",
-b"\xF9\x46\xE2\x23" => "kammon01",
-b"\x40\x42\xE2\x20" => "kammon02",
-b"\xE6\xC4\x9C\x7B" => "kas_movies",
-b"\xBD\x30\xB8\xCD" => "kas1c_h",
-b"\x38\x02\x95\x02" => "kas2tran1",
-b"\x4F\xE5\x49\x8E" => "kasmon01",
-b"\xE2\xE6\x49\x8F" => "kasmon02",
-b"\xD0\xD4\x9A\x06" => "KickBuildup",
-b"\xB0\x35\x49\xEF" => "KickSpread",
-b"\x4A\xDB\xE4\x16" => "KickStrength",
-b"\x81\x2F\xED\x76" => "KillRegion",
-b"\x98\xD8\x49\xF9" => "KillSoldierSound",
-b"\x17\xC4\x4B\x45" => "Knot",
-b"\xFD\x17\x97\xF6" => "Label",
-b"\x10\x80\x60\x56" => "lan",
-b"\x9C\x36\xEA\x6D" => "Land",
-b"\x7A\x97\x42\x76" => "LandedHeight",
-b"\x57\xA9\xE3\x6A" => "LandingSpeed",
-b"\x9D\x20\x83\x12" => "LandingTime",
-b"\x12\x03\x39\x3A" => "LandOnArrival",
-b"\xE3\x89\x3E\xAD" => "LandSound",
-b"\x55\x3B\xEA\x70" => "lang",
-b"\xD9\xDE\x94\xAE" => "large_muzzle_flash",
-b"\x10\x01\x58\x30" => "LaserGlowColor",
-b"\x40\x82\x85\xBA" => "LaserLength",
-b"\xE7\xB5\x09\xB7" => "LaserTexture",
-b"\xB8\x9A\x17\x6F" => "LaserWidth",
-b"\x59\x83\xFB\xF2" => "latency",
-b"\x91\x83\x46\xE3" => "launcher",
-b"\xF6\x40\xA6\x07" => "Layer",
-b"\x55\xCD\x48\x26" => "Leaf",
-b"\xB0\x95\x63\x49" => "LeafFall",
-b"\x99\xB3\x6F\x6A" => "LeafPatch",
-b"\x70\xEC\x4A\x12" => "Left",
-b"\x35\x02\xE1\xF3" => "LeftFootstepSound",
-b"\x38\x06\xFD\x03" => "LegBoneLeft",
-b"\x8D\xE4\x2B\xC4" => "LegBoneRight",
-b"\x75\x4D\x0F\x58" => "LegBoneTopLeft",
-b"\x8E\xFC\xB9\x9E" => "LegBoneTopRight",
-b"\xAE\x89\x32\x15" => "LegPairCount",
-b"\xDA\xBC\x1B\x39" => "LegRayHitLength",
-b"\x15\x36\xD0\x83" => "Length",
-b"\xAB\xFA\x2D\x25" => "LevelDamp",
-b"\x8F\xF3\xF5\xF4" => "LevelFilter",
-b"\x7E\xA7\x55\xBE" => "LevelFilterLanding",
-b"\x04\x64\xE4\x02" => "LevelSpring",
-b"\x4B\x54\x7C\x7C" => "LifeSpan",
-b"\xEE\xAB\x12\x52" => "LifeTime",
-b"\xC6\x2E\x7B\xA3" => "LiftDamp",
-b"\xF9\x71\x50\x1B" => "LiftSpring",
-b"\x2F\x1E\x9D\xE2" => "Light",
-b"\x3A\x69\x56\xDB" => "Light1",
-b"\xA7\x67\x56\xDA" => "Light2",
-b"\xC1\x3C\x4F\x9A" => "LightAzimAndElev",
-b"\x28\x13\x65\xE8" => "LightBeam",
-b"\x3E\xE7\x6C\x74" => "LightColor",
-b"\x6B\xA6\x70\x63" => "LightDuration",
-b"\xC9\xB1\x7E\x82" => "Lighting",
-b"\x2C\x68\xAB\x7F" => "LightName",
-b"\x86\x87\x67\x1F" => "LightningBolt",
-b"\x94\xA8\xD5\x6D" => "LightningEffect",
-b"\x8E\xE9\xFF\x38" => "LightningEffectScale",
-b"\x7E\x68\x69\xE3" => "LightOdf",
-b"\x4D\x56\x76\x27" => "LightRadius",
-b"\x8C\xD2\xA6\x15" => "LightSaberGlowTexture",
-b"\xE0\x05\x30\x64" => "LightSaberLength",
-b"\x07\x31\x77\xFF" => "LightSaberTexture",
-b"\x87\x97\x0C\xFB" => "LightSaberTrailColor",
-b"\xD8\xCC\xCB\xC9" => "LightSaberWidth",
-b"\x52\x65\xFF\x4E" => "LimitAi",
-b"\xE9\x59\x07\xE6" => "Load",
-b"\x93\x59\x0C\xAE" => "load_bar",
-b"\x91\x96\x2B\xFA" => "load_transition",
-b"\xC3\x3F\xF4\x58" => "load_xtracking",
-b"\xD0\x54\x7F\x9A" => "load_ytracking",
-b"\x69\xB6\x98\x0A" => "load_zoom",
-b"\x6C\x34\xE9\xDF" => "LoadingTextBlinkRate",
-b"\x70\x28\xFB\xA6" => "LoadingTextColorPallete",
-b"\x44\x78\x3B\xC5" => "LoadSpam",
-b"\xA1\xE6\x1B\x79" => "locals",
-b"\x84\xD8\xB4\x9E" => "LocalsColor",
-b"\xB4\x85\x89\x84" => "LockedOffScreen",
-b"\xA8\x82\xE9\xAC" => "LockedOffScreenBehind",
-b"\x42\x0A\xDB\x91" => "LockedOnScreen",
-b"\xFE\xD6\x41\x7C" => "LockedOnScreenBehind",
-b"\xCD\xAE\x71\x93" => "LockedOnSound",
-b"\xB2\x5C\x7B\x02" => "LockedSound",
-b"\x56\xB1\x15\xC9" => "LockOffAngle",
-b"\x3B\x8D\x25\xF8" => "LockOn",
-b"\xCA\xC7\x35\x39" => "LockOnAngle",
-b"\xA3\xE7\xA7\x0A" => "LockOnMaxDist",
-b"\x56\x0D\x6D\x59" => "LockOnMaxTrackDist",
-b"\xE1\xDF\xDB\x56" => "LockOnMinDist",
-b"\x70\x2F\x01\x7F" => "LockOnRange",
-b"\xB2\xC3\x7B\x5F" => "LockOnTime",
-b"\xE3\xBC\x8F\x44" => "LockTime",
-b"\x83\x15\x7B\x80" => "LODDecimation",
-b"\x7B\xD9\x74\x13" => "LodGroup",
-b"\x54\xAA\x5B\x23" => "LODPriorityMod",
-b"\x51\x51\x51\x3F" => "log",
-b"\xFF\xE0\xC3\x09" => "logfinalscore",
-b"\x6B\x48\xEF\xDD" => "Loop",
-b"\x2D\x99\x93\x75" => "Looping",
-b"\x1A\x7B\x34\xE7" => "loss",
-b"\x86\x8C\xCB\x42" => "LostMusic",
-b"\x6A\x00\x39\x8E" => "LostSound",
-b"\xD2\xE5\xD3\xF8" => "LowHealthSound",
-b"\x3E\x52\x65\xC8" => "LowHealthThreshold",
-b"\xCA\x68\xF4\x0D" => "LowResModel",
-b"\x54\x7F\x4E\x51" => "LowResPose",
-b"\x9E\xC2\xF0\xDD" => "LowResTerrain",
-b"\x50\x74\x6A\x13" => "MagReloadTime",
-b"\x08\xE2\x90\xEA" => "main",
-b"\xC5\x17\x86\xB3" => "MainMain",
-b"\x43\x3D\xA8\x35" => "MainTexture",
-b"\xB1\xEF\xA2\xDF" => "Map",
-b"\xBF\xF4\x82\x23" => "MapRangeOverall",
-b"\xB3\xE0\x6A\x3F" => "MapRangeShooting",
-b"\xC8\xD4\xC4\x3A" => "MapRangeViewCone",
-b"\xE5\x3F\x8C\x0E" => "MapScale",
-b"\xEA\xE6\x76\x95" => "MapSpeedMax",
-b"\x00\x4C\x8A\x7F" => "MapSpeedMin",
-b"\x50\x71\xEF\x29" => "MapTargetFadeRate",
-b"\x80\x8D\xC3\x9D" => "MapTexture",
-b"\x9E\x44\xD7\xBD" => "MapViewConeAngle",
-b"\x6A\x7E\xDB\xAB" => "MapViewMax",
-b"\x80\xE3\xEE\x95" => "MapViewMin",
-b"\x12\x09\xA9\xDE" => "MarkerLarge",
-b"\xA6\xC1\xD9\x9B" => "MarkerSmall",
-b"\x92\xA5\x9C\x17" => "MarkerSpawn",
-b"\x69\x45\x77\xE7" => "Mask",
-b"\x84\x2C\x27\x7E" => "MaskTexCoords",
-b"\x61\x4B\x99\x4E" => "MaxAcceleration",
-b"\xAF\x39\xE4\xB1" => "MaxAlpha",
-b"\x74\x42\x82\x72" => "MaxAngle",
-b"\xFB\xBE\xF7\xE6" => "MaxBallAngle",
-b"\x8D\x7D\x12\xFD" => "MaxBranches",
-b"\x0F\x28\x1D\xA6" => "MaxChargeStrengthHeavy",
-b"\x74\x2D\xC1\xE6" => "MaxChargeStrengthLight",
-b"\x94\xE7\xD0\x47" => "MaxDamage",
-b"\x43\xDC\x86\x07" => "MaxDecals",
-b"\xC3\xDC\x89\x71" => "MaxDelayHeavy",
-b"\x20\x28\x74\xE2" => "MaxDelayLight",
-b"\xB4\x73\x21\x70" => "MaxDiffuseColor",
-b"\x7D\x5B\xF8\x39" => "MaxDist",
-b"\xF6\x5C\x91\xE6" => "MaxDistance",
-b"\x76\x83\xEA\x95" => "MaxEntities",
-b"\x90\xAE\x7F\xFD" => "MaxFallingLeaves",
-b"\x0D\x24\xE6\x74" => "MaxGodraysInWorld",
-b"\x5D\xC5\x60\xD7" => "MaxGodraysOnScreen",
-b"\x1B\x1F\x97\x19" => "MaxHealth",
-b"\x5C\x9A\x77\x25" => "MaxHeavy",
-b"\x3A\xC2\x40\xF4" => "MaxHeavyDecay",
-b"\xB4\xCA\x37\x8B" => "MaxInterval",
-b"\x93\xC9\xC3\x54" => "MaxItems",
-b"\x4E\x74\x37\xAD" => "MaxJumpDistance",
-b"\xA1\x14\x66\x5D" => "MaxLength",
-b"\x92\x9B\x39\x9D" => "MaxLifetime",
-b"\x43\x3E\x9C\x56" => "MaxLight",
-b"\x03\x16\x22\x78" => "MaxLightDecay",
-b"\xCC\x1D\xD6\xB9" => "MaxLodDist",
-b"\x14\x05\x97\x38" => "MaxOmega",
-b"\x88\x5C\x5D\x86" => "MaxParticles",
-b"\x23\xE4\x77\xDE" => "MaxParticleScale",
-b"\xE0\x4B\x53\xB5" => "MaxPitchSpeed",
-b"\x61\x96\x9A\x31" => "MaxPlaybackTime",
-b"\x0D\xB5\xB0\x38" => "MaxPos",
-b"\xA0\xFF\x19\x28" => "MaxPressedTime",
-b"\x99\x5D\xE0\xAE" => "MaxPush",
-b"\x8A\x36\xC9\x0D" => "MaxRandomSideOffset",
-b"\xE6\xEB\xBC\x43" => "MaxRange",
-b"\x5D\xAA\x0D\x7C" => "MaxRaysPerGodray",
-b"\x2D\x81\x3F\xB4" => "MaxScale",
-b"\xF3\x48\x19\x3B" => "MaxScatterBirds",
-b"\xBD\xF5\x88\x2C" => "MaxShadowLength",
-b"\x81\xC0\xE9\xC3" => "MaxShakeAmt",
-b"\xA0\xEB\x6E\x62" => "MaxShakeLen",
-b"\xBC\x91\x34\xDA" => "MaxShield",
-b"\x08\x13\xBA\x80" => "MaxSize",
-b"\xEB\x3E\x13\x97" => "MaxSkew",
-b"\x64\x03\xDF\x86" => "MaxSpeed",
-b"\x2F\xE0\x0A\xB3" => "maxsplit",
-b"\x12\xBD\xDC\xAE" => "MaxSpread",
-b"\xA9\x0E\x94\x60" => "MaxSquadronSize",
-b"\x84\x81\x32\xEE" => "maxsquadsize",
-b"\x3D\xA3\x37\x34" => "MaxStrafeSpeed",
-b"\x1C\x9B\xBD\x40" => "MaxStrength",
-b"\xCD\x48\x0A\xA0" => "MaxTargets",
-b"\x51\x59\xA9\x47" => "MaxTerrainAngle",
-b"\x04\xBE\x0A\x22" => "MaxTimeLeftHeavy",
-b"\x3B\x6A\x1A\x93" => "MaxTimeLeftLight",
-b"\xED\x07\xFD\xEA" => "MaxTotalWeight",
-b"\x89\xF5\x33\x54" => "MaxTurnSpeed",
-b"\x26\xC6\x32\x73" => "MaxValue",
-b"\x6C\xB8\xBC\xA7" => "MaxVel",
-b"\x8F\x17\x62\xDA" => "MaxViewDistance",
-b"\x87\xAA\x35\x73" => "MaxYawSpeed",
-b"\x72\x45\x67\x84" => "med_muzzle_flash",
-b"\xCB\x7A\x1F\x80" => "MedalsTypeToLock",
-b"\xE0\xA8\x67\x66" => "MedalsTypeToUnlock",
-b"\x76\xD7\xAF\x35" => "MEDIUM",
-b"\x6B\xC7\xF3\xC8" => "MediumBan",
-b"\x7B\x63\xCA\x79" => "melee",
-b"\x8E\x23\x29\x81" => "MemberID",
-b"\xBC\xBE\x00\xA1" => "Mesh",
-b"\xA0\x6C\x42\x4B" => "MeshInfo",
-b"\x20\xDA\x8B\x83" => "MidSpeed",
-b"\x29\x99\xB5\x7D" => "MinAlpha",
-b"\x96\x4F\x54\xFE" => "MinDamage",
-b"\x8D\xE6\xB9\x1C" => "MinDelayHeavy",
-b"\xCA\x81\xC6\xE1" => "MinDelayLight",
-b"\xE6\x6E\xA2\xC2" => "MinDiffuseColor",
-b"\x50\xCF\x35\xB8" => "MinDistance",
-b"\x38\xCD\x40\x3F" => "Mindtrick",
-b"\xB6\xED\x89\x7E" => "mine",
-b"\x64\x20\xED\xF5" => "MineHealth",
-b"\x63\x5B\x9E\x47" => "MinEnemyRadius",
-b"\xA0\x5F\x41\x91" => "MinFootHeight",
-b"\x4A\xE8\x56\x44" => "MinHeavy",
-b"\xC8\xDB\xFB\xA4" => "MinHeavyDecay",
-b"\x87\x48\x72\x35" => "MinInactiveTime",
-b"\x26\x9A\xC6\xF8" => "MinInterval",
-b"\xEC\x97\x93\xBD" => "MinLifeTime",
-b"\xB1\x42\x40\x5A" => "MinLight",
-b"\xB1\x61\x54\x1C" => "MinLightDecay",
-b"\x9E\xAE\x45\x62" => "MinLodDist",
-b"\x38\xEA\x5C\x05" => "MinMaxDepth",
-b"\x9E\x71\xC2\x8E" => "MinOmega",
-b"\x0D\x38\xFE\x7E" => "MinParticleScale",
-b"\x83\xBE\x13\x68" => "MinPlaybackTime",
-b"\xB7\x1B\x7C\x59" => "MinPos",
-b"\xC3\x1A\xD1\x3E" => "MinPush",
-b"\x6C\x5B\xF2\x1E" => "MinRange",
-b"\xCF\x98\x15\xF2" => "MinRaysPerGodray",
-b"\xD7\x00\x36\x4D" => "MinScale",
-b"\x9F\x26\xB4\x4F" => "MinShakeAmt",
-b"\x12\x88\xF1\xCF" => "MinShakeLen",
-b"\x6E\xCF\xEB\xEA" => "MinSize",
-b"\x62\xEB\xE2\xFB" => "MinSpeed",
-b"\x68\x30\x05\x59" => "MinSpread",
-b"\x02\x21\x83\x4D" => "MinStrength",
-b"\xAE\x13\x64\xBF" => "MinTimeLeftHeavy",
-b"\x8D\x15\x04\x41" => "MinTimeLeftLight",
-b"\x4C\xA5\xA5\xF1" => "MinValue",
-b"\xCA\x20\x0F\xB1" => "MinVel",
-b"\xBA\x18\x47\x5C" => "MirrorInfo",
-b"\x83\x68\xEF\xA4" => "missile",
-b"\xE0\x48\x9B\xA0" => "missile_incoming",
-b"\x29\x84\xD9\x53" => "missile_locked_on_me",
-b"\x56\x5D\xA0\x77" => "missile_locking_on_me",
-b"\x85\x63\x50\x72" => "MissionLog",
-b"\x12\xE0\x6E\xEC" => "Mode",
-b"\xB5\x16\x8B\x1A" => "Mode3D",
-b"\xF2\x43\xBA\x0F" => "ModeDoppler",
-b"\x5A\x66\x8B\xB0" => "Model",
-b"\xFD\xDE\xCC\x1E" => "Model3D",
-b"\xE5\xF4\xE9\x54" => "ModeTexture",
-b"\xF8\x81\x3E\xEF" => "ModeTextureColor",
-b"\xAE\x75\x8F\x81" => "Modulate",
-b"\xE9\xDC\x37\xD9" => "MomentumFilter",
-b"\x90\x4A\xAA\xF2" => "MountPos",
-b"\x7B\x6A\x61\x48" => "MouseTurnDecay",
-b"\x14\x00\x4B\x18" => "Move",
-b"\xFA\xFB\x58\xFD" => "MovementScale",
-b"\xF6\x0B\x05\x21" => "MoveTension",
-b"\x8A\x30\xF1\x88" => "MoveTensionX",
-b"\x1D\x32\xF1\x89" => "MoveTensionY",
-b"\x64\x2D\xF1\x86" => "MoveTensionZ",
-b"\x04\xAA\x11\xAA" => "MoveX",
-b"\x97\xAB\x11\xAB" => "MoveY",
-b"\x2A\xAD\x11\xAC" => "MoveZ",
-b"\xCF\x9C\xF3\xAE" => "Movie",
-b"\x26\x4C\xE4\xB0" => "MovieProperties",
-b"\x20\xFE\x96\xE3" => "moviesubtitles",
-b"\x0E\x42\x45\x3C" => "MovingTurnOnly",
-b"\xD7\x50\x3E\xD5" => "MultilineText",
-b"\x4E\x4B\xD9\x26" => "multiworld",
-b"\x18\xC9\x03\xAB" => "mus_movies",
-b"\x70\x5B\x4E\xF4" => "mus_sfx_blur",
-b"\xD7\xF6\x88\xF6" => "mus_sfx_droidconcept",
-b"\xD7\x4E\xD4\x33" => "mus_sfx_lavaburst",
-b"\x04\xCC\xF9\x97" => "mus_sfx_lavaburst_xl",
-b"\x14\x9E\x17\x04" => "mus_sfx_lavaspray",
-b"\x29\x10\x32\x22" => "mus_sfx_lavaspray_skydome",
-b"\xFD\x35\x32\xC0" => "mus_sfx_lightningballs",
-b"\xD3\xEF\x40\x4B" => "mus_sfx_sparks",
-b"\xC7\x2F\x09\x0A" => "mus1",
-b"\xB6\xD3\xA1\xEC" => "mus1_1CTF",
-b"\x57\x17\x9A\xDC" => "mus1_BRun",
-b"\x42\xAE\x35\x15" => "mus1_campaign",
-b"\x5B\x80\x54\x4D" => "mus1_CTF",
-b"\x88\x92\x33\x7D" => "mus1_CTFCP",
-b"\xA0\x80\x7F\x9F" => "mus1_deathreagen",
-b"\xFE\x6D\x64\x8C" => "mus1_design",
-b"\x57\xE7\x5E\xF3" => "mus1_grate2",
-b"\x75\xA5\x83\xC5" => "mus1_mist",
-b"\xED\xD4\x96\x8A" => "mus1_rumble",
-b"\x4C\xD0\xCE\x6D" => "mus1_SoundEmitters",
-b"\x14\x48\xA7\x03" => "mus1_SoundRegions",
-b"\x70\x2D\x2F\xDF" => "mus1_SoundSpaces",
-b"\x47\x53\x7E\x7C" => "mus1_TDM",
-b"\x81\x19\x3A\x4F" => "mus1tran1",
-b"\xD4\x4F\x9C\x9F" => "music",
-b"\xA3\xE0\x84\x17" => "MusicDelay",
-b"\x5F\xB6\xEF\x4D" => "MusicSpeed",
-b"\xB1\x6A\xCD\xF1" => "musmon01",
-b"\xF8\x65\xCD\xEE" => "musmon02",
-b"\xF0\x94\x96\xD5" => "MustShowOneFrame",
-b"\x54\x9F\x06\xD6" => "mute",
-b"\xDF\x2E\xB1\xE4" => "MuteDistance",
-b"\xCE\xCB\xCC\xCF" => "MuzzleFlash",
-b"\x63\xA8\xAA\x39" => "MuzzleFlashEffect",
-b"\x7B\x53\x63\x1F" => "MuzzleFlashModel",
-b"\x28\xDD\x59\x1D" => "myg_movies",
-b"\x61\x01\x3D\x0D" => "myg_sfx_fire",
-b"\xD7\xF4\x45\x71" => "myg1",
-b"\x89\x1A\x9C\x8A" => "myg1_Buildings",
-b"\xDD\x6B\xAA\xDE" => "myg1_Con-VehicleSpawns",
-b"\x45\x49\x84\xC9" => "myg1_CP-Assault",
-b"\xB0\x98\xAB\xB7" => "myg1_cp-conquest",
-b"\x6B\x15\x0B\xC3" => "myg1_CTF",
-b"\x2E\x17\xB6\x2C" => "myg1_design",
-b"\x2B\x31\xA7\xEB" => "myg1_flags",
-b"\xE0\x4E\x10\x5E" => "myg1_Platforms",
-b"\xE6\x1F\x4A\x20" => "myg1_Rubble",
-b"\x47\x57\xDD\xF4" => "myg1_sfx_collector",
-b"\x28\xFF\x0D\x41" => "myg1_sfx_collector_damage1",
-b"\xE1\x03\x0E\x44" => "myg1_sfx_collector_damage2",
-b"\x4E\x02\x0E\x43" => "myg1_sfx_collector_damage3",
-b"\x07\x07\x0E\x46" => "myg1_sfx_collector_damage4",
-b"\xD7\xAA\x5C\xB0" => "myg1_sfx_collector_exp",
-b"\x1E\x15\xEC\xAE" => "myg1_sfx_generator_damage1",
-b"\x8B\x13\xEC\xAD" => "myg1_sfx_generator_damage2",
-b"\xF8\x11\xEC\xAC" => "myg1_sfx_generator_damage3",
-b"\xF6\xB6\x1A\x07" => "myg1_shields",
-b"\x24\xE2\x45\x5B" => "myg1_SoundRegions",
-b"\x33\x88\xCC\xF2" => "myg1_Turrets",
-b"\x11\x86\xAB\x55" => "myg1tran1",
-b"\x61\x1D\xAE\x7A" => "mygmon01",
-b"\xA8\x18\xAE\x77" => "mygmon02",
-b"\xD3\x64\x5A\x47" => "nab_fireworks",
-b"\xF6\xC5\x6C\x77" => "nab_movies",
-b"\x5B\xCC\x54\x17" => "nab_sfx_fountain",
-b"\x0D\x2D\x6C\x2B" => "nab1c_h",
-b"\xCB\x9D\x57\x20" => "nab1tran1",
-b"\x5F\xE3\x58\xA4" => "nab2_attached_lights",
-b"\x60\x72\x1E\x22" => "nab2_prop_treebill",
-b"\x6A\xE1\xC2\x41" => "nab2c_h",
-b"\x7F\x75\xB9\x2A" => "nabmon01",
-b"\x12\x77\xB9\x2B" => "nabmon02",
-b"\x0A\x3D\xDA\x64" => "naboo2",
-b"\xE4\xDB\x44\xD8" => "naboo2_1Flag",
-b"\x91\x64\xD5\xE4" => "naboo2_campaign",
-b"\xA1\x9A\xFA\xE8" => "naboo2_Collision",
-b"\x58\xB3\x2A\x76" => "naboo2_CommonDesign",
-b"\x95\x8C\x44\x0A" => "naboo2_Conquest",
-b"\xA2\xAB\x48\xD6" => "naboo2_CTF",
-b"\x6A\xCB\xE4\xF2" => "naboo2_hunt",
-b"\x2B\xDA\x9C\x34" => "naboo2_Reflections",
-b"\xE5\xF9\xFA\xEC" => "naboo2_soundemitters",
-b"\x53\x3C\x73\x5A" => "naboo2_SoundRegions",
-b"\x16\x6B\x1B\x42" => "naboo2_TDM",
-b"\xE6\xBD\x39\x8D" => "Name",
-b"\x8F\x53\xCC\x63" => "NameMesh",
-b"\x0F\x57\xF3\x54" => "Near",
-b"\xA1\x0F\x70\x24" => "NearMultiScreenRange",
-b"\x9A\x1C\x55\x50" => "NearSceneRange",
-b"\x36\xCF\x84\x3C" => "NearSplitScreenRange",
-b"\xC4\xCD\x60\x44" => "NeedAmmoVO",
-b"\x94\x46\xA3\x03" => "NeedBackupVO",
-b"\x8E\x4D\x6B\x00" => "NeedMedicVO",
-b"\xEA\x03\x2F\x1D" => "NeedPickupVO",
-b"\x53\x7E\xF7\x0F" => "NeedRepairVO",
-b"\xBB\x8F\x4E\x12" => "nethires",
-b"\xFA\x9F\xB8\x90" => "NetPlayers",
-b"\xCC\xE7\x4C\x64" => "NetRegion",
-b"\x68\xF9\x03\x15" => "NetUpdateSize",
-b"\xEB\x92\x99\x26" => "neu",
-b"\xD8\x1A\x4B\x8C" => "neutral",
-b"\x6F\x43\x35\xB1" => "NeutralColor",
-b"\x1B\xF3\xA8\xF1" => "NeutralizeTime",
-b"\xD2\xA1\x92\xDF" => "NeverCrashWhenUnpiloted",
-b"\x69\x78\xA6\xC1" => "NewAttack",
-b"\xE8\x8D\xB6\x5C" => "Next",
-b"\xEA\x96\x5D\x66" => "NextAimer",
-b"\x7C\x37\x8F\xE9" => "NextBarrel",
-b"\x44\xF4\x16\x83" => "NEXTCHARGE",
-b"\x66\x40\xA0\x5E" => "NextDropItem",
-b"\x42\x05\x56\x80" => "NextIsGrouped",
-b"\xAD\x02\xBC\xB7" => "NextLinkedTerminal",
-b"\xB8\x46\xBA\x81" => "NoAi",
-b"\x38\x42\x6F\x96" => "NoAIBoard",
-b"\x4F\x81\x3D\x0D" => "NoAim",
-b"\x8C\xCE\x5C\x2E" => "NoAudioStreaming",
-b"\xA1\x7C\x38\x4C" => "NoChaining",
-b"\x7D\xB4\x66\x18" => "NoCheckcd",
-b"\x49\xDA\xAD\x28" => "NoCombatInterrupt",
-b"\x0D\x4E\xC1\xB1" => "Node",
-b"\x74\x1B\x18\x16" => "NoDeathExplosions",
-b"\xB7\x90\x06\xA0" => "NoDebugHeap",
-b"\x5A\x90\x4E\x51" => "Nodes",
-b"\xD4\x7A\x8E\x71" => "NoDrag",
-b"\x85\x99\x9B\x93" => "NoEnterVehicles",
-b"\xE4\xF8\x29\x67" => "NoFirstPersonFireAnim",
-b"\x44\x49\x15\xCD" => "NoFrameLock",
-b"\x44\xE7\xE5\x77" => "NoIntro",
-b"\xA3\xCA\xB8\x86" => "NoiseFreq",
-b"\x11\x5E\x42\x37" => "NoiseRoughness",
-b"\x0D\x0D\x4F\x20" => "NoJournal",
-b"\xBE\x75\x99\x03" => "NoLifeSpanDisplay",
-b"\xC3\x84\xBA\xDA" => "NoMovies",
-b"\xF6\x80\x1F\xC1" => "NoNames",
-b"\xDB\xAF\xA7\xAD" => "none",
-b"\x9A\xBA\x17\x63" => "none_reformat",
-b"\xB7\x81\x39\xD4" => "NoPoolErrors",
-b"\x39\x13\x87\x5E" => "NoPortals",
-b"\x61\x6B\x97\xF6" => "NoPregame",
-b"\xFC\xE0\x5D\x7A" => "NoRandomSpring",
-b"\xE1\x4D\xFC\xA7" => "NoRegisterStep",
-b"\x0E\x45\xE8\x75" => "NoRender",
-b"\x86\x9D\x95\x80" => "NoReverb",
-b"\x52\x9C\x8B\xE6" => "Normal",
-b"\x87\x9A\x64\xDE" => "NormalDirection",
-b"\xAC\x87\xC5\xEA" => "NormalMapTextures",
-b"\x04\x93\xEF\xDA" => "North",
-b"\x73\xD8\xE8\x91" => "NorthLarge",
-b"\xCB\x18\xC7\x84" => "NorthSmall",
-b"\xDB\x84\x99\xFB" => "NorthSpawn",
-b"\xF1\x3E\x55\xF4" => "NoSound",
-b"\x02\x4A\x69\x31" => "NoStartUpMusic",
-b"\x21\x4B\x38\x63" => "NoTargetLightningEffectCount",
-b"\x4E\xF9\x09\xDD" => "NoTargetLightningEffectRandomSpread",
-b"\xBA\x23\x10\xE5" => "noteamdamage",
-b"\x40\xEC\x8C\xB0" => "NotValues",
-b"\xFF\x4A\x49\xBC" => "NoWait",
-b"\x33\xEE\xE2\x4B" => "NoWaitLate",
-b"\x4A\x29\x03\x0B" => "NTGUI",
-b"\xB7\xCB\x01\xED" => "NumberColor",
-b"\xC0\xD9\x8A\xCE" => "NumberToTime",
-b"\xD4\x7D\x73\x37" => "NumberVector3",
-b"\x03\x58\xEF\xD4" => "NumBloomPasses",
-b"\x35\x15\x30\x8A" => "NumBobPoints",
-b"\x3C\xDB\x0B\xD8" => "NumChildren",
-b"\x69\x28\x4F\xF5" => "NumChunks",
-b"\xB8\x8A\xB5\x03" => "NumDamageEdges",
-b"\xF0\x75\x89\x45" => "NumDustObjectsInEffects",
-b"\x48\xBE\x53\xE4" => "NumFlareOuts",
-b"\xBE\xBA\x69\x02" => "NumLines",
-b"\xC1\x26\x79\x30" => "NumObjects",
-b"\xDA\xF7\x33\xD1" => "NumParticles",
-b"\x07\x22\xC0\x5A" => "NumParts",
-b"\x6A\x46\x57\xF3" => "NumRays",
-b"\xBD\xDE\xFB\x40" => "NumSegments",
-b"\x54\xDE\x6B\x83" => "NumStars",
-b"\x8D\x20\xF9\x67" => "NumSubFlashesMinMax",
-b"\x76\x92\x35\xE0" => "NumTentacles",
-b"\x77\x78\xBD\x1A" => "NumVisible",
-b"\xBA\x0C\xC6\xB8" => "Object",
-b"\x80\xD3\xA0\x6F" => "ObjectiveList",
-b"\xB9\x03\x23\x20" => "ObjectiveOffScreen",
-b"\x65\x65\xD4\xD6" => "ObjectiveOffScreenBehind",
-b"\x15\x86\xD5\x50" => "ObjectiveOnScreen",
-b"\x19\x2E\xF0\x13" => "ObjectiveOnScreenBehind",
-b"\x42\xA2\x3A\x5F" => "ObjectVisibility",
-b"\x74\x12\xB3\x0D" => "ObstructionHFGain",
-b"\x8A\xA7\xA3\xA0" => "ObstructionLFRatio",
-b"\x8B\x1B\x8A\x7C" => "OcclusionHFGain",
-b"\xAF\x06\x6B\xA9" => "OcclusionLFRatio",
-b"\x1A\x43\x9C\xA6" => "OceanEnable",
-b"\x38\x9A\x40\xAB" => "Odf",
-b"\x44\xB5\x8F\x08" => "offensive",
-b"\x6F\x63\xFA\x18" => "Offhand",
-b"\x67\x6B\x2A\xAF" => "Offhand2",
-b"\xD4\x69\x2A\xAE" => "Offhand3",
-b"\xE7\xB9\xD0\x27" => "OffhandAnimation",
-b"\x28\xB2\x10\x9E" => "OffhandFirePointName",
-b"\xC4\xEA\x67\xCA" => "OffhandGeometryName",
-b"\x6F\x94\xCD\x2E" => "OffhandWeapon",
-b"\xCA\xD3\xC8\x14" => "Offset",
-b"\x29\x43\x90\x34" => "OffsetAngle",
-b"\x79\xCD\x59\x92" => "OffsetPath",
-b"\xC1\x27\xC6\x6A" => "OffsetUV",
-b"\x6B\x60\x1F\xFE" => "OffTime",
-b"\x52\x62\x79\x89" => "OldTerrainShader",
-b"\x0A\x00\x59\x9D" => "OmegaXDamp",
-b"\x3D\x39\x84\x91" => "OmegaXSpring",
-b"\x68\x44\x76\xAA" => "OmegaZDamp",
-b"\x63\x96\x94\x37" => "OmegaZSpring",
-b"\x24\x06\x6E\xD5" => "Omni",
-b"\x3E\xF2\xFF\xA0" => "OmniLightRadius",
-b"\x3A\x81\xC1\xC2" => "OmniRadius",
-b"\xD0\x2F\x34\x61" => "On",
-b"\xED\xB8\x64\x22" => "OneWorld",
-b"\x9F\xB1\x71\xDE" => "OnSound",
-b"\x69\x1C\xB5\xD0" => "OnTime",
-b"\x40\xB9\x5E\x29" => "OpenRatio",
-b"\x50\x74\x3F\x32" => "OpenSound",
-b"\xCF\xE4\x5D\xD5" => "OpenTrigger",
-b"\xAE\x9A\x8F\x77" => "OptimalRange",
-b"\x84\x29\x34\x5D" => "Or",
-b"\x5D\xF0\x9B\x39" => "Ordnance",
-b"\x18\xC4\xA8\x24" => "ordnance_template",
-b"\x07\xDF\x2B\xFB" => "Ordnancecollision",
-b"\xAB\xCA\x0A\x33" => "OrdnanceCollisionPrim",
-b"\x0C\x37\x30\x3F" => "OrdnanceEffect",
-b"\x72\x21\x03\xA6" => "OrdnanceGeometryName",
-b"\x5E\x9C\x89\xF8" => "OrdnanceName",
-b"\x5C\xBE\xF9\xCC" => "OrdnanceSound",
-b"\xF3\x9A\xE3\x12" => "OscillationEnable",
-b"\xD4\x17\xA5\xF4" => "OverheatSound",
-b"\x1A\x4E\x2F\xB5" => "OverheatSoundPitch",
-b"\x74\x9F\xB0\xA0" => "OverheatStopSound",
-b"\xAC\x74\xDB\x09" => "OverrideTexture",
-b"\xBA\x94\x78\x22" => "OverrideTexture2",
-b"\x46\x53\x5C\x0D" => "pain_chatter_template",
-b"\xEC\xEE\x55\x4F" => "Pan",
-b"\xFD\xFC\xCD\xEA" => "Parent",
-b"\xBF\x43\x87\x8A" => "PARTICLE",
-b"\xF1\xFF\x93\xC3" => "ParticleDensity",
-b"\x7E\xEF\x14\x22" => "ParticleDensityRange",
-b"\x5F\x21\xBB\xCE" => "ParticleEmitter",
-b"\x92\x36\xF2\x7B" => "ParticleLengthMaxSpeed",
-b"\x54\x5D\xF2\xD9" => "ParticleLengthMinSpeed",
-b"\xF3\x57\xB9\x6E" => "particlemass",
-b"\x16\x1B\xE4\x55" => "ParticleSize",
-b"\x62\xAB\xBB\xBF" => "PassengerEyePoint",
-b"\xA4\x11\xC5\x64" => "PassengerSlots",
-b"\x41\x24\x4E\x60" => "PassThrough",
-b"\x18\x5F\x4B\x36" => "password",
-b"\xEB\x36\x90\x00" => "PatchDivisions",
-b"\xFB\x38\xF7\x6E" => "PatchResolution",
-b"\x36\x4D\x87\x84" => "Path",
-b"\xC5\xB4\x0A\x45" => "PathCount",
-b"\xF4\xB3\x9D\xAB" => "PathFollowerAcceleration",
-b"\xE5\x9F\x6F\x88" => "PathFollowerBranchMaxAngle",
-b"\xEC\x8A\x52\x9F" => "PathFollowerBranchPaths",
-b"\xC5\xEC\xEC\x9E" => "PathFollowerBranchRange",
-b"\x18\x8B\x15\xFF" => "PathFollowerClass",
-b"\x3C\x7D\x5F\x99" => "PathFollowerClass_CTF",
-b"\x8D\x2A\xCF\xC5" => "PathFollowerFlyerVsFlyer",
-b"\x94\xCE\x03\xE1" => "PathFollowerMinBranchDist",
-b"\x37\x6C\x97\x2D" => "PathFollowerRollAcceleration",
-b"\x05\x0C\x90\x60" => "PathFollowerRollAccelerationLag",
-b"\x52\x53\x42\x93" => "PathFollowerRollTolerance",
-b"\xCB\x2D\x03\x91" => "PathFollowerSpeed",
-b"\x39\xEB\xBC\x3C" => "PathRoll",
-b"\x61\x90\xF7\x67" => "PathSpeedType",
-b"\x6F\xF6\xBD\xD7" => "PathTime",
-b"\x46\x8E\x34\x89" => "PathType",
-b"\x4C\x55\xE5\x3B" => "PauseTime",
-b"\x6C\xEE\xEF\x3D" => "PCaxPitchSpeed",
-b"\x1B\x6A\xE4\x48" => "PCMaxPitchSpeed",
-b"\xEC\xDE\xBF\x4B" => "PCMaxStrafeSpeed",
-b"\x5C\xAF\x6F\x27" => "PCMaxTurnSpeed",
-b"\xE0\x56\x4D\x93" => "PCMaxYawSpeed",
-b"\xB8\xFE\x1B\x84" => "PCPitchRate",
-b"\x8D\x51\x92\x2B" => "PCPitchSpeed",
-b"\x52\xD3\x24\x86" => "PCPitchTurnFactor",
-b"\xB6\xA3\x21\x7C" => "PCSpinRate",
-b"\xC1\xE4\x11\x3C" => "PCTurnRate",
-b"\xE6\x10\x9B\xC9" => "PCTurnSpeed",
-b"\xF7\x62\x86\x0E" => "PCYawTurnFactor",
-b"\x0F\xEF\xA9\x52" => "PerceivedTeam",
-b"\x5A\xFA\xF9\x75" => "Percent",
-b"\x80\xB2\x97\xD3" => "Person",
-b"\x2E\x41\x8F\x73" => "PersonAmmo",
-b"\x34\x21\x62\x84" => "PersonEnergy",
-b"\x06\xD2\x1A\xF5" => "PersonHealth",
-b"\x02\x46\xB9\x0B" => "PersonScale",
-b"\x0A\xF9\x5E\xD2" => "PhillipsConstant",
-b"\x84\xDD\x4C\xB5" => "PhysicsActive",
-b"\x54\x8F\x9C\x75" => "PickupSound",
-b"\xE1\x6A\x06\x10" => "Pilot",
-b"\x65\xD0\x76\xA9" => "Pilot9Pose",
-b"\x69\xC0\x4F\x6E" => "PilotAnimation",
-b"\xF9\xE0\xBC\x81" => "PilotDeath",
-b"\xA6\x39\xCA\x51" => "PilotPosition",
-b"\xA7\xB6\x42\x08" => "PilotSkillRepairScale",
-b"\x29\x69\xEE\x91" => "PilotType",
-b"\xB1\x4A\x32\xBD" => "Pitch",
-b"\x5D\x01\x32\x4D" => "PitchBuildupMultiplier",
-b"\x2F\xA0\xAB\xFE" => "PitchDamp",
-b"\xB8\x9E\x11\x35" => "PitchDev",
-b"\xCA\x30\x46\x02" => "PitchFactor",
-b"\xC3\xA8\x0C\x9B" => "PitchFilter",
-b"\xC8\xCD\xD5\x53" => "PitchFilterDecel",
-b"\x39\xB1\x03\x34" => "PitchLimits",
-b"\xC3\xAB\xB8\xB9" => "PitchRate",
-b"\xCA\xB6\x0C\x8D" => "PitchSpread",
-b"\xF9\xC2\x2F\x0B" => "PitchTurnFactor",
-b"\x63\x50\xCF\x83" => "PlantedHash",
-b"\xEC\x43\x8A\xCF" => "Playback",
-b"\xEA\xBE\xF3\x76" => "PlayEffectOnOwner",
-b"\x34\x08\xE2\xC0" => "PlayEffectOnOwnerAimer",
-b"\x00\xC3\x99\x2C" => "Player",
-b"\xAD\x89\x64\xFA" => "player_command_template",
-b"\x77\x0F\xE4\xEB" => "Player1objectives",
-b"\xDE\x81\x98\xA1" => "Player2objectives",
-b"\x3D\xD0\x7B\x12" => "Player3objectives",
-b"\x54\x36\x3A\xF0" => "Player4objectives",
-b"\x0F\xB5\x07\xC7" => "playercount",
-b"\x70\x5D\x2C\x39" => "PlayerDirectionLarge",
-b"\xB8\x81\x94\xEC" => "PlayerDirectionSmall",
-b"\x1C\x98\x46\xDB" => "PlayerDirectionSpawn",
-b"\x4F\xC9\xB8\xCD" => "playerflyerpathing",
-b"\xDE\xD8\x4A\x6B" => "PlayerFOVLarge",
-b"\x22\xE5\xB0\x46" => "PlayerFOVSmall",
-b"\x46\x35\xE6\x20" => "PlayerFOVSpawn",
-b"\xCA\x85\xED\x1E" => "PlayerHealthBelow25",
-b"\x03\xD7\x00\x3A" => "PlayerLimit",
-b"\x37\x67\xE5\xC1" => "PlayerText",
-b"\xEA\x29\x5F\xD4" => "PlayExplosion",
-b"\xCA\x9E\x0B\x01" => "PlayInterval",
-b"\x71\x1E\xCE\xED" => "PlayIntervalDev",
-b"\x6E\x0B\x93\xA7" => "PlayProbability",
-b"\x91\x6C\xAE\x18" => "Point",
-b"\x63\x24\x4B\x71" => "PointsToUnlock",
-b"\x60\x10\x3D\xC7" => "pol_movies",
-b"\xEF\x37\xD6\x3C" => "pol1",
-b"\x7D\x6B\x17\x31" => "pol1_1flag",
-b"\xC6\x69\xA3\x51" => "pol1_buildings01",
-b"\xDA\x77\x53\xC7" => "pol1_Campaign",
-b"\x14\x1D\xBE\xF0" => "pol1_Common_Design",
-b"\x26\x7B\xA9\x2A" => "pol1_Conquest",
-b"\x93\xE0\xB0\x3A" => "pol1_ctf",
-b"\xB6\x4E\xEB\x3F" => "pol1_Damage01",
-b"\x12\xEF\xF9\xCB" => "pol1_death",
-b"\x4A\xBE\xB6\x3B" => "pol1_pol1_rumble",
-b"\x54\xA1\x21\x5A" => "pol1_Roids01",
-b"\x84\xC2\xA3\x54" => "pol1_SoundEmitters",
-b"\x2C\x60\x5C\x75" => "pol1_SoundRegions",
-b"\xA8\x26\x98\x80" => "pol1_SoundSpaces",
-b"\xCF\x4D\x90\xFE" => "pol1_TDM",
-b"\x86\x55\x6D\xCE" => "pol1_Temp",
-b"\x29\x37\x50\xD9" => "pol1tran1",
-b"\x29\x2A\xFA\x2A" => "polmon01",
-b"\x70\x25\xFA\x27" => "polmon02",
-b"\x88\xC3\x1F\x35" => "PoolErrors",
-b"\x8B\x5D\x0C\x15" => "Portal",
-b"\x0A\x4E\x4F\x93" => "Position",
-b"\xF5\xA9\x8C\x43" => "PositionLarge",
-b"\xFA\x1F\xF4\xF9" => "PositionRelative",
-b"\x25\x76\xE1\x69" => "PositionRelativeLarge",
-b"\x89\xAF\xBF\x2E" => "PositionRelativeSmall",
-b"\xC1\x0E\xEC\x5D" => "PositionRelativeSpawn",
-b"\xC0\x63\xF3\x9F" => "PositionScale",
-b"\x59\x43\x6C\x70" => "PositionSmall",
-b"\x91\x76\xDA\x5C" => "PositionSpawn",
-b"\x76\x7D\xD8\x57" => "PositionX",
-b"\x09\x7F\xD8\x58" => "PositionY",
-b"\x50\x7A\xD8\x55" => "PositionZ",
-b"\x96\xA6\x20\x4F" => "PostFlashColor",
-b"\xB5\x9E\x41\xC1" => "PostFlashRadius",
-b"\x2D\x78\x4F\xA3" => "PostFlashRate",
-b"\x02\xD0\x9F\xE9" => "PostLarge",
-b"\xEF\xEC\x18\x5F" => "PostNumbers",
-b"\xA2\xEE\x13\xE8" => "PostSelectLarge",
-b"\x36\x7E\xDC\x7D" => "PostSelectSmall",
-b"\x22\x64\x9F\x77" => "PostSelectSpawn",
-b"\x96\x41\xA1\x63" => "PostSmall",
-b"\x82\x90\xDE\x5F" => "PostSpawn",
-b"\x4F\x01\x25\x4D" => "PostTextLarge",
-b"\x17\x7E\xE9\xEE" => "PostTextSmall",
-b"\xEF\xB6\xCD\x7C" => "PostTextSpawn",
-b"\xCB\x86\xF6\xF0" => "Posture",
-b"\xBA\xCA\x9A\x29" => "PowerupDelay",
-b"\x1C\xAD\x8A\x77" => "PowerUpItem",
-b"\xEA\x34\xA0\xA2" => "PowerupSound",
-b"\x71\x77\x09\xA3" => "PowerUpStation",
-b"\x12\xCA\xEB\xE0" => "ppass",
-b"\xA1\x55\x5D\xFA" => "PPitchRate",
-b"\x0D\x36\x1E\x37" => "pregametime",
-b"\xAE\x71\x09\x13" => "PreparingForDamageSound",
-b"\xB4\xF9\xFC\x01" => "Press",
-b"\x15\xF2\x36\x5B" => "PrevLinkedTerminal",
-b"\xD1\x1C\x0F\xBF" => "PrimaryStance",
-b"\x15\x70\x69\xDC" => "PrimaryWeapon",
-b"\x7B\x71\x3F\xF8" => "PrimaryWeaponIndex",
-b"\x09\xE3\xE4\x94" => "priority",
-b"\x58\xEF\xE6\x86" => "ProceduralBarBitmap",
-b"\xEE\xCA\x74\x46" => "profile",
-b"\x37\x1E\x3F\x53" => "ProgressBarLEDColor",
-b"\xFB\xA5\x6C\x83" => "ProgressBarLEDHeight",
-b"\xED\x07\x4A\x90" => "ProgressBarLEDMinIntensity",
-b"\x61\x94\x64\x4D" => "ProgressBarLEDSpacing",
-b"\x7B\x22\x25\x09" => "ProgressBarLEDTexture",
-b"\x0C\x85\x3B\xC5" => "ProgressBarLEDWidth",
-b"\xC3\xCF\x4C\xEE" => "ProgressBarNumLEDs",
-b"\x76\xBC\xA6\x31" => "ProgressBarTotalTime",
-b"\xFE\xDB\xE6\x99" => "ProjectedTexture",
-b"\x25\x9A\xD9\x6D" => "Prone",
-b"\xBD\xA8\x9D\x7D" => "ProneAimSpread",
-b"\x73\x8C\x60\x73" => "ProneMoveSpread",
-b"\x74\x9D\xDB\xCA" => "ProneSound",
-b"\xCA\xA3\x29\x4A" => "ProneStillSpread",
-b"\x92\xDA\x16\x00" => "ProneZoom",
-b"\xF8\x11\x3E\xD9" => "prop",
-b"\x78\x76\x72\x82" => "prop_corridorsmoke",
-b"\x32\xA0\x07\x37" => "prop_fan",
-b"\x0E\x14\x83\xD0" => "prop_steamgrate",
-b"\x3F\xB6\xE4\x6E" => "prop_steampipe",
-b"\x62\x12\x0F\xC4" => "prop_torturedroid",
-b"\xB0\x02\xB6\x09" => "PropagateAlpha",
-b"\xFE\x22\xC3\x30" => "PropClusterDensity",
-b"\x49\xDD\xBF\xB1" => "PropClusterEnable",
-b"\xC9\x0C\xB2\x91" => "PropClusterFadeAdj",
-b"\xDC\x6C\xDE\x11" => "Properties",
-b"\xC8\x02\xB3\xD6" => "Property",
-b"\x2C\x53\x94\xA7" => "props_template",
-b"\xD4\x26\x42\xAC" => "ProximityMaxDist",
-b"\xCA\x6D\xA0\xF3" => "ProximityMinDist",
-b"\x26\x65\xE2\x84" => "PS2BlendMode",
-b"\x2C\x6D\xB8\x55" => "PS2Overbright",
-b"\x62\x50\x85\x00" => "PuffInfo",
-b"\x7E\x10\x2D\x81" => "Pull",
-b"\x9E\xFE\x0B\x55" => "Pulse",
-b"\xDD\xFF\x6F\x87" => "Push",
-b"\xC1\xBE\xD8\x26" => "PushDeadOnly",
-b"\x24\xDA\xA7\x58" => "PushDeduction",
-b"\xBB\xE2\xB5\x19" => "PushRadius",
-b"\xC5\x1E\x30\x96" => "PushRadiusInner",
-b"\xBE\x2E\x26\x71" => "PushRadiusOuter",
-b"\x62\xC6\xB8\x3C" => "QuadScreen",
-b"\x4D\x11\x0F\x91" => "quakecam",
-b"\x15\x47\x0C\xF7" => "R",
-b"\xB3\x4C\xBA\x0D" => "Radius",
-b"\x23\x45\x1A\x9C" => "RadiusDamageSizeScale",
-b"\x61\xD6\x74\x6E" => "RadiusFadeMax",
-b"\x6F\x7A\x88\x80" => "RadiusFadeMin",
-b"\xCD\x64\x73\x3F" => "RadiusForMaxRays",
-b"\x27\x11\x3C\xCA" => "RainInfo",
-b"\xA8\x4F\xD0\x97" => "RandomBackdrop",
-b"\xBC\x43\x18\x33" => "Randomize",
-b"\x63\x54\xBF\x69" => "RandomSeed",
-b"\xD2\x0C\xDC\xFA" => "Range",
-b"\x7F\x09\xFF\x3D" => "Ray",
-b"\xFD\x3E\xC5\xA4" => "RayRadius",
-b"\xE6\x93\x6A\xDC" => "RayTriggerMinSpeed",
-b"\xAB\x50\xE9\x42" => "RayTriggerWidth",
-b"\x78\x50\xFD\x4C" => "Reach",
-b"\xEB\xD1\xDA\x1C" => "ReallyFar",
-b"\x18\xB7\xDA\xE5" => "ReallyNear",
-b"\x38\xE3\x8D\x67" => "Rebound",
-b"\x52\x1F\x7F\xB9" => "RecoilDecayHeavy",
-b"\x09\x3C\xB9\xA9" => "RecoilDecayLight",
-b"\xAD\x81\xC5\xB7" => "RecoilDelayHeavy",
-b"\xEA\x1C\xD2\x7C" => "RecoilDelayLight",
-b"\x6A\xA0\x92\x4B" => "RecoilLengthHeavy",
-b"\xD1\x74\x35\x90" => "RecoilLengthLight",
-b"\x01\x9F\xBA\x12" => "RecoilStrengthHeavy",
-b"\xE6\x49\x36\xCF" => "RecoilStrengthLight",
-b"\xCC\x58\x30\x59" => "record",
-b"\xA3\x14\x18\xE9" => "recorddir",
-b"\xC0\x8D\x20\x50" => "RecoverFromTumble",
-b"\x07\x4D\xE4\xEA" => "Rect",
-b"\xDC\x80\xF4\x40" => "Red",
-b"\xEC\x59\x9D\xDD" => "red_blaster_bolt",
-b"\x80\x7D\xFD\x5B" => "red_blaster_bolt_small",
-b"\x3D\x37\xB8\x23" => "ReferenceSpeed",
-b"\x62\x6C\xAF\xC7" => "RefillFromItem",
-b"\xAA\x78\xC7\x92" => "Reflect",
-b"\x3B\xFA\x00\xCB" => "Reflected",
-b"\x8B\xDE\x81\x8A" => "ReflectionColor",
-b"\x8B\xC2\xE9\x91" => "ReflectionFogColor",
-b"\xBD\xA3\xA5\x5A" => "RefractionColor",
-b"\x7D\xFE\x1A\x03" => "Region",
-b"\x9B\x57\x1A\x58" => "Reinforcements",
-b"\x1D\x91\x82\xFC" => "Relative",
-b"\x54\x07\xC1\x07" => "RelativeSize",
-b"\xB8\xE5\x41\x09" => "RelativeWidthHeight",
-b"\x7E\xAE\x36\x10" => "Release",
-b"\x8C\xDD\x7C\xED" => "Reload",
-b"\xF3\x19\x61\xEF" => "ReloadSound",
-b"\xAD\xA1\xC1\x9F" => "ReloadTime",
-b"\x53\x79\x8D\xC7" => "Remote",
-b"\x9E\xB8\x0D\xD5" => "RemoteGameObject",
-b"\x4F\x74\x08\x89" => "REMOTETERMINAL",
-b"\x37\x11\x07\x58" => "RemoveFromMapWhenDead",
-b"\x7F\x3B\xC7\x9E" => "RemoveSample",
-b"\x60\x61\xF4\x2C" => "rep",
-b"\xB7\xE1\x5C\x43" => "rep_halo",
-b"\x32\x48\x94\xBA" => "rep_hero_aalya",
-b"\xFE\x80\x28\x98" => "rep_hero_ep3obiwan",
-b"\x43\x5A\xDE\xEE" => "rep_hero_kiyadi",
-b"\x99\x60\xD8\x84" => "rep_hero_macewindu",
-b"\x29\xE0\xF6\x30" => "rep_hero_yoda",
-b"\xF9\xCD\x91\x96" => "rep_sfx_jetpack",
-b"\xB4\x0B\x52\x29" => "Repair",
-b"\x2A\xA8\x9B\xD9" => "Repeat",
-b"\x23\xD1\x47\x93" => "RepMusic",
-b"\xE1\x6F\x83\xC7" => "republic",
-b"\xB1\x3C\xF7\xAD" => "ReserveOneForPlayer",
-b"\x79\x2B\x4C\x2E" => "ResetTeam",
-b"\x8F\x5C\x21\x1D" => "Resolution",
-b"\x4E\xB4\x94\xBD" => "RespawnTime",
-b"\xEC\x11\x9C\xFE" => "Restart",
-b"\xCA\x54\x3A\xA2" => "RestartAnimation",
-b"\x96\xF7\xEB\xB6" => "RestDir",
-b"\x9E\xD9\x8A\x57" => "ReticuleInAimingOnly",
-b"\x93\x2F\x7E\x61" => "ReticuleTexture",
-b"\x5B\x9B\xDA\x0D" => "ReturnDuration",
-b"\xD8\x83\xF6\xB2" => "ReverbDelay",
-b"\xFE\x8C\x40\x6C" => "ReverbGain",
-b"\x6B\x4E\x16\x3F" => "ReverseBackwardSteering",
-b"\xC0\x9E\x06\x1F" => "ReverseSpeed",
-b"\x91\xAD\xE2\x1A" => "RhenVar2",
-b"\xBE\x5D\x30\xB4" => "RhenVar2_SoundRegions",
-b"\xF6\xA9\xFC\x6B" => "rhenvar2_soundspaces",
-b"\xE5\x1C\x9F\x6E" => "rhn_proprocks",
-b"\xD8\x19\xD1\x15" => "rhn_proprocks_02",
-b"\xE5\x2D\xE3\x78" => "right",
-b"\x62\xF4\xA1\x58" => "RightFootstepSound",
-b"\x1E\x3D\xFF\x16" => "roll",
-b"\xE1\xC2\xE8\x1C" => "RollIn",
-b"\xFE\x63\xA1\x80" => "RollInDistance",
-b"\xBB\xD4\x59\xAB" => "RollOff",
-b"\x5C\xF2\xEA\x24" => "RollRate",
-b"\x4C\x1C\x15\x61" => "RollRateAccel",
-b"\x9D\x14\x11\xD2" => "RollSound",
-b"\xDA\x90\xFF\x07" => "RollSpeedFactor",
-b"\xF7\xA0\x17\xC8" => "RollTime",
-b"\x7D\xAB\x76\x56" => "RoomGain",
-b"\x93\x5A\x69\x89" => "RoomHFGain",
-b"\xDA\x1E\x57\xB3" => "RoomRollOff",
-b"\x0D\x62\xF9\xDF" => "RoomRollOffFactor",
-b"\x45\x0E\xFD\x20" => "root",
-b"\x81\xFA\xE3\xC1" => "RootIsSlot",
-b"\x5F\x41\xAC\x21" => "Rotation",
-b"\x74\x7B\x61\xF2" => "RotationRange",
-b"\x65\x97\x98\xE6" => "RotationRate",
-b"\xBA\x8F\x04\x32" => "RotationSpeed",
-b"\x38\x51\x4B\x16" => "RotationVelocity",
-b"\x9A\x78\x91\xC0" => "Rotator",
-b"\x3A\x23\x16\x26" => "RoundDelay",
-b"\xC9\x9E\xFF\x7B" => "RoundsPerClip",
-b"\xD6\xD1\x8A\x16" => "RoundsPerSalvo",
-b"\x9D\xA5\xFC\xCB" => "RumbleEffect",
-b"\x4D\x74\xC8\x44" => "RunSpeed",
-b"\x2B\x54\x51\x04" => "safemode",
-b"\x63\xEA\x60\x92" => "SalvoCount",
-b"\xDB\x37\x6F\xD7" => "SalvoDelay",
-b"\x1D\x63\x25\x42" => "SalvoTime",
-b"\xA7\x82\xE3\x96" => "Sample",
-b"\xAF\xAC\x43\x41" => "SampleList",
-b"\x89\xE2\xA2\xF5" => "Saturation",
-b"\x88\xBD\xB1\x65" => "sb1mon01",
-b"\x41\xC2\xB1\x68" => "sb1mon02",
-b"\xD5\x73\x95\x83" => "sb1tran1",
-b"\x7F\xEB\xC7\xE9" => "sb2mon01",
-b"\x12\xED\xC7\xEA" => "sb2mon02",
-b"\xCE\xD9\xF9\x36" => "sb2tran1",
-b"\x4A\x30\xEF\x1A" => "sb3mon01",
-b"\xB7\x2E\xEF\x19" => "sb3mon02",
-b"\xC7\xDC\xB7\xE8" => "sb3tran1",
-b"\x41\x13\x52\xB8" => "sb4mon01",
-b"\x88\x0E\x52\xB5" => "sb4mon02",
-b"\xD8\x93\x23\xA6" => "sb4tran1",
-b"\xE2\x85\xD3\x0D" => "Scalable",
-b"\x71\x1C\x97\x82" => "Scale",
-b"\xA8\x46\xB1\x6D" => "ScaleAll",
-b"\x00\xA0\xB1\xF5" => "ScaleSize",
-b"\x40\xF3\xB5\x79" => "ScaleTexture",
-b"\x88\xA9\xE5\x73" => "ScaleTime",
-b"\xBF\x50\x54\x74" => "ScaleUniform",
-b"\xFB\x09\x59\x46" => "ScanningRange",
-b"\x5E\x1A\xD8\xEB" => "ScatterDistance",
-b"\xAF\x1B\x04\x47" => "SCDriverGetInSound",
-b"\x94\x8D\xFE\x4E" => "SCDriverGetOutSound",
-b"\x17\x39\x1B\x49" => "SCFieldFollowSound",
-b"\x9F\xA1\xA1\xB2" => "SCFieldHoldSound",
-b"\x4F\xAA\xE7\xE0" => "SCFieldMoveOutSound",
-b"\x43\x59\xD4\xA3" => "SCGunnerAllClearSound",
-b"\x72\xB4\xF9\xD1" => "SCGunnerGetInSound",
-b"\x6F\x31\x7C\xC2" => "SCGunnerGetOutSound",
-b"\x5D\x3D\x1F\x98" => "SCGunnerSteadySound",
-b"\xAD\x07\x1E\x65" => "ScopeDisplayAmbient",
-b"\x9B\x34\x9F\x4D" => "ScopeDisplayZoomIn",
-b"\x48\x53\x4B\x41" => "ScopeDisplayZoomOut",
-b"\x7A\xA3\xE2\x32" => "ScopeTexture",
-b"\xF0\xF9\x9D\x05" => "ScoreForMedalsType",
-b"\x05\xAD\x22\xC3" => "Scout",
-b"\x5F\x06\x89\x6C" => "SCPassengerGetInSound",
-b"\xA4\xF2\x60\xF2" => "SCPassengerGetOutSound",
-b"\xCB\x85\x7C\x5B" => "SCPassengerMoveOutSound",
-b"\xC8\xF4\x42\x5D" => "SCPassengerStopSound",
-b"\x1C\x42\x7A\x69" => "screaming",
-b"\x6C\xE0\x71\x12" => "screen_template",
-b"\x31\xC4\x22\xE8" => "screenshot",
-b"\xE6\xAD\x89\xC9" => "SCResponseNosirSound",
-b"\xF2\xE0\x5B\x76" => "SCResponseYessirSound",
-b"\x7E\x23\xBB\xD8" => "ScrollRate",
-b"\x07\x61\xC9\xDF" => "ScrollSpeed",
-b"\xC1\x4F\x36\x6E" => "SecondaryOrdnance",
-b"\x3A\x25\xDB\xDD" => "SecondaryOrdnanceName",
-b"\x18\x62\xE8\x82" => "SecondaryOrdnancePeriod",
-b"\x59\x5A\xC0\x92" => "SecondaryStance",
-b"\xCD\x9A\xDC\x4A" => "SecondaryWeapon",
-b"\xEA\xCD\xB6\x66" => "Seconds",
-b"\x8F\x81\x91\xE6" => "Sector",
-b"\x1A\x3E\x0F\xB5" => "Sector1",
-b"\x87\x3C\x0F\xB4" => "Sector2",
-b"\xAC\xBC\x45\x50" => "Seed",
-b"\xE6\x42\xC3\xF3" => "Segment",
-b"\xF2\x25\x83\x0A" => "SegmentList",
-b"\x74\x17\x0A\x4E" => "selected",
-b"\x77\xA2\x5B\x64" => "self",
-b"\xAA\xA5\x5A\x4C" => "SelfDestructSoundPitch",
-b"\x90\xC2\x9F\x94" => "SelfText",
-b"\xF7\x4C\x5F\xC3" => "session",
-b"\x85\x84\x35\x4F" => "SetAltitude",
-b"\x61\xC5\x43\x3A" => "Shadow",
-b"\x35\xF5\xE7\x09" => "ShadowCaster",
-b"\x44\x31\x79\x6E" => "ShadowColor",
-b"\xF5\xB2\x8B\xBD" => "Shake",
-b"\xE5\x87\xB7\x25" => "ShakeLength",
-b"\x83\xB2\x34\xD6" => "ShakeRadius",
-b"\x0D\x49\x54\x49" => "ShakeRadiusInner",
-b"\xA6\x99\x53\x12" => "ShakeRadiusOuter",
-b"\xE1\xBD\xCC\x72" => "ShakeScale",
-b"\x01\xFC\xE1\x11" => "shell",
-b"\x77\x3A\xB3\x99" => "shell_keyboard_backspace",
-b"\xFF\x8E\xDD\xD7" => "shell_keyboard_key",
-b"\x16\x22\x94\x6A" => "shell_menu_accept",
-b"\x1C\x8C\x3A\xFF" => "shell_menu_cancel",
-b"\x90\x76\xAB\x25" => "shell_menu_enter",
-b"\x50\xE4\xBB\xA4" => "shell_menu_error",
-b"\xCE\x68\x1C\x0E" => "shell_menu_exit",
-b"\x23\x76\xD9\x12" => "shell_movies",
-b"\x99\x5C\x88\xF6" => "shell_music",
-b"\xC3\xF3\x4C\x4D" => "shell_select_change",
-b"\xA7\x42\x49\x68" => "shell_subtitles",
-b"\x67\xF4\xC7\x82" => "shellfx",
-b"\x40\x2B\xEB\xF4" => "shellmovies",
-b"\x68\x20\x0A\xB0" => "shellmusic",
-b"\xB0\x19\x75\x33" => "shield",
-b"\xA1\x24\xB9\xDD" => "ShieldEffect",
-b"\x13\x63\x72\x1D" => "ShieldOffset",
-b"\xA4\xC1\xF6\x5D" => "ShieldOffSound",
-b"\xB6\x01\x92\x5A" => "ShieldRadius",
-b"\x72\x5C\x54\xD0" => "ShieldScale",
-b"\xFF\x03\x55\xD8" => "ShieldSound",
-b"\xDD\xDA\x6A\x71" => "ShockFadeInTime",
-b"\x52\xA1\xF4\xEC" => "ShockFadeOutGain",
-b"\x00\x1D\xCD\x20" => "ShockFadeOutTime",
-b"\xC8\x84\x67\xF6" => "ShockSound",
-b"\x08\x69\x35\xD9" => "ShotDelay",
-b"\x0D\xB6\x3D\xAE" => "ShotElevate",
-b"\xC0\xAF\xDF\xA4" => "ShotPatternCount",
-b"\xA8\x0A\x12\xA4" => "ShotPatternPitchYaw",
-b"\x57\xB3\xD5\xD0" => "ShotPerSalvo",
-b"\x58\x85\xB3\x03" => "ShotsPerSalvo",
-b"\xDD\xCC\xEA\x42" => "ShowAllServers",
-b"\x06\x00\xAE\x4E" => "ShowAllSessions",
-b"\x4E\x59\xCD\xA5" => "showintro",
-b"\x55\xD5\x81\x04" => "showlegal",
-b"\xA6\x7E\x31\x99" => "sideselect",
-b"\x7F\x80\x66\x16" => "SIMPLE",
-b"\x8E\xAA\x11\x33" => "SingleDirection",
-b"\x5C\xD9\xA0\x23" => "Size",
-b"\xD2\x97\xF2\x55" => "SkeletonLowRes",
-b"\xCD\xF6\x12\x70" => "SkeletonName",
-b"\xD0\xC3\xAC\x4C" => "SkeletonRootScale",
-b"\xD4\x9C\x23\x26" => "SkeletonRootScaleLowRes",
-b"\x36\xD0\x15\xCA" => "skill",
-b"\x9A\xB3\x7B\xFF" => "SkinnyFactor",
-b"\xFD\xEB\x4A\x03" => "SkyDomeDarkenFactor",
-b"\xE6\xD3\xC0\x06" => "SkyInfo",
-b"\xF5\xC3\xE0\xB7" => "SkyObject",
-b"\xA1\xF7\x2C\xB9" => "small_muzzle_flash",
-b"\x6D\xA0\x1C\x6D" => "SmallBan",
-b"\xF1\xED\xDE\x7F" => "SmashParkedFlyers",
-b"\x54\x47\xAC\x09" => "smoke_billow",
-b"\xFB\x95\xF8\x6C" => "SmolderBone",
-b"\xD6\xDF\x76\x10" => "SmolderDamageRate",
-b"\x60\xB1\x2F\x59" => "SmolderEffect",
-b"\x71\xF4\x24\x68" => "SmolderEffectTimer",
-b"\xDC\x06\x85\x34" => "SmolderVanishDeath",
-b"\x72\x34\xA9\xD3" => "SndHeroDefeated",
-b"\xF7\x29\x9E\xEF" => "SndHeroKiller",
-b"\x20\xB2\xC2\x4A" => "SndHeroSelectable",
-b"\xEE\xE4\xDE\xE4" => "SndHeroSpawned",
-b"\xB2\xAE\x5A\xF1" => "SniperScope",
-b"\xDA\xBC\x58\x89" => "Softness",
-b"\x0B\x2E\xF8\xFE" => "SoftnessParam",
-b"\xB5\x1F\xCD\x9C" => "soldier",
-b"\x9F\x52\xDA\xC3" => "SoldierAmmo",
-b"\x0D\xE1\x0C\x5F" => "SoldierAnimation",
-b"\x68\x74\x25\x99" => "SoldierAnnoucement",
-b"\xDA\xFF\x6B\x82" => "SoldierAttachBone",
-b"\x56\xA0\x94\x39" => "SoldierBan",
-b"\x7F\xC0\xFD\x5D" => "SoldierCollision",
-b"\x83\x00\xD0\x07" => "SoldierCollisionPrim",
-b"\x45\x51\xDC\x0A" => "SoldierEnergy",
-b"\xDF\x41\x3B\xF3" => "SoldierHealth",
-b"\xE4\x6B\xB4\xF5" => "SoldierMusic",
-b"\x94\x95\x0D\x0E" => "Sound",
-b"\xCA\x27\x6C\xBB" => "SoundAmbienceStatic",
-b"\x0C\x9B\x9E\xEC" => "SoundAmbienceStreaming",
-b"\xA2\xF7\x6B\xE4" => "SoundCrack",
-b"\x8A\x30\xEB\xEC" => "soundfx",
-b"\x38\x21\x4A\xA5" => "soundlayered",
-b"\x8F\xF1\x5F\x7A" => "SoundName",
-b"\x8F\xDB\xDE\xE6" => "SoundOffset",
-b"\x55\xD5\xEB\x12" => "soundparameterized",
-b"\x01\x34\x3D\xA3" => "SoundPitchDev",
-b"\x19\xC5\x65\xEE" => "SoundProperties",
-b"\xE5\xB4\x50\xF3" => "SoundProperty",
-b"\x4C\xEF\x93\x2E" => "SoundStream",
-b"\x31\x8B\xA3\x4C" => "SoundStreamProperties",
-b"\xB8\x50\x2E\x88" => "SoundSubCrack",
-b"\xC8\x50\x66\xC1" => "soundwarning",
-b"\xD2\xF8\x67\xBA" => "SoundWhenMoving",
-b"\x1E\xA1\x3B\xD9" => "spa_cor",
-b"\x8E\x2D\x4B\xA6" => "spa_dag",
-b"\x9B\x80\x2D\x14" => "spa_end",
-b"\x61\x8C\x25\xC8" => "spa_fel",
-b"\x2F\x06\x08\x32" => "spa_geo",
-b"\x79\xDD\x05\xD7" => "spa_hot",
-b"\x45\x22\xA0\x61" => "spa_kam",
-b"\x9B\x3E\xA0\x73" => "spa_kas",
-b"\x25\xD2\x28\x90" => "spa_mus",
-b"\xF5\xCF\x0A\x9C" => "spa_myg",
-b"\xB3\x62\x85\x33" => "spa_nab",
-b"\xE9\x79\x46\x88" => "spa_pol",
-b"\x5A\x99\x77\x5B" => "spa_sfx_blender",
-b"\xA4\x36\xDC\x15" => "spa_sfx_blender_destruct1",
-b"\x5D\x3B\xDC\x18" => "spa_sfx_blender_destruct2",
-b"\xCA\x39\xDC\x17" => "spa_sfx_blender_destruct3",
-b"\xEB\x31\xDC\x12" => "spa_sfx_blender_destruct4",
-b"\x58\x30\xDC\x11" => "spa_sfx_blender_destruct5",
-b"\x11\x35\xDC\x14" => "spa_sfx_blender_destruct6",
-b"\x42\xC4\x50\x4B" => "spa_sfx_frigate_exp_1",
-b"\xAF\xC2\x50\x4A" => "spa_sfx_frigate_exp_2",
-b"\x1C\xC1\x50\x49" => "spa_sfx_frigate_exp_3",
-b"\x89\xBF\x50\x48" => "spa_sfx_frigate_exp_4",
-b"\xF6\xBD\x50\x47" => "spa_sfx_frigate_exp_5",
-b"\xAA\xB1\xDC\x71" => "spa_sfx_lightningballs",
-b"\x40\xCF\x36\xAC" => "spa_sfx_skydomeexplosions",
-b"\x84\xDD\x2F\xB1" => "spa_sfx_turret_exp",
-b"\xEC\xF4\x69\xF0" => "spa_spa",
-b"\x59\xD2\x73\x79" => "spa_steamgrate",
-b"\xFB\x44\x1D\xC8" => "spa_tat",
-b"\x7A\xAD\x44\x4B" => "spa_uta",
-b"\x6E\xA5\x01\x82" => "spa_yav",
-b"\xCC\xF9\x00\xD2" => "spa1",
-b"\x3C\x17\x64\x1E" => "spa1_Assault Paths",
-b"\xC5\x9C\x4C\xCE" => "spa1_asteroids",
-b"\x06\x8E\x6A\x77" => "spa1_bomber paths",
-b"\xA3\xF9\xC8\x8A" => "spa1_campaign_flyers",
-b"\x5B\x08\x25\x9F" => "spa1_campaign_turrets",
-b"\x0A\xB2\xED\x92" => "spa1_capitalships",
-b"\x28\x07\x14\x8F" => "spa1_CTF",
-b"\x0F\x5D\x7D\x35" => "spa1_ctf_paths",
-b"\x5F\x50\xE1\xEE" => "spa1_Flight Paths",
-b"\x98\x56\xB5\xEF" => "spa1_movies",
-b"\xE6\xC1\x77\xEF" => "spa1_mp_flyers",
-b"\xD0\x99\x6B\xC7" => "spa1_mp_turrets",
-b"\x4D\xBB\x67\xF0" => "spa1_rumbleregions",
-b"\x68\x5B\xB4\xAE" => "spa1_sfx_all_rebelcruiserengine_exp",
-b"\x8E\x91\x19\x16" => "spa1_sfx_asteroid_shatter",
-b"\x64\x02\x51\x31" => "spa1_sfx_engine_exp_destruct2",
-b"\xD9\xDA\x9C\x4C" => "spa1_sfx_junkengine_exp",
-b"\x28\x29\x7B\x78" => "spa1_sfx_lasertrail",
-b"\x85\x5D\xD5\x15" => "spa1_sfx_shipflames_lg",
-b"\xA0\xF7\x46\x23" => "spa1_sfx_weap_laser_exp_lg",
-b"\x5A\x1D\xAE\x3E" => "spa1_Shadows",
-b"\xC5\xC5\x24\x5C" => "spa1_soundregions",
-b"\x2F\xFD\xCB\x91" => "spa1_soundspaces",
-b"\x89\x15\x03\x3D" => "spa1_soundtriggerregions",
-b"\x64\x75\xE6\xEF" => "spa1_swingassault",
-b"\xF7\x28\xBD\x28" => "spa1_transports",
-b"\x78\xC0\xD5\x62" => "spa1_turrets",
-b"\x85\xFE\x00\xD5" => "spa2",
-b"\x44\x83\xE5\xE9" => "spa2_Campaign",
-b"\x9D\xD7\x44\xA8" => "spa2_capitalships",
-b"\x7D\x00\xDB\xD6" => "spa2_cispaths",
-b"\xB0\xC3\x89\x26" => "spa2_design",
-b"\x04\x03\xB3\xED" => "spa2_fighters",
-b"\x00\xE9\x86\x0E" => "spa2_flightschool",
-b"\xF7\xFE\x74\xE7" => "spa2_movies",
-b"\x93\xAB\x52\x55" => "spa2_reppaths",
-b"\xE8\x5A\x97\xDC" => "spa2_rumbles",
-b"\xDE\x09\x63\xBE" => "spa2_sfx_cis_fedcruiser_descruct1_2_exp",
-b"\xB6\x62\x6C\xA2" => "spa2_sfx_cis_fedcruiser_descruct1a",
-b"\x23\x61\x6C\xA1" => "spa2_sfx_cis_fedcruiser_descruct1b",
-b"\x90\x5F\x6C\xA0" => "spa2_sfx_cis_fedcruiser_descruct1c",
-b"\x95\x6A\x6C\xA7" => "spa2_sfx_cis_fedcruiser_descruct1d",
-b"\x85\x7B\x6E\x8A" => "spa2_sfx_cis_fedcruiser_descruct2a",
-b"\xCC\x76\x6E\x87" => "spa2_sfx_cis_fedcruiser_descruct2b",
-b"\x5F\x78\x6E\x88" => "spa2_sfx_cis_fedcruiser_descruct2c",
-b"\xA6\x73\x6E\x85" => "spa2_sfx_cis_fedcruiser_descruct2d",
-b"\xFB\x08\x08\x60" => "spa2_sfx_prop_antenna_descruct_exp",
-b"\xCD\x29\x06\x11" => "spa2_sfx_prop_antenna_descruct1a",
-b"\x14\x25\x06\x0E" => "spa2_sfx_prop_antenna_descruct1b",
-b"\xA7\x26\x06\x0F" => "spa2_sfx_prop_antenna_descruct1c",
-b"\xEE\x21\x06\x0C" => "spa2_sfx_prop_antenna_descruct1d",
-b"\x67\x15\x10\x06" => "spa2_Shadows",
-b"\x72\x77\xD2\xDD" => "spa2_soundregions",
-b"\x5D\xC2\x84\xBC" => "spa2_soundspace",
-b"\x21\x38\xB3\x17" => "spa2_turrets",
-b"\xF2\xFC\x00\xD4" => "SPA3",
-b"\xFA\xBA\xCD\xE1" => "spa3_2_flag_ctf",
-b"\x4E\xD1\xF3\xBC" => "spa3_Assaultpaths",
-b"\x29\x18\x22\xCC" => "spa3_c_gameplay",
-b"\xFF\xE8\xF4\xF3" => "spa3_C_objects",
-b"\xC0\xE8\x7B\x3A" => "spa3_c_ships",
-b"\x48\x05\x11\xF4" => "spa3_c_turrets",
-b"\xF8\x22\x32\x09" => "spa3_cisfrigate",
-b"\x27\x29\x04\xDB" => "spa3_flightpaths",
-b"\xE7\x0E\x02\x3E" => "spa3_hangarwars",
-b"\x02\xEA\xFE\x46" => "spa3_movies",
-b"\xE8\xA7\x16\x09" => "spa3_obj_turrets",
-b"\x0F\xB0\x80\x44" => "spa3_rumbles",
-b"\x04\x62\x37\xCC" => "spa3_shadows",
-b"\x3D\x65\x49\x4D" => "spa3_SoundEmitters",
-b"\x4B\xD1\x8D\x04" => "spa3_soundregions",
-b"\x31\x75\xD7\xB1" => "spa3_soundspaces",
-b"\x64\xC6\x08\x09" => "spa3_swing turrets",
-b"\x7D\x0B\x96\xAF" => "spa3_swing_camp",
-b"\xE5\x90\x0E\x2C" => "spa3_swing_obj",
-b"\x13\xF5\x00\xCF" => "spa4",
-b"\xE2\xAD\x5B\x97" => "spa4_asteroids",
-b"\xD1\xBD\x80\xE9" => "spa4_c_cis-ships",
-b"\xCE\x8A\xBC\x3E" => "spa4_c_gameplay",
-b"\xD5\x65\x9C\xDC" => "spa4_c_ships",
-b"\xA2\x9E\x18\x96" => "spa4_extra",
-b"\xFE\x2E\x8D\xAF" => "spa4_flightpaths",
-b"\x69\x04\x8D\x46" => "spa4_movies",
-b"\x0E\x3B\xCF\xC6" => "spa4_rumbles",
-b"\x69\x05\x4F\x07" => "spa4_shadows",
-b"\xE0\x2C\xC5\x7C" => "spa4_soundregions",
-b"\xCC\x63\x29\xF1" => "spa4_soundspaces",
-b"\x80\xF3\x00\xCE" => "spa5",
-b"\x23\xBB\xE6\x2F" => "spa5_C_Gameplay",
-b"\xF2\x42\xDC\x43" => "spa5_c_ships",
-b"\x2D\x24\x09\xEC" => "spa5_flightpaths",
-b"\x84\x12\xD2\x1F" => "spa5_turrets",
-b"\x39\xF8\x00\xD1" => "SPA6",
-b"\xC7\x78\xBA\x90" => "spa6_1flag_flags",
-b"\x50\x18\x78\x59" => "spa6_1flag_paths",
-b"\xE1\x42\x8D\xB4" => "spa6_capitalships",
-b"\x32\xCE\x0A\x91" => "spa6_cmn_hangar",
-b"\x13\x67\x2D\x78" => "spa6_cmn_paths",
-b"\x5D\x3B\x04\x41" => "spa6_ctf_flags",
-b"\x29\xBE\x0B\xF9" => "spa6_ctf_spawn",
-b"\x57\x38\xAE\x6B" => "spa6_frigates",
-b"\x3B\x1F\x83\x42" => "spa6_obj_hangar",
-b"\x6C\xF2\x83\x16" => "spa6_obj_paths",
-b"\x1F\x32\x99\xB0" => "spa6_obj_spawn",
-b"\x3B\x06\x34\x03" => "spa6_shadows",
-b"\x76\xE9\x3E\x6E" => "spa6_soundregions",
-b"\x2E\xE2\xA6\x89" => "spa6_soundspaces",
-b"\xA6\xF6\x00\xD0" => "spa7",
-b"\x64\x4C\xFE\x3D" => "spa7_1flag_flags",
-b"\x77\x9F\x56\x08" => "spa7_1flag_paths",
-b"\x4B\x52\xDD\x11" => "spa7_asteroids",
-b"\x90\xFE\x6C\x12" => "spa7_capitalships",
-b"\x8F\x17\xFF\x2B" => "spa7_cmn_hangar",
-b"\x90\x33\xE1\x13" => "spa7_cmn_paths",
-b"\xC2\xFE\x42\xB8" => "spa7_ctf_flags",
-b"\xCE\x82\x0B\x45" => "spa7_ctf_spawn",
-b"\x9A\x61\xB9\x6C" => "spa7_frigates",
-b"\xA6\x13\x63\xCE" => "spa7_obj_hangar",
-b"\xF7\xBE\x54\x1E" => "spa7_obj_paths",
-b"\x70\xA7\xEA\x3A" => "spa7_obj_spawn",
-b"\xC8\x0C\xDE\xE4" => "spa7_Shadows",
-b"\x1F\x9E\x39\x28" => "spa7_SoundRegions",
-b"\xC4\xDB\x1B\xB6" => "spa7_SoundSpace",
-b"\xF7\x07\x01\xDB" => "spa8",
-b"\xE9\xF9\x8D\x77" => "spa8_1flag_flags",
-b"\x42\x5B\xE1\x5E" => "spa8_1flag_paths",
-b"\xB1\x08\xEF\xD6" => "spa8_ass_hangar",
-b"\x7E\xAA\xDF\x98" => "spa8_ass_paths",
-b"\x11\xD7\x64\x40" => "spa8_ass_spawn",
-b"\x43\x91\x10\x08" => "spa8_capitalships",
-b"\xF0\xA2\x16\x1D" => "spa8_cmn_hangar",
-b"\x85\xDA\x3D\x55" => "spa8_cmn_paths",
-b"\xCF\x18\x69\x5F" => "spa8_ctf_flags",
-b"\x33\x6B\x8E\xAD" => "spa8_ctf_spawn",
-b"\xA5\x01\xC6\x01" => "spa8_frigates",
-b"\x1D\xE9\xD3\xF4" => "spa8_Shadows",
-b"\xC4\x25\x8A\xFA" => "spa8_SoundRegions",
-b"\x93\x93\x5A\x43" => "spa8_soundspace",
-b"\x64\x06\x01\xDA" => "spa9",
-b"\xDE\x7C\x48\x60" => "spa9_1flag_flags",
-b"\xB9\x2D\x5F\x02" => "spa9_1flag_paths",
-b"\xC0\x37\x27\x81" => "spa9_ass_hangar",
-b"\x75\xCE\xD2\x1C" => "spa9_ass_paths",
-b"\xA6\xD5\xE7\xB7" => "spa9_ass_spawn",
-b"\x0D\x82\xA0\x8C" => "spa9_asteroids",
-b"\xC2\x85\x6F\xDE" => "spa9_capitalships",
-b"\xBD\x6F\xB2\x1E" => "spa9_cmn_hangar",
-b"\xEA\xBB\x29\x11" => "spa9_cmn_paths",
-b"\x44\x96\x6F\x47" => "spa9_ctf_flags",
-b"\xD0\x3A\xD8\x30" => "spa9_ctf_spawn",
-b"\x73\x9C\xFA\x42" => "spa9_eli_spawn",
-b"\xAF\x73\xE3\x22" => "spa9_Fighters",
-b"\x60\x7E\x86\x97" => "spa9_Frigates",
-b"\xC2\x5A\xC9\x54" => "spa9_shadows",
-b"\x4D\xB5\xAC\x70" => "spa9_soundregions",
-b"\x3E\x26\x02\x08" => "spa9_SoundSpace",
-b"\x85\xE2\x53\x35" => "Space",
-b"\xF5\xB6\xC0\xD5" => "spaceassaultscorelimit",
-b"\x6A\x21\x2F\x3F" => "Spark",
-b"\x4E\xAA\x0A\x53" => "SparkLength",
-b"\x98\x4D\x22\x3A" => "spawn",
-b"\xF7\x3F\x92\x88" => "SpawnCount",
-b"\xD7\xA8\xC9\xA4" => "SpawnDelay",
-b"\xD5\xA4\x76\x7B" => "SpawnDisplayBack",
-b"\xD5\xB9\x57\xAF" => "SpawnDisplaySpawnPointAccept",
-b"\xD1\x89\x10\x7A" => "SpawnDisplaySpawnPointChange",
-b"\x28\xF2\xF3\x29" => "SpawnDisplayUnitAccept",
-b"\x68\x2D\xBD\x80" => "SpawnDisplayUnitChange",
-b"\xB3\x0B\x98\x5C" => "SpawnDistance",
-b"\x6F\x72\x36\x49" => "Spawner",
-b"\x4F\xB1\xE0\xDD" => "SpawnPath",
-b"\x4D\x9F\x57\x32" => "SpawnPointCount",
-b"\x45\xD1\x67\xA0" => "SpawnPointLocation",
-b"\xBA\x0C\x19\xC5" => "SpawnPointNames",
-b"\x0C\xAD\x4A\x9B" => "SpawnRegion",
-b"\x57\x88\x36\xB4" => "SpawnSound",
-b"\x71\xB3\x99\x4E" => "SpawnTime",
-b"\x30\xD5\x40\xD9" => "Special",
-b"\x83\x76\x5F\xBA" => "SpeckleAmbientColor",
-b"\x57\x07\xE8\x6E" => "SpeckleCoordShift",
-b"\x68\x12\xB9\xE9" => "SpeckleScrollSpeed",
-b"\xB0\x74\xB2\xA6" => "SpeckleSpecularColor",
-b"\xF4\x5F\xA9\x2F" => "SpeckleTextures",
-b"\xC0\x9C\xA6\xDB" => "SpeckleTile",
-b"\x84\xB4\x46\xF9" => "SpecularCaster",
-b"\xF7\xAA\xE4\x01" => "SpecularColor",
-b"\x44\x6C\x97\xDD" => "SpecularMaskScrollSpeed",
-b"\x80\x11\x31\xC0" => "SpecularMaskTextures",
-b"\x2C\x34\x38\xE9" => "SpecularMaskTile",
-b"\x80\xC7\x80\x7B" => "Speed",
-b"\x06\x02\x85\xEE" => "SpeedForInvisibilityMax",
-b"\x44\x64\x71\xE0" => "SpeedForInvisibilityMin",
-b"\x20\x0A\x6E\x00" => "SpeedParticleMaxLength",
-b"\x2A\xA5\x6B\xE2" => "SpeedParticleMinLength",
-b"\x18\x85\xD9\xAF" => "Sphere",
-b"\x68\xA4\xBA\xB9" => "SpikeColor",
-b"\xAC\xEC\xAA\xAC" => "SpikeSize",
-b"\x4B\xE7\xD0\xDD" => "SpinRate",
-b"\x0F\xC6\xE8\xB3" => "SplatInfo",
-b"\x14\xA8\x34\x81" => "SplineType",
-b"\xE3\x2D\xB8\x87" => "split",
-b"\x03\x20\xF0\x77" => "split2",
-b"\x75\x29\xF0\x7D" => "split4",
-b"\xF9\x0C\x78\xCA" => "SPLITCROUCH",
-b"\xEE\x29\xE0\x37" => "SPLITCROUCHZOOM",
-b"\xEC\x7E\xE3\x70" => "SplitMode",
-b"\x37\x03\xE1\x23" => "SplitOptions",
-b"\xCB\x9C\xD3\xF6" => "SPLITPRONE",
-b"\x34\xFC\x5C\xFE" => "SPLITPRONEZOOM",
-b"\xDF\x4F\x92\xB0" => "splitscreen",
-b"\x57\xDB\xBA\x4A" => "SPLITSPRINT",
-b"\xCD\xCC\xAE\xD8" => "SPLITSTAND",
-b"\x7A\x51\x43\x16" => "SPLITSTANDZOOM",
-b"\x7D\xF6\x05\x23" => "Spot",
-b"\x6B\x09\x67\xC9" => "SpotInnerConeAngle",
-b"\x9E\x42\x2D\xB2" => "SpotOuterConeAngle",
-b"\x21\x41\xB6\x31" => "SpottedVO",
-b"\xCE\x00\x4A\x49" => "Spread",
-b"\xE9\x76\xE2\xDB" => "SpreadFactor",
-b"\xFD\xB6\xA6\x85" => "SpreadLimit",
-b"\x7D\x2C\xAB\x43" => "SpreadPerShot",
-b"\x9C\x37\xA0\x82" => "SpreadRadius",
-b"\x8A\x1A\x84\x33" => "SpreadRecover",
-b"\x38\xB6\xED\xCC" => "SpreadRecoverRate",
-b"\xB9\xB9\x13\x26" => "SpreadThreshold",
-b"\x55\x51\xF1\x76" => "sprint",
-b"\x79\x98\x38\x56" => "SprintAccelerateTime",
-b"\x61\x24\xD5\x81" => "SprintTimeForBowling",
-b"\xAD\x26\x05\xA8" => "SpritesInfo",
-b"\xAF\x9E\x98\x14" => "SquadronDistance",
-b"\x93\x8B\x10\xE5" => "SquadronFormation",
-b"\x61\xE6\x9C\x45" => "SquadronMaxAngle",
-b"\x6B\xEB\x9F\x95" => "SquadronMemberID",
-b"\xE1\xA4\xBF\x65" => "Squat",
-b"\x58\x03\x9B\xFC" => "SquatSound",
-b"\x23\x33\x49\x75" => "stand",
-b"\x06\xCD\x09\x25" => "stand_attack1a",
-b"\x88\xE4\x74\x1E" => "stand_attack1a_end",
-b"\x73\xCB\x09\x24" => "stand_attack1b",
-b"\x99\x90\x7D\xB9" => "stand_attack1b_end",
-b"\xE0\xC9\x09\x23" => "stand_attack1c",
-b"\x7F\x13\xC0\x5B" => "StandAimSpread",
-b"\x31\x15\x56\xF7" => "StandMoveSpread",
-b"\x62\x2D\x8B\x0E" => "StandSound",
-b"\x30\x1D\x08\x4C" => "StandStillSpread",
-b"\xEC\xCD\xD7\x94" => "STANDZOOM",
-b"\x71\x2F\x3B\xD1" => "star",
-b"\xA1\xFE\x26\x69" => "starblue",
-b"\x26\x00\x2B\x62" => "stars",
-b"\xD6\xF4\x67\x3E" => "StarsInfo",
-b"\x5E\xD9\x6C\x82" => "StartDelay",
-b"\x40\x94\x68\xDA" => "StarTexture",
-b"\x85\xC1\x2C\x1B" => "StartMovementSound",
-b"\x8A\xDE\x02\xF1" => "StartMoveX",
-b"\x1D\xE0\x02\xF2" => "StartMoveY",
-b"\x64\xDB\x02\xEF" => "StartMoveZ",
-b"\x81\xEF\x4C\x3D" => "StartRotation",
-b"\x2B\xDE\x0C\x0D" => "StartTimerOnContact",
-b"\xE5\x52\xB8\xE9" => "StartX",
-b"\x52\x51\xB8\xE8" => "StartY",
-b"\xBF\x4F\xB8\xE7" => "StartZ",
-b"\xF6\x32\x31\x78" => "State",
-b"\x3B\xC2\x90\xD2" => "STATIC",
-b"\x6E\xD4\x7C\x03" => "StatusTexture",
-b"\xF3\x4D\x80\xBA" => "SteerAtStrafeLimit",
-b"\x1D\xBB\x7F\xE5" => "SteerAtYawLimit",
-b"\x2D\x54\x58\x32" => "SteerTowardAimFactor",
-b"\x68\xC6\x28\xD1" => "SteerTowardAimRate",
-b"\xC2\x3C\x91\xD3" => "StickAll",
-b"\x73\x9B\xB4\xD1" => "StickAnimal",
-b"\xB3\xC0\x49\x74" => "StickBuilding",
-b"\xA5\x5F\x9C\xAD" => "StickBuildingDead",
-b"\xF4\xAF\xA2\xA8" => "StickBuildingUnbuilt",
-b"\xB7\x45\x3F\x3B" => "StickDroid",
-b"\xEA\xCA\xE9\x01" => "StickPerson",
-b"\x30\x6D\x70\x95" => "StickTerrain",
-b"\xED\xF9\x4A\x9B" => "StickVehicle",
-b"\x9A\x28\xFD\xE3" => "sticky",
-b"\xAE\x91\x5F\x49" => "Stiffness",
-b"\x67\x03\x65\x9B" => "StompDecal",
-b"\x7E\xED\x11\xE6" => "StompDecalSize",
-b"\xED\x08\x22\xB3" => "StompDetectionType",
-b"\x07\x5A\xFF\x07" => "StompEffect",
-b"\xDA\x89\xEB\x8A" => "StompRumbleHeavy",
-b"\xE6\xC4\x7D\x4E" => "StompRumbleHeavyDuration",
-b"\x21\x4B\x71\xBA" => "StompRumbleLight",
-b"\xF1\xC3\x99\x37" => "StompRumbleLightDuration",
-b"\xF5\xE1\x08\x4F" => "StompThreshold",
-b"\xB7\xE0\x78\x2F" => "StopMovementSound",
-b"\xC0\x02\x8F\x7C" => "StoppedTurnSpeed",
-b"\xE9\x95\xD8\xAD" => "StrafeFactor",
-b"\x6C\x89\xA4\xF1" => "StrafeRollAngle",
-b"\x11\xE5\x1C\xFE" => "StrafeSpeed",
-b"\x6B\xB3\xB0\xCC" => "Strategic_Filter1",
-b"\xFE\xB4\xB0\xCD" => "Strategic_Filter2",
-b"\x91\xB6\xB0\xCE" => "Strategic_Filter3",
-b"\x24\xB8\xB0\xCF" => "Strategic_Filter4",
-b"\xB7\xB9\xB0\xD0" => "Strategic_Filter5",
-b"\x4A\xBB\xB0\xD1" => "Strategic_Filter6",
-b"\xF3\x63\x6F\x59" => "Streak",
-b"\xDB\x1A\x6A\xB1" => "StreakLength",
-b"\x65\x6D\x6F\x5F" => "Stream",
-b"\x99\xCD\x11\x83" => "stretchconstraint",
-b"\xA3\xB6\x52\x0E" => "StrikeOrdnance",
-b"\xE8\x2B\xD9\x6F" => "StrikeOrdnanceName",
-b"\x3B\x22\x37\x07" => "StringAngle",
-b"\xD1\x39\x21\xF8" => "StringGrids",
-b"\x68\x6F\x3D\xC3" => "Strobe",
-b"\xDE\x52\x57\x13" => "StrobeFade",
-b"\xDF\x83\x6C\xF5" => "StrobeRandom",
-b"\x21\xDF\x6C\xDD" => "Stun",
-b"\xA9\x32\x27\x6F" => "StunAnimation",
-b"\x58\x1D\xAB\xA7" => "StunTime",
-b"\xB9\xFC\x5B\x11" => "Subtitle",
-b"\x05\x66\x83\x09" => "SubtitleList",
-b"\x44\x1F\x4A\x66" => "SunColor",
-b"\xF6\x7C\x90\xE1" => "SunDirection",
-b"\xB3\x57\xF3\xBD" => "SunFlare",
-b"\xF9\xC8\x80\x37" => "SunInfo",
-b"\xB6\xDE\xF3\x15" => "SunlightFadeFactor",
-b"\xE8\x18\x3E\x3B" => "Support",
-b"\x94\x48\x75\xCE" => "SuppressRadius",
-b"\x16\x41\xB2\xAB" => "SuspensionLeftArmNodeName",
-b"\x5D\x6C\x85\xF0" => "SuspensionMaxOffset",
-b"\x09\x7A\xDA\x8E" => "SuspensionMidOffset",
-b"\xCB\xC2\x34\xE5" => "SuspensionNodeName",
-b"\x9F\x45\x0B\x58" => "SuspensionRightArmNodeName",
-b"\x85\xED\xBC\xBE" => "SwingAmount",
-b"\xB4\xE6\x2B\x86" => "SwingSpeed",
-b"\xE0\x8A\x9C\x7C" => "SwingTime",
-b"\x4C\xCC\x97\xF4" => "SWIPE_BT",
-b"\x98\x74\xBA\x02" => "SWIPE_LR",
-b"\x79\x0C\x02\xCA" => "SWIPE_RAND",
-b"\x44\xB0\x70\xEC" => "SWIPE_RL",
-b"\x70\xDD\x7F\x02" => "SWIPE_TB",
-b"\x19\xB1\xC9\x4B" => "SwitchClassRadius",
-b"\xDD\x77\x3B\xC4" => "SwitchImmediately",
-b"\x3D\x6E\x27\x7E" => "Synchronize",
-b"\x06\xCD\x7D\x3A" => "SyncIfOvertime",
-b"\x5A\xA7\xA5\x8D" => "SyncType",
-b"\xF7\xDD\x8D\xB6" => "tactical_template",
-b"\x30\x21\x93\x81" => "TailPosition",
-b"\xFC\xFA\x07\x51" => "TakeoffHeight",
-b"\x32\xB2\x93\xC0" => "TakeoffSound",
-b"\x4E\x42\x55\x82" => "TakeoffSpeed",
-b"\x0A\x5C\x19\x61" => "TakeoffTime",
-b"\xD4\x9B\xCA\x24" => "tan_movies",
-b"\x19\x33\x15\x9B" => "tan_sfx_console_damage1",
-b"\x60\x2E\x15\x98" => "tan_sfx_console_damage2",
-b"\xF3\x2F\x15\x99" => "tan_sfx_console_damage3",
-b"\x0B\xE6\x27\xC2" => "tan1",
-b"\xE9\xEA\x8B\x5F" => "tan1_1flag",
-b"\x0A\x9F\x09\x01" => "tan1_conquest",
-b"\x07\xD6\xB3\x4E" => "tan1_CTF",
-b"\x15\x81\x12\x52" => "tan1_Design01",
-b"\x25\xB9\x7C\x73" => "tan1_NewCompProps",
-b"\x0D\x2A\x14\x77" => "tan1_NewObjective",
-b"\x22\x70\x43\x3C" => "tan1_Props",
-b"\x76\x84\xCD\x7A" => "tan1_reflections",
-b"\x41\x5F\xB8\x09" => "tan1_Rumble",
-b"\xFB\x0C\xAD\x73" => "tan1_sfx_turbine",
-b"\x54\x4E\x4B\x49" => "tan1_sfx_turbine_damage1",
-b"\x0D\x53\x4B\x4C" => "tan1_sfx_turbine_damage2",
-b"\x7A\x51\x4B\x4B" => "tan1_sfx_turbine_damage3",
-b"\xF1\x92\xBE\x03" => "tan1_shadows",
-b"\x30\xDD\x58\x1D" => "tan1_SoundEmitters",
-b"\x48\x10\x9B\xD2" => "tan1_SoundRegions",
-b"\x74\x59\x65\xB8" => "tan1_SoundSpaces",
-b"\xFD\x23\xB2\xB7" => "tan1tran1",
-b"\x4D\x20\x60\x21" => "tanmon01",
-b"\x94\x1B\x60\x1E" => "tanmon02",
-b"\xA2\x4A\xF7\xAA" => "Tap",
-b"\xDB\x28\x1F\x0C" => "TapHold",
-b"\xA1\x56\xD0\x46" => "TapPress",
-b"\x48\x88\x60\x32" => "Target",
-b"\xF8\xBA\x3D\xD2" => "TargetableCollision",
-b"\xE8\x3E\xE6\x74" => "TargetableCollisionPrim",
-b"\x6B\xC5\x36\x51" => "TargetAll",
-b"\x48\x87\xC5\x75" => "TargetAnimal",
-b"\xF0\x9F\x40\xC4" => "TargetBuilding",
-b"\x9E\xAE\x76\x3F" => "TargetBuildingDead",
-b"\xB9\xFA\xAE\xE4" => "TargetBuildingUnbuilt",
-b"\x1A\x7F\x00\x19" => "TargetDroid",
-b"\x24\x22\x0D\xE1" => "TargetEnemy",
-b"\x23\x4C\x5B\x8B" => "TargetFireColor",
-b"\x87\x58\x7F\xCA" => "TargetFriendly",
-b"\x12\x28\xFD\xEA" => "TargetHealthBelow25",
-b"\xF7\x9D\xF3\x6E" => "TargetLarge",
-b"\xDF\xEF\xE7\xE2" => "TargetLock",
-b"\xF0\xA0\xD5\x7F" => "TargetLockMaxDistance",
-b"\x09\x32\xD2\x5C" => "TargetLockMaxDistanceLose",
-b"\x13\x86\x2A\x8F" => "TargetMine",
-b"\x67\xE1\x0A\x5F" => "TargetNeutral",
-b"\x4D\x05\xE6\xFA" => "TargetPerson",
-b"\xC9\x9F\x01\xE3" => "TargetPointOffset",
-b"\x0F\xB7\x55\x03" => "TargetPointOffsetCrouch",
-b"\xF7\x5C\x73\x71" => "TargetPointOffsetStand",
-b"\xBF\x65\x12\x83" => "TargetSmall",
-b"\x77\xF2\x44\x89" => "TargetSpawn",
-b"\x38\x93\x1B\x28" => "TargetVehicle",
-b"\x6C\xA9\x40\x3B" => "tat2",
-b"\x4A\xC3\x67\x09" => "tat2_1flag",
-b"\x53\x8E\x40\x48" => "tat2_Collision",
-b"\x17\x1B\xBE\xBC" => "tat2_con",
-b"\xC8\x7A\xAB\xE2" => "tat2_ctf",
-b"\x23\x75\xEE\x16" => "tat2_Design",
-b"\x40\x53\x63\x2A" => "tat2_hunt",
-b"\xE5\x39\x94\x3C" => "tat2_soundregions",
-b"\x7C\x71\xF2\xEE" => "tat2_tdm",
-b"\x18\x64\x3F\x39" => "tat2_turrets",
-b"\xFF\xAA\x40\x3C" => "tat3",
-b"\x0D\xF2\x6A\xAD" => "tat3_1flag",
-b"\x04\x13\x22\xC3" => "tat3_con",
-b"\xA3\xE2\x0A\xDD" => "tat3_ctf",
-b"\x96\x9D\x5E\x1E" => "tat3_Design",
-b"\xBD\x79\x46\x29" => "tat3_hunt",
-b"\x95\x27\xE6\x9A" => "tat3_Shadows",
-b"\xB4\xF7\xD5\x30" => "tat3_SoundEmitters",
-b"\x3C\xFD\x09\x30" => "tat3_soundregions",
-b"\xB8\xD5\x97\xE3" => "tat3_SoundSpaces",
-b"\x5D\xC5\x5E\x7D" => "tat3_white_light01",
-b"\x0C\x7D\xFD\xA2" => "Team",
-b"\x2E\x44\x03\xBB" => "team_bonus_advanced_blasters",
-b"\x69\xCB\x68\xDE" => "team_bonus_autoturrets",
-b"\xDC\x9C\x0B\xC1" => "team_bonus_bacta_tanks",
-b"\xE1\x12\xB2\xDD" => "team_bonus_combat_shielding",
-b"\x6D\x82\xAD\x85" => "team_bonus_energy_boost",
-b"\x50\x69\xF3\x96" => "team_bonus_garrison",
-b"\x44\xE6\xA6\xD6" => "team_bonus_garrison_used_by",
-b"\xD4\xE9\xC1\xEA" => "team_bonus_leader",
-b"\x05\xE9\x65\xD2" => "team_bonus_sabotage",
-b"\x71\xCE\xE2\x56" => "team_bonus_sensor_array",
-b"\xE6\x56\x19\xC8" => "team_bonus_supplies",
-b"\xF9\xB5\xC2\xD6" => "TeamModel",
-b"\x06\x5A\x45\x26" => "TeamModelRotationSpeed",
-b"\x79\x86\x8D\x6B" => "TEMP_AnimationSpeed",
-b"\x80\xFD\xB6\x96" => "TEMP_Type",
-b"\xF5\x24\xAB\xF7" => "TentacleCollType",
-b"\x0E\x4C\xC0\x26" => "terrain_foley",
-b"\x4A\xBF\x10\x90" => "TerrainBumpSpecularTexture",
-b"\x51\x3A\xEB\xEE" => "TerrainBumpTexture",
-b"\xCE\x93\xE6\xAF" => "TerrainCollision",
-b"\x2A\xCF\xDC\x7C" => "TerrainCollisionPrim",
-b"\x22\x3A\xA9\xD8" => "TerrainColorDarkening",
-b"\xDB\x2A\x32\x21" => "TerrainLeft",
-b"\x24\xEB\x2D\xB6" => "TerrainRight",
-b"\x04\x0D\x00\x99" => "Tessellation",
-b"\xBC\x30\x23\x99" => "test1",
-b"\x46\x44\x7A\xC9" => "test1_Layer000",
-b"\xE0\xD2\xFA\x1F" => "TexCoords",
-b"\x3E\x4E\xE6\xBD" => "Text",
-b"\x23\x07\x20\xC2" => "TextAlignment",
-b"\x5B\xE3\xAC\x5B" => "TextBox",
-b"\x55\xF2\xD0\x37" => "TextBreak",
-b"\xAA\xBE\x0A\xF3" => "TextCharacterSpacing",
-b"\x88\xFF\x69\xB0" => "TextClip",
-b"\x7F\x14\xAE\x90" => "TextFont",
-b"\x77\x0A\x7A\xAF" => "TextHAlignment",
-b"\xDC\x15\x3C\x13" => "TextScale",
-b"\xB3\x6C\x74\x15" => "TextStyle",
-b"\xC6\x22\xE3\x4C" => "TextTabSpacing",
-b"\xF4\x68\x64\x3C" => "Texture",
-b"\x75\xAB\x3F\xEF" => "TextureJitterSpeed",
-b"\x6E\x43\x9B\x6D" => "TextureScale",
-b"\xAD\x78\x98\x30" => "TextureSize",
-b"\xFF\xD6\x45\x5A" => "TextureSpeed",
-b"\xAF\x61\x9F\x20" => "TextureVelocity",
-b"\x2D\xEA\x78\xFD" => "TextVAlignment",
-b"\x12\x8C\x72\x20" => "ThirdPersonFOV",
-b"\x96\x37\xEF\x0B" => "threats",
-b"\xF8\xF3\x39\x49" => "Threshold",
-b"\xDD\x32\x8F\x37" => "throttle",
-b"\xA7\xEC\xCB\x5D" => "ThrowAnimation",
-b"\x33\x4E\x62\xD0" => "Thrust",
-b"\xF2\xCD\xA3\x70" => "ThrustAngle",
-b"\x7F\x91\xD8\xBE" => "ThrustAngleToStop",
-b"\x5C\xDD\x47\xEA" => "ThrustAngleToStrafe",
-b"\xF4\xCA\xB0\xAF" => "ThrustAngleToTurn",
-b"\x69\x57\x77\x34" => "ThrustAttachOffset",
-b"\xF0\x57\x05\x2E" => "ThrustAttachPoint",
-b"\x56\xD7\x94\x08" => "ThrustEffect",
-b"\x4A\x78\xFD\xC3" => "ThrustEffectMaxScale",
-b"\xF8\xA3\x74\xF1" => "ThrustEffectMinScale",
-b"\xB8\x41\x70\xE9" => "ThrustEffectScaleStart",
-b"\xDC\x01\xC6\x0E" => "ThrustFactor",
-b"\xA6\x08\x5B\x4C" => "ThrustPitchAngle",
-b"\x9B\x0D\xFE\xAC" => "TickSound",
-b"\x67\x39\x95\x18" => "TickSoundPitch",
-b"\xC9\x7C\x3A\x71" => "Tile",
-b"\xF8\x0A\xB9\x5A" => "TileSize",
-b"\xA6\x0D\xE6\xBF" => "TileUV",
-b"\x27\x52\x9D\x35" => "TiltValue",
-b"\xE4\x9B\x3C\x5D" => "Time",
-b"\x41\x6C\xE6\x0E" => "TimeAtMaxCharge",
-b"\x34\x92\x0E\x26" => "TimeBetweenFlashesMinMax",
-b"\x32\x3C\x1D\xD7" => "TimeBetweenSubFlashesMinMax",
-b"\x99\x38\x69\x3E" => "TimeEnd",
-b"\x51\xCB\x04\x8F" => "TimeInPosture",
-b"\xB0\x95\x34\x41" => "TimePerFrame",
-b"\xD5\x97\x92\xBB" => "TimeRequiredToEject",
-b"\xE8\x98\x4B\xC4" => "TimeStart",
-b"\xE0\x67\x5E\x16" => "TimeTilReboard",
-b"\xC5\xFE\xA6\x8D" => "TimeToChange",
-b"\x87\xED\x30\x6C" => "TipsBoxTexture",
-b"\x5A\x1B\xEB\xD1" => "TipsColor",
-b"\x0F\x88\xAD\xC5" => "TipsPrefix",
-b"\x72\x33\xC1\x55" => "TipsTime",
-b"\x05\x62\x29\x40" => "Toggle",
-b"\x55\xD8\x7B\xDE" => "toggledisplay",
-b"\x3C\xDC\x10\xA7" => "Top",
-b"\xC3\xB8\x31\x69" => "TopColor",
-b"\x5F\xE7\x3E\xF0" => "TopDirectionalAmbientColor",
-b"\xE1\x46\xD2\x3D" => "TopRange",
-b"\xC4\x4B\x20\x26" => "torchflame",
-b"\xC4\x73\x5B\x8F" => "Torso",
-b"\x97\x9C\x79\x55" => "TotalFrames",
-b"\xD2\x71\xCA\xDB" => "towcable",
-b"\x22\xD7\x36\x24" => "TowCableCollision",
-b"\xB2\x2D\x22\xC5" => "towcableweapon",
-b"\xAE\x36\x22\xB2" => "tps",
-b"\x95\x58\x5D\xE8" => "TrackCenter",
-b"\xB2\xA3\x50\x05" => "TrackDeathOnAttach",
-b"\x43\x5A\x34\xE8" => "TrackingSound",
-b"\x07\x95\x3D\xFD" => "TrackOffset",
-b"\x3B\x2C\xC1\xD6" => "TrackUseRoot",
-b"\xF1\xD3\xE8\x4F" => "Traction",
-b"\xC6\xE2\x71\x7E" => "TrailEffect",
-b"\x37\x74\x9B\x7B" => "training",
-b"\x72\xA5\xB7\x9B" => "training1",
-b"\x1A\xC7\x81\x10" => "TrakCenter",
-b"\xF8\x3B\x77\xF1" => "Transformer",
-b"\x15\xC7\xC3\x67" => "TransformNameMesh",
-b"\x0D\x92\x34\x0D" => "TransformNumberColor",
-b"\x06\xF0\xF8\x2E" => "TransformNumberVector3",
-b"\xE2\x82\x27\xA6" => "Transition",
-b"\x25\x3B\x57\xB2" => "transition_template",
-b"\x83\xA4\x86\x26" => "TransitionTime",
-b"\x76\x0A\x46\xEB" => "TransmitRange",
-b"\x31\x47\xE3\xAF" => "transparent",
-b"\x19\x7A\x3F\x9C" => "TransparentType",
-b"\x12\x63\x2F\xD3" => "Transport",
-b"\x02\x93\x80\x60" => "trap",
-b"\xF7\x6F\xB0\x40" => "TreeLine",
-b"\xA7\x7A\xEB\x77" => "TrickFlipCameraDetach",
-b"\x9A\x01\xAB\xB9" => "TrickFlipSpeed",
-b"\x02\xD1\x9E\xA6" => "TrickRollSpeed",
-b"\xD0\x94\xAE\xC4" => "TrickSideRollCameraDetach",
-b"\x5E\xE6\xFB\x3D" => "TrickSideRollStrafeSpeed",
-b"\xFB\x2E\x6B\x7E" => "TrickSound",
-b"\x25\x5E\x60\xC4" => "TrickTimeMid",
-b"\x12\x3B\xC1\x2D" => "TrickTimeRange",
-b"\x76\x69\x1B\x1C" => "TriggerAll",
-b"\x5B\xD7\xA5\xF7" => "TriggerAnimation",
-b"\x85\xFE\x6F\x14" => "TriggerCollision",
-b"\x35\x2E\xB2\xBF" => "TriggerContact",
-b"\x13\x82\xFE\x58" => "TriggerNode",
-b"\x30\x52\x7C\xFE" => "TriggerOffset",
-b"\x11\xD8\x61\xC4" => "TriggerRadius",
-b"\xDB\x56\x9D\xED" => "TriggerSingle",
-b"\xC2\xF8\x6F\x83" => "TriggerSound",
-b"\x0A\x95\xCB\xAB" => "TriggerTeam",
-b"\x90\x5F\x62\xDF" => "Trooper",
-b"\x5A\x87\x36\xB3" => "ttachEffect",
-b"\xC2\x9B\x00\x4C" => "tumble",
-b"\x04\x57\x2C\x48" => "TurnBuildupMultiplier",
-b"\xAD\xB6\x07\x63" => "TurnFactor",
-b"\xB4\x1C\x72\xDE" => "TurnFilter",
-b"\x0D\x17\x95\x17" => "TurnFilterDecel",
-b"\xA2\x2F\x2C\x7D" => "TurningOffSound",
-b"\xB6\xC1\xA4\x66" => "TurnOffLightsaber",
-b"\xAE\xAB\x2D\x04" => "TurnOffSound",
-b"\xC6\xE3\xA9\xC8" => "TurnOffTime",
-b"\x27\xE5\xDD\xA7" => "TurnOnDuration",
-b"\x52\x3E\x60\xEA" => "TurnOnSound",
-b"\xAA\xB3\xAA\x08" => "TurnOnTime",
-b"\xE8\xC4\x77\x0F" => "TurnRate",
-b"\xD5\xA6\xBB\x46" => "TurnThreshold",
-b"\xB7\xA4\x2A\xFB" => "Turret",
-b"\x62\xBD\xF3\xED" => "turret_template",
-b"\xF2\xFE\x20\xEA" => "TURRET1",
-b"\x5F\xFD\x20\xE9" => "TURRET2",
-b"\xCC\xFB\x20\xE8" => "TURRET3",
-b"\x39\xFA\x20\xE7" => "TURRET4",
-b"\xA6\xF8\x20\xE6" => "TURRET5",
-b"\x13\xF7\x20\xE5" => "TURRET6",
-b"\x80\xF5\x20\xE4" => "TURRET7",
-b"\x1D\x0D\x21\xF3" => "TURRET8",
-b"\x69\xFB\xD0\xE9" => "TurretActivateSound",
-b"\xE8\xA6\x5D\x8E" => "TurretAmbientSound",
-b"\x04\xA2\x4A\xF8" => "TurretDeactivateSound",
-b"\x4D\x15\x39\x4C" => "TurretMoveSound",
-b"\xAA\x2D\x4F\x9E" => "TurretMoveSoundStartEndPitch",
-b"\x05\xE1\xDF\x5F" => "TurretMoveSoundStartEndTime",
-b"\xB4\x7C\x9A\xB6" => "TurretNodeName",
-b"\x20\x62\x46\x73" => "TurretPath",
-b"\x05\xDD\xEA\xF1" => "TurretPitchoundPitch",
-b"\xD2\x16\xF0\x28" => "TurretPitchSound",
-b"\xD0\xA4\xD7\x4E" => "TurretPitchSoundPitch",
-b"\xD2\x15\x79\xAD" => "TURRETSECTION",
-b"\x3C\xCB\x22\x88" => "TurretStartSound",
-b"\xB6\x68\x30\xC1" => "TurretStopSound",
-b"\x99\x3B\xA4\x1F" => "TurretYawSound",
-b"\x25\xF4\x03\x15" => "TurretYawSoundPitch",
-b"\xA4\xAA\x35\xA4" => "TwinkleFactor",
-b"\x23\xE4\xEE\xA9" => "TwinkleFrequency",
-b"\x4D\xF1\x27\x51" => "Type",
-b"\x2C\x68\xE5\x27" => "TypeCount",
-b"\xEE\xCD\x2E\x1C" => "ui_cancel",
-b"\x79\x25\xD5\xC0" => "ui_keyboardtype",
-b"\xE9\xC1\x2D\xB6" => "ui_load_lp",
-b"\x8C\x70\xAA\xEB" => "ui_menuBack",
-b"\xEA\x7D\x1A\xB2" => "ui_menuMove",
-b"\x3B\xF2\x02\xE5" => "ui_menuSelectionToggle",
-b"\xCD\x27\x5D\xB7" => "ui_planetzoom",
-b"\xC5\x31\xC9\x97" => "UnbuiltGeometryName",
-b"\x41\x20\x54\x48" => "UnbuiltHoloOdf",
-b"\xFF\x5F\x2C\x36" => "UnderwaterColor",
-b"\x04\xBC\xA8\x45" => "unitName",
-b"\xDF\xAE\xA7\x54" => "UnitType",
-b"\x8E\xCB\x06\x7B" => "unlimitedammo",
-b"\xD4\xAD\x09\x32" => "UnrollTime",
-b"\x4F\x21\xB5\xBA" => "Until",
-b"\x20\x0B\x43\x43" => "Up",
-b"\xE3\xF3\xF9\xF1" => "UprightLowResModel",
-b"\x83\x55\x1D\xF0" => "UprightWaterDamageHeight",
-b"\xEB\x04\x96\x84" => "UseChangeColor",
-b"\x0C\xC4\xED\x26" => "UseCollision",
-b"\x33\x05\x3E\x52" => "UseDirectionalDeaths",
-b"\xFD\xF6\xF4\x3A" => "UseDirectionalJumps",
-b"\x29\xA6\x23\x81" => "UseHSV",
-b"\x8F\xF8\x42\x4D" => "UseTexture",
-b"\x78\x14\x1D\xF3" => "UseVCollForFlyers",
-b"\x0A\xAD\xCC\xF8" => "uta_bldg_tower_damage1",
-b"\x77\xAB\xCC\xF7" => "uta_bldg_tower_damage2",
-b"\xE4\xA9\xCC\xF6" => "uta_bldg_tower_damage3",
-b"\x51\xA8\xCC\xF5" => "uta_bldg_tower_damage4",
-b"\xBE\xA6\xCC\xF4" => "uta_bldg_tower_damage5",
-b"\x2B\xA5\xCC\xF3" => "uta_bldg_tower_damage6",
-b"\x9D\x7D\x37\x5C" => "uta_movies",
-b"\x32\xE3\xBB\x0F" => "uta1",
-b"\xEC\x38\x5B\xA2" => "uta1_1flag",
-b"\x04\x72\x6E\xBA" => "uta1_bldg_lizardpen",
-b"\x29\xBC\x85\x07" => "uta1_Campaign",
-b"\xBD\xB1\x00\x86" => "uta1_Conquest",
-b"\x7A\x9F\xAF\xB4" => "uta1_CTF2flag",
-b"\x7C\x8E\xC9\x38" => "uta1_Droids",
-b"\x3D\x1A\x24\xCD" => "uta1_Light_RG",
-b"\xFB\xFE\x04\x9B" => "uta1_Pens",
-b"\xA2\xB7\x8C\x90" => "uta1_post_spot",
-b"\x8B\x67\xC3\x23" => "uta1_props",
-b"\x4F\xF9\xFD\x5F" => "uta1_Rumbles",
-b"\x66\xC0\xF1\xDE" => "uta1_sfx_bldg_tower_exp",
-b"\x8B\x76\xD1\x9D" => "uta1_SoundRegions",
-b"\x71\x0B\x1D\xA8" => "uta1_SoundSpaces",
-b"\x8C\x03\x9E\x3D" => "uta1_steamgrate",
-b"\xFE\xAB\x92\xD6" => "uta1_TDM",
-b"\x2A\x5F\xEA\x70" => "uta1_Vehicles",
-b"\x22\x0B\x77\x6D" => "uta1tran1",
-b"\xEC\x7C\x61\xE8" => "utamon01",
-b"\xA5\x81\x61\xEB" => "utamon02",
-b"\xE2\xCF\x9D\x5A" => "VAlign",
-b"\xCA\xD3\x5E\x42" => "Value",
-b"\xB5\xD6\x40\xA3" => "Value_ATK_Alliance",
-b"\x45\x32\xE7\x00" => "Value_ATK_CIS",
-b"\x70\x46\xB4\x4D" => "Value_ATK_Empire",
-b"\xA4\x98\x3C\x57" => "Value_ATK_Locals",
-b"\x10\xB4\x78\x9E" => "Value_ATK_Republic",
-b"\xA6\xC4\xA2\x44" => "Value_DEF_Alliance",
-b"\xE8\x5A\xF8\x5B" => "Value_DEF_CIS",
-b"\x57\x94\x67\xA8" => "Value_DEF_Empire",
-b"\x93\xEF\x89\xE6" => "Value_DEF_Locals",
-b"\xCB\x40\x55\x8F" => "Value_DEF_Republic",
-b"\xDA\x97\xBF\x4E" => "ValueBleed",
-b"\xD2\xC9\xE7\xFD" => "ValueBleed_Alliance",
-b"\x9C\xA8\x50\x24" => "ValueBleed_CIS",
-b"\xDB\xB7\x7E\x5A" => "ValueBleed_Empire",
-b"\x17\xC0\x7F\xD9" => "ValueBleed_Locals",
-b"\xFE\xC1\x13\xEB" => "ValueBleed_Neutral",
-b"\x3F\x2F\x39\xDC" => "ValueBleed_Republic",
-b"\xB2\x47\x86\x85" => "VarianceX",
-b"\x45\x49\x86\x86" => "VarianceY",
-b"\x8C\x44\x86\x83" => "VarianceZ",
-b"\x5E\x77\x77\x80" => "vBarrelNodeName",
-b"\x08\x86\x92\x36" => "vcenter",
-b"\xD8\x5A\xCE\x3D" => "veh_weapon_template",
-b"\x2F\x88\xCD\x71" => "Vehicle",
-b"\xDC\xB2\x50\x63" => "vehicle_eng_hi-mid_template",
-b"\xB0\x73\x46\x96" => "vehicle_eng_low_template",
-b"\x3A\x0B\x93\xBD" => "vehicle_foley_template",
-b"\x9A\xCF\xAC\x87" => "vehicle_template",
-b"\x6C\x80\xC6\x47" => "VehicleAmbientColor",
-b"\x55\xD9\x28\xE7" => "VehicleAmmo",
-b"\xA1\x65\x53\xDE" => "vehiclecollision",
-b"\xBB\x5C\xF2\xC3" => "vehiclecollisiononly",
-b"\xA9\xCE\xC8\xFE" => "VehicleCollisionPrim",
-b"\x18\x45\x82\x84" => "VehicleCollisionSound",
-b"\x9B\x8A\x23\x56" => "VehicleEnergy",
-b"\xC9\x81\xE3\x14" => "VehicleHealth",
-b"\xA0\x5E\xD7\x0B" => "vehiclepad",
-b"\x0C\x8C\x1B\xDA" => "VehiclePosition",
-b"\xEF\xC5\xCD\x6C" => "VehicleScale",
-b"\x3A\xB4\x89\x6A" => "VehicleSeating",
-b"\x61\x03\xE7\x1F" => "vehicleself",
-b"\xEA\xDC\xEB\x23" => "vehiclespawn",
-b"\x17\xB8\x33\x52" => "VehicleType",
-b"\x32\x1C\x74\x32" => "Velocity",
-b"\xA4\x52\xEC\xEC" => "VelocityChangeRate",
-b"\x24\x85\x1D\x66" => "VelocityDamp",
-b"\x3D\x04\x5C\x8A" => "VelocityFromStrafe",
-b"\x38\xCB\xA5\x1A" => "VelocityFromThrust",
-b"\x3F\x30\x69\x54" => "VelocityRange",
-b"\x68\x41\x3C\x98" => "VelocityScale",
-b"\x6F\x2C\x0D\xDF" => "VelocitySpring",
-b"\x7E\x88\xC8\xB6" => "VelocityX",
-b"\x4C\x1E\xAE\xC1" => "VelocityXZ",
-b"\xB7\x06\xA3\x82" => "VelocityXZAngle",
-b"\x11\x8A\xC8\xB7" => "VelocityY",
-b"\x1F\x9E\xE2\x2B" => "VelocityYAbs",
-b"\x58\x85\xC8\xB4" => "VelocityZ",
-b"\x97\xAE\x71\x46" => "Version",
-b"\x4C\x54\xD0\xB0" => "VerticalSpread",
-b"\xF4\xF6\x8A\xB2" => "ViewAngleFactor",
-b"\xC3\xBA\xAB\xE4" => "Viewport",
-b"\x5E\xC9\xBF\x0F" => "Viewport0Position",
-b"\xFF\xEF\xC3\xD7" => "Viewport0UseSafezone",
-b"\x61\x10\x0B\x87" => "Viewport1Position",
-b"\xE2\x76\x71\xA9" => "Viewport1UseSafezone",
-b"\x30\xE2\x23\xE1" => "Viewport2Position",
-b"\x7D\xBD\x23\x5D" => "Viewport2UseSafezone",
-b"\x73\x58\x5F\x2E" => "Viewport3Position",
-b"\x10\xB6\xAB\x04" => "Viewport3UseSafezone",
-b"\x10\xE3\x56\xAA" => "Viewports",
-b"\x64\x3D\x12\x74" => "vil",
-b"\xA8\x90\x00\x50" => "villain",
-b"\xC1\x08\xE4\xCB" => "villains",
-b"\x3D\x38\xBC\xCD" => "Vine",
-b"\x1D\xD4\xC0\xA8" => "VineLength",
-b"\xC6\x8D\x32\x45" => "VineSpread",
-b"\xBC\x7E\x96\x5D" => "Virtual",
-b"\x72\xEF\x4D\x2F" => "VisibilityRange",
-b"\x7F\x1B\xA8\x53" => "VisibleRadius",
-b"\xA0\x1E\xAD\x13" => "VO_All_AllCapture",
-b"\x53\x34\xBD\x22" => "VO_All_AllInDispute",
-b"\x0A\xCB\x31\xBA" => "VO_All_AllInfo",
-b"\x20\xF3\x71\x9E" => "VO_All_AllLost",
-b"\xA1\x3A\x4B\xFD" => "VO_All_AllSaved",
-b"\x17\x03\x34\x64" => "VO_All_ImpCapture",
-b"\xA4\xB5\x18\xE0" => "VO_All_ImpInDispute",
-b"\xCB\xEC\xC6\xF7" => "VO_All_ImpInfo",
-b"\x45\x46\xFC\xC0" => "VO_All_ImpLost",
-b"\xEA\xAB\xDD\x1A" => "VO_All_ImpSaved",
-b"\x98\x07\x27\x8F" => "VO_CIS_CISCapture",
-b"\xBB\x86\xA6\x5B" => "VO_CIS_CISInDispute",
-b"\x82\xCE\x08\xB7" => "VO_CIS_CISInfo",
-b"\xE8\x71\xD6\xD4" => "VO_CIS_CISLost",
-b"\x99\x65\xE4\x3E" => "VO_CIS_CISSaved",
-b"\x5E\x68\x34\x19" => "VO_CIS_RepCapture",
-b"\x41\xA5\x03\x0D" => "VO_CIS_RepInDispute",
-b"\x90\xFA\xA3\x66" => "VO_CIS_RepInfo",
-b"\xDA\x48\x66\x9F" => "VO_CIS_RepLost",
-b"\xF7\x5F\xB5\x6E" => "VO_CIS_RepSaved",
-b"\xCB\x7F\x4A\xD7" => "VO_Imp_AllCapture",
-b"\x18\x42\x83\x87" => "VO_Imp_AllInDispute",
-b"\xC7\xBE\xF2\x05" => "VO_Imp_AllInfo",
-b"\x41\xD1\x46\x24" => "VO_Imp_AllLost",
-b"\xDE\x1D\x12\x23" => "VO_Imp_AllSaved",
-b"\xB4\x90\x21\xBD" => "VO_Imp_ImpCapture",
-b"\xE7\x07\xD8\xDE" => "VO_Imp_ImpInDispute",
-b"\x76\x0A\x90\x6F" => "VO_Imp_ImpInfo",
-b"\x0C\x20\x16\x18" => "VO_Imp_ImpLost",
-b"\x25\x14\x87\xC2" => "VO_Imp_ImpSaved",
-b"\x8E\x95\x21\x7B" => "VO_Rep_CISCapture",
-b"\x71\xE8\x53\x86" => "VO_Rep_CISInDispute",
-b"\xA0\x25\x5E\x1F" => "VO_Rep_CISInfo",
-b"\x2A\x32\xBA\xB2" => "VO_Rep_CISLost",
-b"\x27\x50\x01\x6D" => "VO_Rep_CISSaved",
-b"\x40\x8F\x1D\x95" => "VO_Rep_RepCapture",
-b"\xF3\x8C\xD1\xF8" => "VO_Rep_RepInDispute",
-b"\x2A\xD0\x2A\xBE" => "VO_Rep_RepInfo",
-b"\x40\xCE\xE3\xA4" => "VO_Rep_RepLost",
-b"\x41\x7E\xD3\x1B" => "VO_Rep_RepSaved",
-b"\xF5\x9E\x1B\xC0" => "vo_template",
-b"\xF9\xC9\x9B\x7F" => "Voice",
-b"\x27\x8E\xC8\xA1" => "VoiceChat",
-b"\x56\xA8\xDF\xDA" => "voicemode",
-b"\x4B\xAA\x13\x3B" => "voiceover",
-b"\x20\x97\x23\xC9" => "VORadius",
-b"\x19\x0E\x76\xD8" => "Vortex",
-b"\xB9\x45\x26\x17" => "VOSound",
-b"\x3C\x49\x96\x61" => "VOUnitType",
-b"\x6E\x38\xBE\x88" => "WaitMax",
-b"\x7C\xDC\xD1\x9A" => "WaitMin",
-b"\x1C\xA2\xD7\x26" => "WakeEffect",
-b"\x52\xEE\x4F\xF4" => "WakeWaterSplashEffect",
-b"\x67\x2C\xFA\xAC" => "walker",
-b"\x57\x4F\xAF\x31" => "walkerdroid",
-b"\xDD\x4A\xEA\x2A" => "WalkerLegPair",
-b"\x05\x71\x58\x65" => "WalkerOrientRoll",
-b"\x62\xF7\xD9\x1C" => "WALKERSECTION",
-b"\x08\x82\x1D\x43" => "walkerstomp",
-b"\xC9\xB9\x9B\x0B" => "WalkerWidth",
-b"\x94\x3E\x33\xCA" => "WarmUpSound",
-b"\x3C\x15\xE5\x22" => "WarmUpSoundFadeInTime",
-b"\xBF\x0D\x82\x25" => "WarmUpSoundFadeOutTime",
-b"\xBE\x34\x20\x1A" => "WarmUpSoundStartPitch",
-b"\xE4\xEE\xED\x44" => "WarmUpTime",
-b"\x10\x9A\xC6\x49" => "water",
-b"\x24\xA1\x5A\x42" => "water_foley",
-b"\xAD\x99\x92\x82" => "WaterDamageAmount",
-b"\xCC\x1A\x49\x5B" => "WaterDamageInterval",
-b"\x01\x38\x73\x87" => "WaterEffect",
-b"\x6F\xF6\xC0\xF9" => "waterfall_bottom_center",
-b"\xD9\x83\x9D\x58" => "waterfall_bottom_side",
-b"\xAC\x64\xF3\x48" => "WaterInfo",
-b"\x1D\x13\x68\x3E" => "WaterRingColor",
-b"\x6E\x4B\xFB\x19" => "WaterSplashColor",
-b"\xFA\x4C\xA6\xDE" => "WaterSplashEffect",
-b"\x9F\x19\xE6\x6B" => "WaterWadingSound",
-b"\x57\xB5\x9C\x39" => "WaterWakeColor",
-b"\x40\x88\x4A\x6D" => "WaverRate",
-b"\xC1\x08\x9F\x12" => "WaverTurn",
-b"\x41\x20\x33\x6F" => "Weapon",
-b"\x7C\x50\xAD\xB1" => "weapon_template",
-b"\x13\xBD\x19\xDC" => "WeaponAmmo",
-b"\x86\xBC\x84\x9E" => "WeaponAmmo1",
-b"\xF3\xBA\x84\x9D" => "WeaponAmmo2",
-b"\x60\xB9\x84\x9C" => "WeaponAmmo3",
-b"\x65\xC4\x84\xA3" => "WeaponAmmo4",
-b"\xC1\x38\xDA\x1A" => "WeaponChange",
-b"\xB8\x4D\x2A\x35" => "WeaponChangeSound",
-b"\x28\x23\x47\x16" => "WeaponChannel",
-b"\x5B\x40\xFC\x2A" => "WeaponChannel1",
-b"\xEE\x41\xFC\x2B" => "WeaponChannel2",
-b"\x81\x43\xFC\x2C" => "WeaponChannel3",
-b"\x14\x45\xFC\x2D" => "WeaponChannel4",
-b"\xBA\x7D\xF4\xFB" => "WeaponName",
-b"\xD1\xA1\xE1\x2C" => "WeaponName1",
-b"\x18\x9D\xE1\x29" => "WeaponName2",
-b"\xAB\x9E\xE1\x2A" => "WeaponName3",
-b"\x8A\xA6\xE1\x2F" => "WeaponName4",
-b"\x80\x9E\x32\xD0" => "WeaponSection",
-b"\x8A\xBB\x85\x85" => "WeaponShareAmmo",
-b"\x61\x87\x86\xEC" => "WeaponShareAmmo1",
-b"\xA8\x82\x86\xE9" => "WeaponShareAmmo2",
-b"\x3B\x84\x86\xEA" => "WeaponShareAmmo3",
-b"\x1A\x8C\x86\xEF" => "WeaponShareAmmo4",
-b"\x46\xA4\x55\x94" => "WeaponShareHeat",
-b"\x55\xE7\xD1\xF9" => "WeaponShareHeat1",
-b"\x9C\xE2\xD1\xF6" => "WeaponShareHeat2",
-b"\x2F\xE4\xD1\xF7" => "WeaponShareHeat3",
-b"\x76\xDF\xD1\xF4" => "WeaponShareHeat4",
-b"\x62\x4E\x45\xD1" => "WeaponUnableSelect",
-b"\x3D\x1E\x99\xBA" => "WheelOmegaToU",
-b"\x84\x19\x99\xB7" => "WheelOmegaToV",
-b"\xDB\x93\x65\x1A" => "WheelSection",
-b"\x97\x16\x84\x80" => "WheelTexture",
-b"\x9F\x0D\x8C\x4B" => "WheelVelocToU",
-b"\x32\x0F\x8C\x4C" => "WheelVelocToV",
-b"\xF6\x47\xDC\xF5" => "Widescreen",
-b"\x1F\x6E\x87\x95" => "Width",
-b"\x6C\x79\xBB\x7C" => "WiggleAmount",
-b"\x5B\xD3\xCB\xD4" => "WiggleSpeed",
-b"\x59\xCB\xF7\x0B" => "win",
-b"\x30\x2B\x5E\x41" => "winddirection",
-b"\xF2\xA1\x64\xFC" => "WindDownSound",
-b"\x7E\x68\xA1\x96" => "WindSound",
-b"\x72\x1A\x90\x13" => "WindSpeed",
-b"\x59\x8D\x5B\x20" => "WindUpSound",
-b"\x37\x73\x43\x95" => "WingModel",
-b"\x94\x36\xA8\x31" => "wookie_movement_metal_template",
-b"\x24\x6A\xFE\x19" => "wookie_movement_template",
-b"\xFD\xE9\x98\x6A" => "word",
-b"\xC4\x2C\xDF\x30" => "word_reformat",
-b"\x79\xEF\xBB\x33" => "WorldAligned",
-b"\x98\x0D\xC7\x46" => "WorldBrightness",
-b"\xAD\x74\xFA\xB8" => "WorldContrast",
-b"\xF2\x06\xC7\xD6" => "WorldFogRange",
-b"\x7C\xCA\x99\xB0" => "WorldHeight",
-b"\xE8\x10\x08\x36" => "WorldRange",
-b"\x17\x57\x98\xCF" => "WorldSaturation",
-b"\xDB\x64\x48\x4B" => "WrapInput",
-b"\xE1\x33\xA6\x75" => "yav_movies",
-b"\xB8\xBD\xF8\x2C" => "yav_prop_bush",
-b"\x3E\x60\x92\x55" => "yav_prop_bush2",
-b"\xEC\x8B\x90\xD2" => "yav_prop_grass",
-b"\x7D\x49\x44\x00" => "yav_prop_shrub1",
-b"\x21\x04\x49\x52" => "yav_prop_treebillboard",
-b"\x96\x9B\x3F\xEB" => "yav1tran1",
-b"\xDB\x51\xC6\x4A" => "yavin1",
-b"\xEE\x3D\xA4\x45" => "yavin1_Campaign",
-b"\x17\x7E\x37\xA5" => "yavin1_CommonDesign",
-b"\x9A\x6E\x2D\xA7" => "yavin1_Conquest",
-b"\x17\x08\x7D\x50" => "yavin1_ctf",
-b"\x7B\x32\xF3\x59" => "yavin1_CTF_centerflag",
-b"\x60\x01\xE5\x41" => "yavin1_soundemitters",
-b"\xFE\x0D\x7A\x90" => "yavin1_soundregionenv",
-b"\x18\x8F\x3A\x10" => "yavin1_SoundRegions",
-b"\xC4\xCE\x63\x04" => "yavin1_SoundSpaces",
-b"\xCB\x08\x12\x1B" => "yavin1_tdm",
-b"\xFC\x3F\xED\x4B" => "yavin1_tree",
-b"\x80\xB6\x9A\x84" => "yavmon01",
-b"\x39\xBB\x9A\x87" => "yavmon02",
-b"\x78\x80\x3E\x2C" => "YawLimits",
-b"\xD7\xDC\xEF\xEB" => "YawSpread",
-b"\xC8\x87\xBB\x0E" => "YawTurnFactor",
-b"\xEF\x0A\x5D\x3D" => "YOffset",
-b"\x9B\x3B\x9D\xF7" => "ZeroGravity",
-b"\x1D\x7E\xEC\x14" => "ZoomFirstPerson",
-b"\xB4\xE8\x96\x2A" => "ZoomMax",
-b"\xA6\xB1\x81\x18" => "ZoomMin",
-b"\x20\xFB\x73\x95" => "ZoomRate",
-b"\x3D\x78\x37\xAD" => "ZoomTurnDivisorMax",
-b"\x43\x6D\x22\xB7" => "ZoomTurnDivisorMin",
-b"\x3F\x26\x9F\xEC" => "ZOrder",
-};
-
-pub(crate) const CLASSLABELS: &'static [&'static str] = &[
-    "animatedbuilding",
-    "animatedprop",
-    "armedbuilding",
-    "armedbuildingdynamic",
-    "beacon",
-    "beam",
-    "binoculars",
-    "bolt",
-    "building",
-    "bullet",
-    "cannon",
-    "catapult",
-    "cloudcluster",
-    "commandarmedanimatedbuilding",
-    "commandhover",
-    "commandpost",
-    "commandwalker",
-    "destruct",
-    "destructablebuilding",
-    "detonator",
-    "disguise",
-    "dispenser",
-    "droid",
-    "dusteffect",
-    "emitterordnance",
-    "explosion",
-    "fatray",
-    "flyer",
-    "godray",
-    "grapplinghook",
-    "grapplinghookweapon",
-    "grasspatch",
-    "grenade",
-    "haywire",
-    "hologram",
-    "hover",
-    "launcher",
-    "leafpatch",
-    "Light",
-    "melee",
-    "mine",
-    "missile",
-    "powerupitem",
-    "prop",
-    "remote",
-    "repair",
-    "rumbleeffect",
-    "shell",
-    "shield",
-    "soldier",
-    "SoundAmbienceStatic",
-    "SoundAmbienceStreaming",
-    "sticky",
-    "towcable",
-    "towcableweapon",
-    "trap",
-    "vehiclepad",
-    "vehiclespawn",
-    "walker",
-    "walkerdroid",
-    "water",
-    "weapon",
-];
+include!(concat!(env!("OUT_DIR"), "/constants.rs"));
@@ -1,12 +1,20 @@
-use std::{collections::HashMap, ffi::CStr};
+use std::{ffi::CStr, fmt::Display};
 
+use crate::fourcc::FourCC;
+use crate::hash::{HashDictionary, NameHash};
 use crate::prop::constants::*;
 use crate::ucfb::*;
 
 mod constants;
 
+/// Find the name with this hash in the list of known property names
+pub(crate) fn lookup_name(hash: u32) -> Option<&'static str> {
+    HASHVALUES.get(&hash).copied()
+}
+
 /// Possible property container types
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub enum PropertyContainerTypes {
     /// Game Object
     GameObjectClass,
@@ -18,13 +26,64 @@ pub enum PropertyContainerTypes {
     WeaponClass,
 }
 
+/// Name of a property
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PropertyKey {
+    /// The property name
+    Known(String),
+    /// Hash of a property name that isn't in the dictionary, kept so it can be written back or cracked later
+    Unknown(u32),
+}
+
+impl PropertyKey {
+    /// Get the property name, if it is known
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            PropertyKey::Known(v) => Some(v),
+            PropertyKey::Unknown(_) => None,
+        }
+    }
+    /// Get the hash the property is stored with
+    pub fn hash(&self) -> NameHash {
+        match self {
+            PropertyKey::Known(v) => NameHash::new(v),
+            PropertyKey::Unknown(v) => NameHash(*v),
+        }
+    }
+}
+
+impl From<&str> for PropertyKey {
+    fn from(value: &str) -> Self {
+        PropertyKey::Known(value.to_string())
+    }
+}
+
+impl Display for PropertyKey {
+    /// Known names are written as the name, unknown ones as the hash in hex such as `0x1234ABCD`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyKey::Known(v) => write!(f, "{}", v),
+            PropertyKey::Unknown(v) => write!(f, "{:#010X}", v),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl serde::Serialize for PropertyKey {
+    /// Serialize as the displayed name, such as `MaxHealth` or `0x1234ABCD`
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 /// Object that reperesents an in-game property container (odf)
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct PropertyContainer {
     /// Property Container/Class Type
     pub r#type: PropertyContainerTypes,
-    /// The properties
-    pub properties: HashMap<String, String>,
+    /// The properties in the order they are stored, keys such as `WeaponName` can appear more than once
+    pub properties: Vec<(PropertyKey, String)>,
     /// The class name
     pub name: String,
     /// One of these properties will be populated
@@ -44,24 +103,63 @@ pub enum PropertyError {
     NotAProperty,
     /// Property is corrupted
     CorruptedProperty,
+    /// odf file can't be read, with the line or part that is wrong
+    InvalidOdf(String),
+}
+
+impl std::fmt::Display for PropertyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PropertyError::ChunkParseError(_) => {
+                write!(f, "failed to read the chunks in the property container")
+            }
+            PropertyError::NotAProperty => {
+                write!(
+                    f,
+                    "chunk is not a property container (entc, expc, ordc or wpnc)"
+                )
+            }
+            PropertyError::CorruptedProperty => write!(f, "property container is corrupted"),
+            PropertyError::InvalidOdf(reason) => write!(f, "invalid odf: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PropertyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PropertyError::ChunkParseError(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 impl PropertyContainer {
     /// Deserialize class from chunk
     pub fn from_chunk(chunk: Chunk) -> Result<Self, PropertyError> {
-        let r#type: PropertyContainerTypes = match chunk.header.name.as_str() {
-            "entc" => PropertyContainerTypes::GameObjectClass,
-            "expc" => PropertyContainerTypes::ExplosionClass,
-            "ordc" => PropertyContainerTypes::OrdnanceClass,
-            "wpnc" => PropertyContainerTypes::WeaponClass,
+        Self::from_chunk_ref(&chunk.as_chunk_ref())
+    }
+    /// Deserialize class from a borrowed chunk
+    pub fn from_chunk_ref(chunk: &ChunkRef) -> Result<Self, PropertyError> {
+        Self::from_chunk_ref_with(chunk, &HashDictionary::new())
+    }
+    /// Deserialize class from a borrowed chunk, looking property names up in a dictionary
+    pub fn from_chunk_ref_with(
+        chunk: &ChunkRef,
+        dictionary: &HashDictionary,
+    ) -> Result<Self, PropertyError> {
+        let r#type: PropertyContainerTypes = match chunk.header.name {
+            FourCC::GAME_OBJECT_CLASS => PropertyContainerTypes::GameObjectClass,
+            FourCC::EXPLOSION_CLASS => PropertyContainerTypes::ExplosionClass,
+            FourCC::ORDNANCE_CLASS => PropertyContainerTypes::OrdnanceClass,
+            FourCC::WEAPON_CLASS => PropertyContainerTypes::WeaponClass,
             _ => return Err(PropertyError::NotAProperty),
         };
-        let subchunks = extract_chunks_bytearray(&mut chunk.data.clone())
-            .map_err(|e| PropertyError::ChunkParseError(e))?;
+        let subchunks = chunk.subchunks().map_err(PropertyError::ChunkParseError)?;
 
         // The chunk name doesn't really matter, as the chunks are always in a specific order
         // BASE chunk
-        let base_chunk = match subchunks.get(0) {
+        let base_chunk = match subchunks.first() {
             Some(v) => v,
             None => return Err(PropertyError::CorruptedProperty),
         };
@@ -72,55 +170,48 @@ impl PropertyContainer {
         };
 
         // The base class TODO: find if label or parent
-        let base_class = CStr::from_bytes_until_nul(&base_chunk.data)
+        let base_class = CStr::from_bytes_until_nul(base_chunk.data)
             .map_err(|_| PropertyError::CorruptedProperty)?
             .to_str()
             .map_err(|_| PropertyError::CorruptedProperty)?
             .to_string();
         // The name of the odf
-        let type_class = CStr::from_bytes_until_nul(&type_chunk.data)
+        let type_class = CStr::from_bytes_until_nul(type_chunk.data)
             .map_err(|_| PropertyError::CorruptedProperty)?
             .to_str()
             .map_err(|_| PropertyError::CorruptedProperty)?
             .to_string();
 
-        let mut prop_index = 2;
-        let mut prop_subchunk = subchunks
-            .get(prop_index)
-            .ok_or(PropertyError::CorruptedProperty)?;
-        let mut properties: HashMap<String, String> = HashMap::new();
-        while prop_subchunk.header.name == "PROP" {
-            let (hash_index, value) = (
+        let mut properties: Vec<(PropertyKey, String)> = vec![];
+        // Classes without properties end after their name, like the ones `to_chunk` writes
+        for prop_subchunk in subchunks
+            .iter()
+            .skip(2)
+            .take_while(|c| c.header.name == FourCC::PROPERTY)
+        {
+            let (hash, value) = (
                 prop_subchunk
-                    .data
-                    .get(0..4)
-                    .ok_or(PropertyError::CorruptedProperty)?
-                    .try_into()
-                    .map_err(|_| PropertyError::CorruptedProperty)?,
+                    .header
+                    .endianness
+                    .read_u32_from(prop_subchunk.data)
+                    .ok_or(PropertyError::CorruptedProperty)?,
                 String::from_utf8(
                     prop_subchunk
                         .data
                         .get(4..)
                         .ok_or(PropertyError::CorruptedProperty)?
-                        .try_into()
-                        .map_err(|_| PropertyError::CorruptedProperty)?,
+                        .to_vec(),
                 )
                 .map_err(|_| PropertyError::CorruptedProperty)?
                 .replace("\0", ""),
             );
-            properties.insert(
-                match HASHVALUES.get(hash_index) {
-                    Some(v) => v.to_string(),
-                    None => return Err(PropertyError::CorruptedProperty),
+            properties.push((
+                match dictionary.get(NameHash(hash)) {
+                    Some(v) => PropertyKey::Known(v.to_string()),
+                    None => PropertyKey::Unknown(hash),
                 },
                 value, //.as_str()
-            );
-            prop_index += 1;
-
-            prop_subchunk = match subchunks.get(prop_index) {
-                Some(v) => v,
-                None => break,
-            }
+            ));
         }
 
         let lab = if CLASSLABELS.contains(&base_class.as_str()) {
@@ -135,8 +226,8 @@ impl PropertyContainer {
         };
 
         Ok(PropertyContainer {
-            r#type: r#type,
-            properties: properties,
+            r#type,
+            properties,
             name: type_class,
             class_label: lab,
             class_parent: parent,
@@ -144,41 +235,142 @@ impl PropertyContainer {
     }
 
     /// Get the ODF text representation of this object
+    ///
+    /// GeometryName is written in the class section wherever it is stored, so classes storing it after other
+    /// properties come back from `from_odf` in a different order
     pub fn get_odf(&self) -> String {
-        let mut result: String = format!(
-            "[{:?}]\n\n{}\n",
-            self.r#type,
-            match self.class_label.clone() {
-                Some(v) => format!("ClassLabel = {}", v),
-                // TODO: better error handling
-                None => format!("ClassParent = {}", self.class_parent.clone().unwrap()),
+        let mut result: String = format!("[{:?}]\n\n", self.r#type);
+        match (&self.class_label, &self.class_parent) {
+            (Some(v), _) => result = format!("{}ClassLabel = {}\n", result, v),
+            (None, Some(v)) => result = format!("{}ClassParent = {}\n", result, v),
+            (None, None) => {}
+        }
+
+        let odf_value = |v: &str| {
+            if v.parse::<u64>().is_ok() {
+                v.to_string()
+            } else {
+                format!("\"{}\"", v)
             }
-        );
+        };
 
-        self.properties.get("GeometryName").map(|v| {
-            result = format!("{}\nGeometryName = {}\n", result, v);
-        });
+        // GeometryName belongs in the class section
+        let geometry = self
+            .properties
+            .iter()
+            .position(|(k, _)| k.name() == Some("GeometryName"));
+        if let Some(i) = geometry {
+            result = format!(
+                "{}\nGeometryName = {}\n",
+                result,
+                odf_value(&self.properties[i].1)
+            );
+        }
 
         result = format!("{}\n[Properties]\n\n", result);
 
-        for (k, v) in self.properties.clone() {
-            match k.as_str() {
-                "GeometryName" => continue,
-                _ => {
-                    result = format!(
-                        "{}\n{} = {}\n",
-                        result,
-                        k,
-                        if v.parse::<u64>().is_ok() {
-                            v
-                        } else {
-                            format!("\"{}\"", v)
-                        }
-                    );
-                }
+        for (i, (k, v)) in self.properties.iter().enumerate() {
+            if Some(i) != geometry {
+                result = format!("{}\n{} = {}\n", result, k, odf_value(v));
             }
         }
 
         result
     }
+    /// Get the value of a property, the first one if the key appears more than once
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let key = PropertyKey::from(name);
+        self.properties
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+    }
+    /// Read an odf file written by `get_odf` or by hand, the class name isn't stored in it so it is passed in
+    ///
+    /// Keys written as a hash, such as `0x1234ABCD = 5`, are read as properties whose name isn't known
+    pub fn from_odf(name: &str, odf: &str) -> Result<Self, PropertyError> {
+        let mut r#type: Option<PropertyContainerTypes> = None;
+        let mut class_label: Option<String> = None;
+        let mut class_parent: Option<String> = None;
+        let mut properties: Vec<(PropertyKey, String)> = vec![];
+        for line in odf.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+            if let Some(section) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                r#type = match section {
+                    "GameObjectClass" => Some(PropertyContainerTypes::GameObjectClass),
+                    "ExplosionClass" => Some(PropertyContainerTypes::ExplosionClass),
+                    "OrdnanceClass" => Some(PropertyContainerTypes::OrdnanceClass),
+                    "WeaponClass" => Some(PropertyContainerTypes::WeaponClass),
+                    "Properties" => continue,
+                    _ => return Err(PropertyError::InvalidOdf(line.to_string())),
+                };
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(PropertyError::InvalidOdf(line.to_string()))?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value)
+                .to_string();
+            match key.trim() {
+                "ClassLabel" => class_label = Some(value),
+                "ClassParent" => class_parent = Some(value),
+                key => {
+                    let hash = key
+                        .strip_prefix("0x")
+                        .and_then(|k| u32::from_str_radix(k, 16).ok());
+                    let key = match hash {
+                        Some(v) => PropertyKey::Unknown(v),
+                        None => PropertyKey::from(key),
+                    };
+                    properties.push((key, value));
+                }
+            }
+        }
+        if class_label.is_none() && class_parent.is_none() {
+            return Err(PropertyError::InvalidOdf(
+                "missing ClassLabel or ClassParent".to_string(),
+            ));
+        }
+        Ok(PropertyContainer {
+            r#type: r#type.ok_or(PropertyError::InvalidOdf("missing class type".to_string()))?,
+            properties,
+            name: name.to_string(),
+            class_label,
+            class_parent,
+        })
+    }
+
+    /// Serialize the class to a chunk, the properties are written in the order they are stored
+    pub fn to_chunk(&self) -> Result<Chunk, PropertyError> {
+        let name = match self.r#type {
+            PropertyContainerTypes::GameObjectClass => FourCC::GAME_OBJECT_CLASS,
+            PropertyContainerTypes::ExplosionClass => FourCC::EXPLOSION_CLASS,
+            PropertyContainerTypes::OrdnanceClass => FourCC::ORDNANCE_CLASS,
+            PropertyContainerTypes::WeaponClass => FourCC::WEAPON_CLASS,
+        };
+        let base_class = match (&self.class_label, &self.class_parent) {
+            (Some(v), _) | (None, Some(v)) => v,
+            (None, None) => return Err(PropertyError::CorruptedProperty),
+        };
+        let mut subchunks = vec![
+            Chunk::new(FourCC::BASE, format!("{}\0", base_class).into_bytes()),
+            Chunk::new(FourCC::TYPE, format!("{}\0", self.name).into_bytes()),
+        ];
+        for (key, value) in &self.properties {
+            let mut data = key.hash().to_le_bytes().to_vec();
+            data.extend_from_slice(value.as_bytes());
+            data.push(0);
+            subchunks.push(Chunk::new(FourCC::PROPERTY, data));
+        }
+        Ok(Chunk::new(
+            name,
+            chunks_to_bytes(&subchunks).map_err(PropertyError::ChunkParseError)?,
+        ))
+    }
 }
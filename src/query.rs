@@ -0,0 +1,344 @@
+use std::collections::{HashMap, HashSet};
+use std::ffi::CStr;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::fourcc::FourCC;
+use crate::ucfb::*;
+
+/// A path-like selector over a chunk tree, such as `ucfb/lvl_/tex_` or `**/entc[TYPE=rep_inf_ep3_rifleman]`
+///
+/// Steps are separated by `/` and are either a chunk name, `*` for any chunk or `**` for any number of
+/// levels. A name can be followed by predicates:
+/// - `[N]` matches the `N`th sibling with the same name, like in `ChunkLocation::path`
+/// - `[KEY=VALUE]` matches chunks whose `KEY` subchunk holds the string `VALUE`,
+///   or decoded property containers whose `KEY` property is `VALUE`
+/// - `[name=VALUE]` also matches decoded levels by name in any case, or by hash written as hex
+///
+/// The first step matches the root of the file, so chunk paths from `ChunkLocation` are valid queries
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    /// Matches zero or more levels of chunks (`**`)
+    Descendants,
+    /// Matches chunks with the name (any name if `None`) that satisfy all predicates
+    Child {
+        name: Option<FourCC>,
+        predicates: Vec<Predicate>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Predicate {
+    /// Index among siblings with the same name
+    Index(usize),
+    /// Subchunk or property has the value
+    Equals(String, String),
+}
+
+/// Errors returned while parsing a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryError {
+    /// Query has no steps
+    EmptyQuery,
+    /// Step isn't a chunk name, `*` or `**`
+    InvalidStep(String),
+    /// Predicate isn't an index or `KEY=VALUE`
+    InvalidPredicate(String),
+}
+
+impl Display for QueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QueryError::EmptyQuery => write!(f, "query is empty"),
+            QueryError::InvalidStep(s) => write!(f, "invalid query step {}", s),
+            QueryError::InvalidPredicate(s) => write!(f, "invalid query predicate [{}]", s),
+        }
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// A chunk that can be searched with a `Query`
+pub trait QueryNode: Sized {
+    /// Chunk header
+    fn header(&self) -> &ChunkHeader;
+    /// The chunks stored in this chunk
+    fn children(&self) -> Vec<Self>;
+    /// Whether the chunk holds every `(KEY, VALUE)` of the `[KEY=VALUE]` predicates of a step
+    ///
+    /// All the predicates of a step are checked in one call so the chunk is only read once
+    fn has_fields(&self, fields: &[(&str, &str)]) -> bool;
+    /// Identifies the chunk, nodes referring to the same chunk have the same id
+    fn id(&self) -> usize;
+}
+
+/// Read the null terminated strings stored in the subchunks named by the keys, reading the subchunks once
+fn subchunk_strings(chunk: &ChunkRef, fields: &[(&str, &str)]) -> Vec<Option<String>> {
+    let subchunks = if chunk.header.is_known_container() {
+        chunk.subchunks().unwrap_or_default()
+    } else {
+        vec![]
+    };
+    fields
+        .iter()
+        .map(|(key, _)| {
+            let name: FourCC = key.parse().ok()?;
+            let subchunk = subchunks.iter().find(|c| c.header.name == name)?;
+            Some(match CStr::from_bytes_until_nul(subchunk.data) {
+                Ok(v) => v.to_string_lossy().into_owned(),
+                Err(_) => String::from_utf8_lossy(subchunk.data).into_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Whether the deciphered form of a chunk has the field, for keys that aren't subchunks
+///
+/// Levels are matched by name hash like `Level::has_name`, so case doesn't matter and unknown names match
+fn deciphered_has_field(chunk: &Chunk, key: &str, value: &str) -> bool {
+    match &chunk.deciphered_chunk {
+        Some(DecipheredChunk::PropertyContainer(v)) => v.get(key) == Some(value),
+        Some(DecipheredChunk::Level(v)) if key == "name" => v.has_name(value),
+        _ => false,
+    }
+}
+
+impl<'a> QueryNode for ChunkRef<'a> {
+    fn header(&self) -> &ChunkHeader {
+        &self.header
+    }
+    /// Subchunks of chunks that are known to be containers, malformed chunks have none
+    fn children(&self) -> Vec<Self> {
+        if self.header.is_known_container() {
+            self.subchunks().unwrap_or_default()
+        } else {
+            vec![]
+        }
+    }
+    fn has_fields(&self, fields: &[(&str, &str)]) -> bool {
+        subchunk_strings(self, fields)
+            .iter()
+            .zip(fields)
+            .all(|(s, (_, value))| s.as_deref() == Some(*value))
+    }
+    /// Address of the chunk data, chunks in the same buffer never start at the same address
+    fn id(&self) -> usize {
+        self.data.as_ptr() as usize
+    }
+}
+
+impl QueryNode for &Chunk {
+    fn header(&self) -> &ChunkHeader {
+        &self.header
+    }
+    /// Subchunks of visited ucfb and level chunks
+    fn children(&self) -> Vec<Self> {
+        self.deciphered_chunk
+            .as_ref()
+            .and_then(DecipheredChunk::subchunks)
+            .map(|c| c.iter().collect())
+            .unwrap_or_default()
+    }
+    fn has_fields(&self, fields: &[(&str, &str)]) -> bool {
+        subchunk_strings(&self.as_chunk_ref(), fields)
+            .into_iter()
+            .zip(fields)
+            .all(|(s, (key, value))| match s {
+                Some(s) => s == *value,
+                None => deciphered_has_field(self, key, value),
+            })
+    }
+    /// Address of the chunk
+    fn id(&self) -> usize {
+        *self as *const Chunk as usize
+    }
+}
+
+impl Step {
+    fn parse(step: &str) -> Result<Self, QueryError> {
+        match step {
+            "**" => return Ok(Step::Descendants),
+            "" => return Err(QueryError::InvalidStep(step.to_string())),
+            _ => {}
+        }
+        let (name, mut rest) = match step.find('[') {
+            Some(i) => step.split_at(i),
+            None => (step, ""),
+        };
+        let name = match name {
+            "*" => None,
+            _ => Some(
+                name.parse::<FourCC>()
+                    .map_err(|_| QueryError::InvalidStep(step.to_string()))?,
+            ),
+        };
+        let mut predicates = vec![];
+        while !rest.is_empty() {
+            let end = match (rest.starts_with('['), rest.find(']')) {
+                (true, Some(v)) => v,
+                _ => return Err(QueryError::InvalidStep(step.to_string())),
+            };
+            let predicate = &rest[1..end];
+            predicates.push(match predicate.split_once('=') {
+                Some((k, v)) => Predicate::Equals(k.to_string(), v.to_string()),
+                None => Predicate::Index(
+                    predicate
+                        .parse()
+                        .map_err(|_| QueryError::InvalidPredicate(predicate.to_string()))?,
+                ),
+            });
+            rest = &rest[end + 1..];
+        }
+        Ok(Step::Child { name, predicates })
+    }
+    /// Whether a chunk that is the `index`th sibling with its name matches this step
+    fn matches<N: QueryNode>(&self, node: &N, index: usize) -> bool {
+        match self {
+            Step::Descendants => true,
+            Step::Child { name, predicates } => {
+                let fields: Vec<(&str, &str)> = predicates
+                    .iter()
+                    .filter_map(|p| match p {
+                        Predicate::Equals(k, v) => Some((k.as_str(), v.as_str())),
+                        Predicate::Index(_) => None,
+                    })
+                    .collect();
+                name.is_none_or(|n| n == node.header().name)
+                    && predicates.iter().all(|p| match p {
+                        Predicate::Index(i) => *i == index,
+                        Predicate::Equals(..) => true,
+                    })
+                    && (fields.is_empty() || node.has_fields(&fields))
+            }
+        }
+    }
+}
+
+/// Split a query on the slashes that aren't inside predicates
+fn split_steps(query: &str) -> Vec<&str> {
+    let mut steps = vec![];
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in query.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            '/' if depth == 0 => {
+                steps.push(&query[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    steps.push(&query[start..]);
+    steps
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(QueryError::EmptyQuery);
+        }
+        let mut steps: Vec<Step> = vec![];
+        for step in split_steps(s) {
+            let step = Step::parse(step)?;
+            // Repeating ** doesn't match anything more
+            if step == Step::Descendants && steps.last() == Some(&Step::Descendants) {
+                continue;
+            }
+            steps.push(step);
+        }
+        Ok(Query { steps })
+    }
+}
+
+impl Query {
+    /// Parse a query
+    pub fn new(query: &str) -> Result<Self, QueryError> {
+        query.parse()
+    }
+    /// Find the chunks matching the query, given the name of the root and the chunks directly inside it
+    ///
+    /// Matches are returned in the order they appear in the file
+    pub fn select_from<N: QueryNode>(&self, root: FourCC, root_chunks: Vec<N>) -> Vec<N> {
+        let mut matches: Vec<N> = vec![];
+        match self.steps.first() {
+            Some(Step::Descendants) => select_in(&self.steps, root_chunks, &mut matches),
+            // The root itself isn't a chunk, so only match past it
+            Some(Step::Child { name, predicates }) => {
+                let is_root = name.is_none_or(|n| n == root)
+                    && predicates.iter().all(|p| *p == Predicate::Index(0));
+                if is_root && self.steps.len() > 1 {
+                    select_in(&self.steps[1..], root_chunks, &mut matches);
+                }
+            }
+            None => {}
+        }
+        // A chunk can be reached in more than one way when there are several **
+        let mut seen: HashSet<usize> = HashSet::new();
+        matches.retain(|n| seen.insert(n.id()));
+        matches
+    }
+    /// Find the chunks matching the query in a file
+    ///
+    /// Only visited ucfb and level chunks are searched inside, so visit the file first
+    pub fn select<'a>(&self, file: &'a UCFBFile) -> Vec<&'a Chunk> {
+        self.select_from(FourCC::UCFB, file.chunks.iter().collect())
+    }
+    /// Find the chunks matching the query in a borrowed file, searching inside every known container
+    pub fn select_refs<'a>(&self, file: &UCFBFileRef<'a>) -> Vec<ChunkRef<'a>> {
+        self.select_from(FourCC::UCFB, file.chunks.clone())
+    }
+}
+
+fn select_in<N: QueryNode>(steps: &[Step], chunks: Vec<N>, matches: &mut Vec<N>) {
+    let mut sibling_counts: HashMap<FourCC, usize> = HashMap::new();
+    for chunk in chunks {
+        let index = sibling_index(&mut sibling_counts, chunk.header());
+        select_node(steps, chunk, index, matches);
+    }
+}
+
+/// Match the steps against a chunk that is the `index`th sibling with its name
+fn select_node<N: QueryNode>(steps: &[Step], chunk: N, index: usize, matches: &mut Vec<N>) {
+    match steps.split_first() {
+        None => matches.push(chunk),
+        Some((Step::Descendants, rest)) => {
+            let children = chunk.children();
+            // Match zero levels, then try again one level down
+            select_node(rest, chunk, index, matches);
+            select_in(steps, children, matches);
+        }
+        Some((step, rest)) => {
+            if !step.matches(&chunk, index) {
+                return;
+            }
+            if rest.is_empty() {
+                matches.push(chunk);
+            } else {
+                select_in(rest, chunk.children(), matches);
+            }
+        }
+    }
+}
+
+impl UCFBFile {
+    /// Find the visited chunks matching a query such as `ucfb/lvl_/tex_`, see `Query`
+    pub fn select(&self, query: &str) -> Result<Vec<&Chunk>, QueryError> {
+        Ok(Query::new(query)?.select(self))
+    }
+}
+
+impl<'a> UCFBFileRef<'a> {
+    /// Find the chunks matching a query such as `ucfb/lvl_/tex_`, see `Query`
+    pub fn select(&self, query: &str) -> Result<Vec<ChunkRef<'a>>, QueryError> {
+        Ok(Query::new(query)?.select_refs(self))
+    }
+}
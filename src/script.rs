@@ -1,15 +1,21 @@
+use crate::fourcc::FourCC;
 use crate::ucfb::*;
 use lunify::{unify, Format, InstructionLayout, LunifyError, OperandType, Settings};
 
 /// This object represents the addme.script file
 /// 'Tis a wrapper above `ucfb` that also decompiles the lua code
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct Script {
     /// The name of the script
     pub name: String,
     /// I don't know, probably represents the format
     pub info: u8,
     /// Lua bytecode
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::export::serialize_bytes")
+    )]
     pub body: Vec<u8>,
 }
 
@@ -26,14 +32,40 @@ pub enum ScriptError {
     LuaBytecodeParseFailure(LunifyError),
 }
 
+impl std::fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptError::ChunkParseError(_) => write!(f, "failed to read the chunks in the script"),
+            ScriptError::NotAScript => write!(f, "chunk is not a script (scr_)"),
+            ScriptError::CorruptScript => write!(f, "script is corrupt"),
+            // LunifyError doesn't implement Display, so it can't be a source
+            ScriptError::LuaBytecodeParseFailure(e) => {
+                write!(f, "failed to convert the lua bytecode: {:?}", e)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScriptError::ChunkParseError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 impl Script {
     /// Deserialize script from chunk
     pub fn from_chunk(chunk: Chunk) -> Result<Self, ScriptError> {
-        if chunk.header.name != "scr_" {
+        Self::from_chunk_ref(&chunk.as_chunk_ref())
+    }
+    /// Deserialize script from a borrowed chunk
+    pub fn from_chunk_ref(chunk: &ChunkRef) -> Result<Self, ScriptError> {
+        if chunk.header.name != FourCC::SCRIPT {
             return Err(ScriptError::NotAScript);
         }
-        let mut data = chunk.data.clone();
-        let subchunks: Vec<Chunk> = match extract_chunks_bytearray(&mut data) {
+        let subchunks: Vec<ChunkRef> = match chunk.subchunks() {
             Ok(v) => v,
             Err(e) => return Err(ScriptError::ChunkParseError(e)),
         };
@@ -42,18 +74,49 @@ impl Script {
             None => return Err(ScriptError::CorruptScript),
         }
         .data
-        .clone();
+        .to_vec();
         // There is a trailing null byte after the data that must be removed
         body.pop();
         Ok(Script {
             // Remove all null bytes after extracting name
-            name: String::from_utf8(subchunks.get(0).unwrap().data.clone())
-                .unwrap()
-                .replace('\0', ""),
-            info: *subchunks.get(1).unwrap().data.get(0).unwrap(),
-            body: body,
+            name: String::from_utf8(
+                subchunks
+                    .first()
+                    .ok_or(ScriptError::CorruptScript)?
+                    .data
+                    .to_vec(),
+            )
+            .map_err(|_| ScriptError::CorruptScript)?
+            .replace('\0', ""),
+            info: *subchunks
+                .get(1)
+                .and_then(|v| v.data.first())
+                .ok_or(ScriptError::CorruptScript)?,
+            body,
         })
     }
+    /// Get the lua version the bytecode was compiled with, such as `0x50` for lua 5.0
+    pub fn lua_version(&self) -> Option<u8> {
+        match self.body.strip_prefix(b"\x1bLua") {
+            Some([version, ..]) => Some(*version),
+            _ => None,
+        }
+    }
+    /// Serialize the script to a chunk
+    pub fn to_chunk(&self) -> Result<Chunk, ScriptError> {
+        let mut body = self.body.clone();
+        // The bytecode is followed by a null byte, like the name
+        body.push(0);
+        let subchunks = [
+            Chunk::new(FourCC::NAME, format!("{}\0", self.name).into_bytes()),
+            Chunk::new(FourCC::INFO, vec![self.info]),
+            Chunk::new(FourCC::BODY, body),
+        ];
+        Ok(Chunk::new(
+            FourCC::SCRIPT,
+            chunks_to_bytes(&subchunks).map_err(ScriptError::ChunkParseError)?,
+        ))
+    }
     /// Convert the lua 5.0 bytecode to lua 5.1 so it can be decompiled
     pub fn get_lua_51_bytecode_from_50(&self) -> Result<Vec<u8>, LunifyError> {
         // TODO: numbers converted seem to be wrong
@@ -80,11 +143,11 @@ impl Script {
         };
         // TODO: error handling
         // TODO: actually made it work by reimplementing this function but injecting lineinfo
-        Ok(unify(bytecode, &fmt, &bytecode_settings)?)
+        unify(bytecode, &fmt, &bytecode_settings)
     }
     /// Decompile the bytecode, internally converting to lua 5.1 first
     pub fn decompile_bytecode(&self) {
-        let bytecode = self.get_lua_51_bytecode_from_50();
+        let _bytecode = self.get_lua_51_bytecode_from_50();
         // TODO: decompile the new bytecode
     }
 }
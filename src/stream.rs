@@ -0,0 +1,157 @@
+use crate::fourcc::FourCC;
+use crate::ucfb::*;
+use nom::ToUsize;
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+
+/// A chunk header found by `ChunkStream`, along with where it is in the stream
+#[derive(Debug, Clone)]
+pub struct ChunkEntry {
+    /// Chunk header
+    pub header: ChunkHeader,
+    /// Where the chunk was found, the offset is from the start of the stream
+    pub location: ChunkLocation,
+}
+
+impl ChunkEntry {
+    /// Offset of the chunk data from the start of the stream
+    pub fn data_offset(&self) -> u64 {
+        self.location.offset + 8
+    }
+    /// Offset of the first byte after the chunk and its alignment padding
+    pub fn end_offset(&self) -> u64 {
+        self.data_offset() + ((u64::from(self.header.size) + 3) & !3)
+    }
+}
+
+/// Iterator over the chunk headers in a stream that only reads chunk bodies when asked to
+///
+/// Bodies are skipped by seeking, so listing a large file only reads its headers
+#[derive(Debug)]
+pub struct ChunkStream<'r, R: Read + Seek> {
+    reader: &'r mut R,
+    position: u64,
+    end: u64,
+    parent: ChunkLocation,
+    sibling_counts: HashMap<FourCC, usize>,
+    endianness: Endianness,
+}
+
+impl<'r, R: Read + Seek> ChunkStream<'r, R> {
+    /// Start streaming the chunks of a ucfb file from the reader's current position
+    ///
+    /// The byte order is detected from the header, see `Endianness::detect`
+    pub fn new(reader: &'r mut R) -> Result<Self, UCFBError> {
+        let start = reader.stream_position().map_err(UCFBError::IOError)?;
+        let mut buffer = [0; 8];
+        reader
+            .read_exact(&mut buffer)
+            .map_err(|_| UCFBError::FileTooSmall)?;
+        let length = reader.seek(SeekFrom::End(0)).map_err(UCFBError::IOError)?;
+        let endianness = Endianness::detect(&buffer, length.saturating_sub(start))
+            .ok_or(UCFBError::FileTooSmall)?;
+        let (_, header) = parse_header(&buffer, endianness).map_err(|_| UCFBError::NotAUCFBFile)?;
+        Ok(ChunkStream {
+            reader,
+            position: start + 8,
+            end: start + 8 + u64::from(header.size),
+            parent: ChunkLocation::root(start),
+            sibling_counts: HashMap::new(),
+            endianness,
+        })
+    }
+    /// Stream the chunks stored between two offsets of the reader
+    pub fn from_range(reader: &'r mut R, start: u64, end: u64, endianness: Endianness) -> Self {
+        ChunkStream {
+            reader,
+            position: start,
+            end,
+            parent: ChunkLocation::default(),
+            sibling_counts: HashMap::new(),
+            endianness,
+        }
+    }
+    /// Byte order of the chunk sizes
+    pub fn endianness(&self) -> Endianness {
+        self.endianness
+    }
+    /// Read the data of a chunk returned by this stream
+    pub fn read_body(&mut self, entry: &ChunkEntry) -> Result<Vec<u8>, UCFBError> {
+        let mut body = vec![0; entry.header.size.to_usize()];
+        self.reader
+            .seek(SeekFrom::Start(entry.data_offset()))
+            .map_err(UCFBError::IOError)?;
+        self.reader
+            .read_exact(&mut body)
+            .map_err(UCFBError::IOError)?;
+        Ok(body)
+    }
+    /// Read a chunk returned by this stream into a `Chunk`
+    pub fn read_chunk(&mut self, entry: &ChunkEntry) -> Result<Chunk, UCFBError> {
+        Ok(Chunk {
+            header: entry.header.clone(),
+            location: entry.location.clone(),
+            data: self.read_body(entry)?.into(),
+            deciphered_chunk: None,
+            diagnostics: vec![],
+        })
+    }
+    /// Stream the subchunks of a chunk returned by this stream
+    pub fn subchunks(&mut self, entry: &ChunkEntry) -> ChunkStream<'_, R> {
+        let start = entry.data_offset() + entry.header.subchunk_offset() as u64;
+        ChunkStream {
+            reader: self.reader,
+            position: start,
+            end: entry.data_offset() + u64::from(entry.header.size),
+            parent: entry.location.clone(),
+            sibling_counts: HashMap::new(),
+            endianness: self.endianness,
+        }
+    }
+}
+
+impl<'r, R: Read + Seek> Iterator for ChunkStream<'r, R> {
+    type Item = Result<ChunkEntry, UCFBError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.position + 8 > self.end {
+            return None;
+        }
+        if self.parent.depth >= MAX_NESTING_DEPTH {
+            self.position = self.end;
+            return Some(Err(UCFBError::TooDeeplyNested(self.parent.clone())));
+        }
+        let mut buffer = [0; 8];
+        let header = self
+            .reader
+            .seek(SeekFrom::Start(self.position))
+            .and_then(|_| self.reader.read_exact(&mut buffer))
+            .map_err(UCFBError::IOError)
+            .and_then(|_| {
+                parse_chunk_header(&buffer, self.endianness)
+                    .map(|(_, h)| h)
+                    .map_err(|_| UCFBError::NotAUCFBFile)
+            });
+        let entry = match header {
+            Ok(v) => ChunkEntry {
+                location: self.parent.child(
+                    &v,
+                    sibling_index(&mut self.sibling_counts, &v),
+                    self.position,
+                ),
+                header: v,
+            },
+            Err(e) => {
+                // Stop after the first error since there is no way to find the next chunk
+                self.position = self.end;
+                return Some(Err(e));
+            }
+        };
+        if entry.data_offset() + u64::from(entry.header.size) > self.end {
+            self.position = self.end;
+            return Some(Err(UCFBError::ChunkOutOfBounds(entry.location)));
+        }
+        self.position = entry.end_offset();
+        Some(Ok(entry))
+    }
+}
@@ -48,7 +48,13 @@ impl TextureHeader {
         })
     }
     fn to_dds_header(&self, format: D3DFormat) -> Result<Header, HeaderError> {
-        Ok(Header::new_d3d(
+        // ddsfile computes the size of the texture as a u32, which can overflow for hostile headers
+        let largest_size =
+            u64::from(self.width) * u64::from(self.height) * u64::from(self.depth.max(1)) * 16;
+        if largest_size > u64::from(u32::MAX) {
+            return Err(HeaderError::TextureTooLarge);
+        }
+        Header::new_d3d(
             self.height as u32,
             self.width as u32,
             Some(self.depth as u32),
@@ -56,7 +62,7 @@ impl TextureHeader {
             Some(self.mipmap_count as u32),
             None,
         )
-        .map_err(|e| HeaderError::OtherError(e))?)
+        .map_err(HeaderError::OtherError)
     }
 }
 
@@ -86,6 +92,8 @@ pub struct TextureContainer {
     pub name: String,
     /// List of textures
     formats: Vec<DdsTemporaryInformationStorageObject>,
+    /// Why each format that couldn't be read was skipped
+    pub skipped_formats: Vec<String>,
 }
 
 /// Errors produced during header parsing
@@ -96,6 +104,27 @@ pub enum HeaderError {
     InvalidFourCC(u32),
     /// Some other error happened during parsing the header
     OtherError(ddsfile::Error),
+    /// Texture dimensions are too large to be real
+    TextureTooLarge,
+}
+
+impl std::fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HeaderError::InvalidFourCC(v) => write!(f, "unsupported texture format {:#010X}", v),
+            HeaderError::OtherError(_) => write!(f, "failed to build the dds header"),
+            HeaderError::TextureTooLarge => write!(f, "texture dimensions are too large"),
+        }
+    }
+}
+
+impl std::error::Error for HeaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            HeaderError::OtherError(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 /// Errors produced by this class
@@ -113,18 +142,48 @@ pub enum TextureError {
     TextureParseError,
 }
 
+impl std::fmt::Display for TextureError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextureError::NotATexture => write!(f, "chunk is not a texture (tex_)"),
+            TextureError::HeaderError(_) => write!(f, "invalid texture header"),
+            TextureError::ChunkParseError(_) => {
+                write!(f, "failed to read the chunks in the texture")
+            }
+            TextureError::TextureInfoHeaderParseFailure(_) => {
+                write!(f, "failed to parse the texture format info")
+            }
+            TextureError::TextureParseError => write!(f, "texture is corrupt"),
+        }
+    }
+}
+
+impl std::error::Error for TextureError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TextureError::HeaderError(e) => Some(e),
+            TextureError::ChunkParseError(e) => Some(e),
+            TextureError::TextureInfoHeaderParseFailure(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
 impl TextureContainer {
     // TODO: error handling
     /// Get texture from chunk
     pub fn from_chunk(chunk: Chunk) -> Result<Self, TextureError> {
+        Self::from_chunk_ref(&chunk.as_chunk_ref())
+    }
+    /// Get texture from a borrowed chunk
+    pub fn from_chunk_ref(chunk: &ChunkRef) -> Result<Self, TextureError> {
         // Warning: this format is idiotic and whoever devised it is too
-        if chunk.header.name != "tex_" {
+        if chunk.header.name != crate::fourcc::FourCC::TEXTURE {
             return Err(TextureError::NotATexture);
         }
-        let subchunks = extract_chunks_bytearray(&mut chunk.data.clone())
-            .map_err(|e| TextureError::ChunkParseError(e))?;
+        let subchunks = chunk.subchunks().map_err(TextureError::ChunkParseError)?;
         // NAME chunk
-        let mut name: String = match subchunks.get(0) {
+        let mut name: String = match subchunks.first() {
             Some(v) => v,
             None => return Err(TextureError::TextureParseError),
         }
@@ -138,26 +197,23 @@ impl TextureContainer {
             Some(v) => v,
             None => return Err(TextureError::TextureParseError),
         }
-        .data
-        .clone();
-        let format_count = u32::from_le_bytes(
-            (match format_chunk_data.get(0..4) {
-                Some(v) => v,
-                None => return Err(TextureError::TextureParseError),
-            })
-            .try_into()
-            .map_err(|_| TextureError::TextureParseError)?,
-        );
+        .data;
+        let format_count = chunk
+            .header
+            .endianness
+            .read_u32_from(format_chunk_data)
+            .ok_or(TextureError::TextureParseError)?;
         let mut formats: Vec<DdsTemporaryInformationStorageObject> = vec![];
+        let mut skipped_formats: Vec<String> = vec![];
         // Read the formats
         for i in 0..format_count {
             let texture_format_chunk = match subchunks.get(2 + i as usize) {
                 Some(v) => v,
                 None => return Err(TextureError::TextureParseError),
             };
-            let texture_format_subchunks =
-                extract_chunks_bytearray(&mut texture_format_chunk.data.clone())
-                    .map_err(|e| TextureError::ChunkParseError(e))?;
+            let texture_format_subchunks = texture_format_chunk
+                .subchunks()
+                .map_err(TextureError::ChunkParseError)?;
             // INFO chunk (format info)
             /*let info = match texture_format_subchunks.get(0) {
                 Some(v) => v,
@@ -168,24 +224,18 @@ impl TextureContainer {
             let face = match texture_format_subchunks.get(1) {
                 Some(v) => v,
                 None => return Err(TextureError::TextureParseError),
-            }
-            .data
-            .clone();
-            let face_subchunks = extract_chunks_bytearray(&mut face.clone())
-                .map_err(|e| TextureError::ChunkParseError(e))?;
+            };
+            let face_subchunks = face.subchunks().map_err(TextureError::ChunkParseError)?;
             // FACE.LVL_ chunk
-            let lvl_subchunks = extract_chunks_bytearray(
-                &mut match face_subchunks.get(0) {
-                    Some(v) => v,
-                    None => return Err(TextureError::TextureParseError),
-                }
-                .data
-                .clone(),
-            )
-            .map_err(|e| TextureError::ChunkParseError(e))?;
+            let lvl_subchunks = match face_subchunks.first() {
+                Some(v) => v,
+                None => return Err(TextureError::TextureParseError),
+            }
+            .subchunks()
+            .map_err(TextureError::ChunkParseError)?;
             // FACE.LVL_.INFO chunk (more format info)
             let info2: TextureHeader = match deserialize(
-                match texture_format_subchunks.get(0) {
+                match texture_format_subchunks.first() {
                     Some(v) => v,
                     None => return Err(TextureError::TextureParseError),
                 }
@@ -195,18 +245,20 @@ impl TextureContainer {
                 Ok(v) => v,
                 Err(e) => {
                     //return Err(TextureError::TextureInfoHeaderParseFailure(e));
-                    // Skip, should add a log message here
-                    //println!("Skipped bc of err: {:?}", e);
+                    skipped_formats.push(format!("format {}: {}", i, e));
                     continue;
                 }
             };
             // FACE.LVL_.BODY chunk (texture data)
-            let body = lvl_subchunks.get(1).unwrap().data.clone();
+            let body = lvl_subchunks
+                .get(1)
+                .ok_or(TextureError::TextureParseError)?
+                .data
+                .to_vec();
             let format_of_the_format: D3DFormat = match TextureHeader::find_format(info2.format) {
                 Ok(v) => v,
                 Err(e) => {
-                    // Skip, should add a log message here
-                    //println!("Skipped bc of err: {:?}", e);
+                    skipped_formats.push(format!("format {}: {}", i, e));
                     continue;
                 }
             };
@@ -214,13 +266,14 @@ impl TextureContainer {
             formats.push(DdsTemporaryInformationStorageObject {
                 header: info2
                     .to_dds_header(format_of_the_format)
-                    .map_err(|e| TextureError::HeaderError(e))?,
+                    .map_err(TextureError::HeaderError)?,
                 data: body,
             });
         }
         Ok(TextureContainer {
-            name: name,
-            formats: formats,
+            name,
+            formats,
+            skipped_formats,
         })
     }
 
@@ -237,6 +290,6 @@ impl TextureContainer {
             });
         }
 
-        return formats;
+        formats
     }
 }
@@ -3,43 +3,160 @@ use nom::{
     bytes::complete::tag, combinator::map, multi::count, number::streaming::*, sequence::tuple,
     IResult, ToUsize,
 };
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
 use std::fs::File;
-use std::io::SeekFrom;
+use std::io::{Cursor, SeekFrom};
+use std::ops::{Deref, Range};
+use std::sync::Arc;
 use std::{fmt::Debug, io::prelude::*};
 
+use crate::decoder::{DecoderRegistry, VisitMode};
+use crate::diagnostic::{Diagnostic, VisitReport};
+use crate::fourcc::FourCC;
+use crate::game::GameVersion;
 use crate::lvl::{Level, LevelError};
 use crate::mvs::{Movie, MovieError};
 use crate::prop::{PropertyContainer, PropertyError};
 use crate::script::{Script, ScriptError};
 use crate::tex::{TextureContainer, TextureError};
 
+/// How deeply chunks can be nested before parsing their subchunks fails with `UCFBError::TooDeeplyNested`
+///
+/// Real files nest chunks about ten deep, the limit stops hostile files from overflowing the stack
+pub const MAX_NESTING_DEPTH: usize = 64;
+
 /// This object represents the ucfb file
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct UCFBFile {
     /// The ucfb header
     pub header: UCFBHeader,
     /// List of chunks in the ucfb file
     pub chunks: Vec<Chunk>,
+    /// The game the file was made for, detected and stored by the first visit if `None`
+    pub game_version: Option<GameVersion>,
 }
 
 /// Header for ucfb file
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct UCFBHeader {
     /// Size of ucfb file
     pub size: u32,
+    /// Byte order of the sizes in the file
+    pub endianness: Endianness,
 }
 
 /// Header for ucfb chunk
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub struct ChunkHeader {
     /// Chunk name
-    pub name: String,
+    pub name: FourCC,
     /// Chunk size
     pub size: u32,
+    /// Byte order the size was stored in, the subchunks and numbers in the data use it too
+    pub endianness: Endianness,
+}
+
+/// Byte order of the sizes and numbers in a ucfb file
+///
+/// PC files are little endian, files from big endian consoles store their sizes in big endian
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub enum Endianness {
+    /// Least significant byte first
+    #[default]
+    Little,
+    /// Most significant byte first
+    Big,
+}
+
+impl Endianness {
+    /// Read a number stored in this byte order
+    pub fn read_u32(self, bytes: [u8; 4]) -> u32 {
+        match self {
+            Endianness::Little => u32::from_le_bytes(bytes),
+            Endianness::Big => u32::from_be_bytes(bytes),
+        }
+    }
+    /// Read a number stored in this byte order from the start of a byte array
+    pub fn read_u32_from(self, bytes: &[u8]) -> Option<u32> {
+        Some(self.read_u32(bytes.get(0..4)?.try_into().ok()?))
+    }
+    /// Get the bytes of a number in this byte order
+    pub fn write_u32(self, value: u32) -> [u8; 4] {
+        match self {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big => value.to_be_bytes(),
+        }
+    }
+    /// Guess the byte order of a ucfb file from its header and its length
+    ///
+    /// Files are big endian only when the big endian header size matches the length and the little endian
+    /// one doesn't, anything else such as a truncated file is little endian, the byte order of PC files
+    pub fn detect(header: &[u8], length: u64) -> Option<Endianness> {
+        let size = header.get(4..8)?;
+        let body_length = length.checked_sub(8)?;
+        let matches = |e: Endianness| e.read_u32_from(size).map(u64::from) == Some(body_length);
+        if matches(Endianness::Big) && !matches(Endianness::Little) {
+            Some(Endianness::Big)
+        } else {
+            Some(Endianness::Little)
+        }
+    }
+}
+
+impl From<Endianness> for nom::number::Endianness {
+    fn from(endianness: Endianness) -> Self {
+        match endianness {
+            Endianness::Little => nom::number::Endianness::Little,
+            Endianness::Big => nom::number::Endianness::Big,
+        }
+    }
+}
+
+impl ChunkHeader {
+    /// Whether chunks with this name are known to only contain subchunks
+    pub fn is_known_container(&self) -> bool {
+        matches!(
+            self.name,
+            FourCC::UCFB
+                | FourCC::LEVEL
+                | FourCC::SCRIPT
+                | FourCC::TEXTURE
+                | FourCC::TEXTURE_FORMAT
+                | FourCC::FACE
+                | FourCC::MIP_LEVEL
+                | FourCC::GAME_OBJECT_CLASS
+                | FourCC::EXPLOSION_CLASS
+                | FourCC::ORDNANCE_CLASS
+                | FourCC::WEAPON_CLASS
+        )
+    }
+    /// Get the chunk name in a form that can be printed and used in chunk paths
+    ///
+    /// Names that aren't made of plain ascii are written as hex, such as `0x60701F2F`
+    pub fn display_name(&self) -> String {
+        self.name.to_string()
+    }
+    /// Number of bytes before the subchunks in the chunk data
+    ///
+    /// Levels store their name hash and size before their subchunks
+    pub fn subchunk_offset(&self) -> usize {
+        match self.name {
+            FourCC::LEVEL => 8,
+            _ => 0,
+        }
+    }
 }
 
 /// A container for an object that stores information about a deciphered chunk
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 pub enum DecipheredChunk {
     /// Chunk that represents a lua script
     Script(Script),
@@ -53,6 +170,205 @@ pub enum DecipheredChunk {
     Texture(TextureContainer),
     /// Chunk that represents a class
     PropertyContainer(PropertyContainer),
+    /// Chunk decoded by a decoder from outside this library
+    #[cfg_attr(
+        feature = "serialize",
+        serde(serialize_with = "crate::export::serialize_custom")
+    )]
+    Custom(Arc<dyn Any + Send + Sync>),
+}
+
+impl DecipheredChunk {
+    /// Get the chunks contained in an embedded ucfb file or level
+    pub fn subchunks(&self) -> Option<&[Chunk]> {
+        match self {
+            DecipheredChunk::UCFB(v) => Some(&v.chunks),
+            DecipheredChunk::Level(v) => Some(&v.chunks),
+            _ => None,
+        }
+    }
+    /// Get the chunks contained in an embedded ucfb file or level for modification
+    pub fn subchunks_mut(&mut self) -> Option<&mut Vec<Chunk>> {
+        match self {
+            DecipheredChunk::UCFB(v) => Some(&mut v.chunks),
+            DecipheredChunk::Level(v) => Some(&mut v.chunks),
+            _ => None,
+        }
+    }
+    /// Get descriptions of the parts of the chunk that were skipped while decoding it
+    pub fn skipped_parts(&self) -> &[String] {
+        match self {
+            DecipheredChunk::Texture(v) => &v.skipped_formats,
+            _ => &[],
+        }
+    }
+    /// Get the value stored by a custom decoder if it has the given type
+    pub fn custom<T: Any>(&self) -> Option<&T> {
+        match self {
+            DecipheredChunk::Custom(v) => v.downcast_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Where a chunk was found in the file it was parsed from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+pub struct ChunkLocation {
+    /// Absolute offset of the chunk header in the file
+    pub offset: u64,
+    /// How deeply the chunk is nested, chunks directly in the file have a depth of 1
+    pub depth: usize,
+    /// Path of the chunk from the root of the file, such as `ucfb/lvl_[0]/tex_[12]`
+    ///
+    /// The index counts the previous siblings with the same name
+    pub path: String,
+}
+
+impl ChunkLocation {
+    /// Location of the root of a ucfb file starting at the given offset
+    pub fn root(offset: u64) -> Self {
+        ChunkLocation {
+            offset,
+            depth: 0,
+            path: "ucfb".to_string(),
+        }
+    }
+    /// Location of the `index`th chunk named `header.name` stored in this chunk at `offset`
+    pub fn child(&self, header: &ChunkHeader, index: usize, offset: u64) -> Self {
+        let segment = format!("{}[{}]", header.display_name(), index);
+        ChunkLocation {
+            offset,
+            depth: self.depth + 1,
+            path: if self.path.is_empty() {
+                segment
+            } else {
+                format!("{}/{}", self.path, segment)
+            },
+        }
+    }
+}
+
+impl Display for ChunkLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#X}, {}", self.offset, self.path)
+    }
+}
+
+/// Data of a chunk, a range of a buffer shared with the chunk it was found in and its subchunks
+///
+/// Cloning the data or taking part of it doesn't copy anything
+#[derive(Clone, Default)]
+pub struct ChunkData {
+    buffer: Arc<[u8]>,
+    range: Range<usize>,
+}
+
+impl ChunkData {
+    /// Get part of the data without copying it, returning `None` if the range is out of bounds
+    pub fn slice(&self, range: Range<usize>) -> Option<Self> {
+        if range.start > range.end || range.end > self.len() {
+            return None;
+        }
+        Some(ChunkData {
+            buffer: self.buffer.clone(),
+            range: self.range.start + range.start..self.range.start + range.end,
+        })
+    }
+    /// Get the part of the data `part` borrows from without copying it
+    ///
+    /// `part` is copied if it doesn't borrow from this data
+    pub fn slice_ref(&self, part: &[u8]) -> Self {
+        let start = (part.as_ptr() as usize).wrapping_sub(self.as_ptr() as usize);
+        start
+            .checked_add(part.len())
+            .and_then(|end| self.slice(start..end))
+            .filter(|v| v.as_ptr() == part.as_ptr())
+            .unwrap_or_else(|| ChunkData::from(part))
+    }
+}
+
+impl Deref for ChunkData {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.buffer[self.range.clone()]
+    }
+}
+
+impl AsRef<[u8]> for ChunkData {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl From<Vec<u8>> for ChunkData {
+    fn from(data: Vec<u8>) -> Self {
+        let range = 0..data.len();
+        ChunkData {
+            buffer: data.into(),
+            range,
+        }
+    }
+}
+
+impl From<&[u8]> for ChunkData {
+    fn from(data: &[u8]) -> Self {
+        ChunkData {
+            buffer: data.into(),
+            range: 0..data.len(),
+        }
+    }
+}
+
+impl Debug for ChunkData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&**self, f)
+    }
+}
+
+impl PartialEq for ChunkData {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl Eq for ChunkData {}
+
+impl std::hash::Hash for ChunkData {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl PartialEq<[u8]> for ChunkData {
+    fn eq(&self, other: &[u8]) -> bool {
+        **self == *other
+    }
+}
+
+impl PartialEq<&[u8]> for ChunkData {
+    fn eq(&self, other: &&[u8]) -> bool {
+        **self == **other
+    }
+}
+
+impl PartialEq<Vec<u8>> for ChunkData {
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        **self == **other
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for ChunkData {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        **self == *other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8; N]> for ChunkData {
+    fn eq(&self, other: &&[u8; N]) -> bool {
+        **self == **other
+    }
 }
 
 /// ucfb chunk
@@ -60,10 +376,36 @@ pub enum DecipheredChunk {
 pub struct Chunk {
     /// Chunk header
     pub header: ChunkHeader,
-    /// Chunk data
-    pub data: Vec<u8>,
+    /// Where the chunk was found
+    pub location: ChunkLocation,
+    /// Chunk data, shared with the chunk it was found in and its subchunks
+    pub data: ChunkData,
     /// Class representing the chunk data
     pub deciphered_chunk: Option<DecipheredChunk>,
+    /// Problems found while visiting the chunk
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// ucfb chunk that borrows its data from a larger buffer, such as a memory-mapped file
+///
+/// Nothing is copied until `to_chunk` is called or a decoder needs owned data
+#[derive(Debug, Clone)]
+pub struct ChunkRef<'a> {
+    /// Chunk header
+    pub header: ChunkHeader,
+    /// Where the chunk was found
+    pub location: ChunkLocation,
+    /// Chunk data
+    pub data: &'a [u8],
+}
+
+/// ucfb file that borrows its chunks from a byte array
+#[derive(Debug, Clone)]
+pub struct UCFBFileRef<'a> {
+    /// The ucfb header
+    pub header: UCFBHeader,
+    /// List of chunks in the ucfb file
+    pub chunks: Vec<ChunkRef<'a>>,
 }
 
 /// Error returned by this namespace
@@ -77,10 +419,58 @@ pub enum UCFBError {
     NotAUCFBFile,
     /// Failure reading file
     IOError(std::io::Error),
+    /// Failure reading the chunk at this offset, such as a chunk cut off by the end of the file
+    ReadError(u64, std::io::Error),
     /// Failure to parse chunk file
     InvalidChunkName,
     /// Failure during alignment
     BadAlignment,
+    /// Chunk is too large for its size to fit in the header
+    ChunkTooLarge,
+    /// Chunk at this location claims to be larger than the data containing it
+    ChunkOutOfBounds(ChunkLocation),
+    /// Chunk at this location is nested more than `MAX_NESTING_DEPTH` deep
+    TooDeeplyNested(ChunkLocation),
+}
+
+impl Display for UCFBError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UCFBError::FileTooSmall => write!(f, "file is too small to parse"),
+            UCFBError::WrongHeaderSize => {
+                write!(f, "size in the ucfb header doesn't match the file")
+            }
+            UCFBError::NotAUCFBFile => write!(f, "not a ucfb file"),
+            UCFBError::IOError(_) => write!(f, "failed to read or write the file"),
+            UCFBError::ReadError(offset, _) => {
+                write!(f, "failed to read the chunk at {:#X}", offset)
+            }
+            UCFBError::InvalidChunkName => write!(f, "chunk name is not 4 bytes long"),
+            UCFBError::BadAlignment => write!(f, "failed to align to the next chunk"),
+            UCFBError::ChunkTooLarge => {
+                write!(f, "chunk is too large for its size to fit in a header")
+            }
+            UCFBError::ChunkOutOfBounds(location) => write!(
+                f,
+                "chunk at {} is larger than the data containing it",
+                location
+            ),
+            UCFBError::TooDeeplyNested(location) => write!(
+                f,
+                "chunk at {} is nested more than {} deep",
+                location, MAX_NESTING_DEPTH
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UCFBError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UCFBError::IOError(e) | UCFBError::ReadError(_, e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 /// Error returned during chunk visitation
@@ -103,162 +493,477 @@ pub enum VisitError {
     /// Error during property container/class visitation
     PropertyContainerVisitError(PropertyError),
     /// Unknow chunk name
-    InvalidChunk(String),
+    InvalidChunk(FourCC),
+    /// Error while visiting the chunk at this location
+    AtLocation(ChunkLocation, Box<VisitError>),
 }
 
-fn parse_header(input: &[u8]) -> IResult<&[u8], UCFBHeader> {
-    map(tuple((tag("ucfb"), le_u32)), |(_, s)| UCFBHeader {
-        size: s,
-    })(input)
+impl VisitError {
+    /// Get the location of the innermost chunk that failed, if known
+    pub fn location(&self) -> Option<&ChunkLocation> {
+        match self {
+            VisitError::AtLocation(location, e) => e.location().or(Some(location)),
+            VisitError::UCFBSubchunkVisitationError(e)
+            | VisitError::LevelSubchunkVisitationError(e) => e.location(),
+            _ => None,
+        }
+    }
 }
 
-fn parse_chunk_header(input: &[u8]) -> IResult<&[u8], ChunkHeader> {
-    map(tuple((count(le_u8, 4), le_u32)), |(n, s)| ChunkHeader {
-        name: n.iter().map(|&c| char::from(c)).collect(), // Convert the bytes to ascii and not utf8
-        size: s,
-    })(input)
+impl Display for VisitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VisitError::ScriptError(_) => write!(f, "failed to decode script"),
+            VisitError::MovieError(_) => write!(f, "failed to decode movie"),
+            VisitError::UCFBError(_) => write!(f, "failed to read embedded ucfb"),
+            VisitError::UCFBSubchunkVisitationError(_) => {
+                write!(f, "failed to visit the chunks in embedded ucfb")
+            }
+            VisitError::LevelError(_) => write!(f, "failed to decode level"),
+            VisitError::LevelSubchunkVisitationError(_) => {
+                write!(f, "failed to visit the chunks in level")
+            }
+            VisitError::TextureVisitationError(_) => write!(f, "failed to decode texture"),
+            VisitError::PropertyContainerVisitError(_) => {
+                write!(f, "failed to decode property container")
+            }
+            VisitError::InvalidChunk(name) => write!(f, "unknown chunk {}", name),
+            VisitError::AtLocation(location, _) => {
+                write!(f, "failed to decode chunk at {}", location)
+            }
+        }
+    }
 }
 
-fn align_file_pointer(f: &mut File) -> Result<(), UCFBError> {
-    let offset = 4 - i64::try_from(
-        match f.stream_position() {
-            Ok(v) => v,
-            Err(_) => return Err(UCFBError::BadAlignment),
-        } % 4,
-    )
-    .unwrap();
-    // Don't align if aligned already
-    if offset != 4 {
-        match f.seek(SeekFrom::Current(offset)) {
-            Err(e) => return Err(UCFBError::IOError(e)),
-            Ok(_) => {}
+impl std::error::Error for VisitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            VisitError::ScriptError(e) => Some(e),
+            VisitError::MovieError(e) => Some(e),
+            VisitError::UCFBError(e) => Some(e),
+            VisitError::UCFBSubchunkVisitationError(e) => Some(e.as_ref()),
+            VisitError::LevelError(e) => Some(e),
+            VisitError::LevelSubchunkVisitationError(e) => Some(e.as_ref()),
+            VisitError::TextureVisitationError(e) => Some(e),
+            VisitError::PropertyContainerVisitError(e) => Some(e),
+            VisitError::InvalidChunk(_) => None,
+            VisitError::AtLocation(_, e) => Some(e.as_ref()),
         }
     }
+}
+
+pub(crate) fn parse_header(input: &[u8], endianness: Endianness) -> IResult<&[u8], UCFBHeader> {
+    map(tuple((tag("ucfb"), u32(endianness.into()))), |(_, s)| {
+        UCFBHeader {
+            size: s,
+            endianness,
+        }
+    })(input)
+}
+
+pub(crate) fn parse_chunk_header(
+    input: &[u8],
+    endianness: Endianness,
+) -> IResult<&[u8], ChunkHeader> {
+    map(
+        tuple((count(le_u8, 4), u32(endianness.into()))),
+        |(n, s)| ChunkHeader {
+            name: FourCC([n[0], n[1], n[2], n[3]]),
+            size: s,
+            endianness,
+        },
+    )(input)
+}
+
+/// Skip the padding after a chunk with this size
+///
+/// Padding is relative to the start of the chunk, which isn't always aligned in the stream
+/// such as when a ucfb file is embedded at an odd offset
+fn skip_padding<R: Seek>(f: &mut R, size: u32) -> Result<(), UCFBError> {
+    let padding = (4 - i64::from(size % 4)) % 4;
+    if padding != 0 {
+        f.seek(SeekFrom::Current(padding))
+            .map_err(UCFBError::IOError)?;
+    }
     Ok(())
 }
 
-/// Extract chunks from a file with the file pointer advanced to the start of the chunks
-pub fn extract_chunks(file: &mut File) -> Result<Vec<Chunk>, UCFBError> {
+pub(crate) fn sibling_index(counts: &mut HashMap<FourCC, usize>, header: &ChunkHeader) -> usize {
+    let count = counts.entry(header.name).or_insert(0);
+    *count += 1;
+    *count - 1
+}
+
+/// Set the locations of a list of chunks stored at `offset` in the parent to where they are written out
+fn update_locations(chunks: &mut [Chunk], parent: &ChunkLocation, mut offset: u64) {
+    let mut sibling_counts: HashMap<FourCC, usize> = HashMap::new();
+    for chunk in chunks {
+        let index = sibling_index(&mut sibling_counts, &chunk.header);
+        chunk.location = parent.child(&chunk.header, index, offset);
+        let subchunk_offset = offset + 8 + chunk.header.subchunk_offset() as u64;
+        if let Some(subchunks) = chunk
+            .deciphered_chunk
+            .as_mut()
+            .and_then(DecipheredChunk::subchunks_mut)
+        {
+            update_locations(subchunks, &chunk.location, subchunk_offset);
+        }
+        offset += 8 + ((u64::from(chunk.header.size) + 3) & !3);
+    }
+}
+
+/// Extract little endian chunks from a reader with the file pointer advanced to the start of the chunks
+pub fn extract_chunks<R: Read + Seek>(file: &mut R) -> Result<Vec<Chunk>, UCFBError> {
+    extract_chunks_in(file, &ChunkLocation::root(0), Endianness::Little)
+}
+
+fn extract_chunks_in<R: Read + Seek>(
+    file: &mut R,
+    parent: &ChunkLocation,
+    endianness: Endianness,
+) -> Result<Vec<Chunk>, UCFBError> {
     let mut current_chunk_header: ChunkHeader;
     let mut chunks: Vec<Chunk> = vec![];
     let mut temp_chunk_data: Vec<u8>;
     let mut buffer: Vec<u8> = vec![0; 8];
+    let mut sibling_counts: HashMap<FourCC, usize> = HashMap::new();
+    let mut offset = file.stream_position().map_err(UCFBError::IOError)?;
+    // Find the end so chunk sizes can be checked before allocating anything
+    let end = file.seek(SeekFrom::End(0)).map_err(UCFBError::IOError)?;
+    file.seek(SeekFrom::Start(offset))
+        .map_err(UCFBError::IOError)?;
     // Parse out the chunks
     // read in these steps: Read header, read data, align on 4 bytes, repeat
-    while match file.read(&mut buffer) {
-        Ok(v) => v,
-        Err(_) => return Err(UCFBError::FileTooSmall),
-    } == buffer.len()
-    {
-        (_, current_chunk_header) = match parse_chunk_header(&mut buffer) {
+    // The last chunk may not be padded, so the padding can end past the end
+    while offset < end {
+        file.read_exact(&mut buffer)
+            .map_err(|e| UCFBError::ReadError(offset, e))?;
+        (_, current_chunk_header) = match parse_chunk_header(&buffer, endianness) {
             Ok(v) => (v.0.to_vec(), v.1),
-            Err(e) => {
-                println!("Error: {}", e);
-                return Err(UCFBError::NotAUCFBFile);
-            }
-        };
-        temp_chunk_data = vec![0; current_chunk_header.size.to_usize()];
-        match file.read(&mut temp_chunk_data) {
-            Err(_) => return Err(UCFBError::BadAlignment),
-            _ => (), // Otherwise do nothing
+            Err(_) => return Err(UCFBError::NotAUCFBFile),
         };
+        let index = sibling_index(&mut sibling_counts, &current_chunk_header);
+        let location = parent.child(&current_chunk_header, index, offset);
+        if offset + 8 + u64::from(current_chunk_header.size) > end {
+            return Err(UCFBError::ChunkOutOfBounds(location));
+        }
+        let size = current_chunk_header.size;
+        temp_chunk_data = vec![0; size.to_usize()];
+        file.read_exact(&mut temp_chunk_data)
+            .map_err(|e| UCFBError::ReadError(offset, e))?;
         chunks.push(Chunk {
             header: current_chunk_header,
-            data: temp_chunk_data.to_vec(),
+            location,
+            data: temp_chunk_data.into(),
             deciphered_chunk: None,
+            diagnostics: vec![],
         });
         // align by 4 bytes
-        align_file_pointer(file)?;
+        skip_padding(file, size)?;
+        offset = file.stream_position().map_err(UCFBError::IOError)?;
     }
     Ok(chunks)
 }
 
-/// Extract chunks from a byte array of chunks
-/// TODO: dont make buffer mutable
-pub fn extract_chunks_bytearray(buffer: &mut Vec<u8>) -> Result<Vec<Chunk>, UCFBError> {
+/// Extract borrowed little endian chunks from a byte array of chunks without copying their data
+///
+/// The locations of the chunks are relative to the start of the byte array
+pub fn extract_chunk_refs(buffer: &[u8]) -> Result<Vec<ChunkRef<'_>>, UCFBError> {
+    extract_chunk_refs_in(buffer, &ChunkLocation::default(), 0, Endianness::Little)
+}
+
+fn extract_chunk_refs_in<'a>(
+    buffer: &'a [u8],
+    parent: &ChunkLocation,
+    buffer_offset: u64,
+    endianness: Endianness,
+) -> Result<Vec<ChunkRef<'a>>, UCFBError> {
     let mut current_chunk_header: ChunkHeader;
-    let mut chunks: Vec<Chunk> = vec![];
-    let mut chunk_data: Vec<u8>;
+    let mut chunks: Vec<ChunkRef> = vec![];
+    let mut remaining: &[u8] = buffer;
+    let mut sibling_counts: HashMap<FourCC, usize> = HashMap::new();
     // Parse out the chunks
     // read in these steps: Read header, read data, align on 4 bytes, repeat
-    while buffer.len() > 0 {
-        (*buffer, current_chunk_header) = match parse_chunk_header(buffer) {
-            Ok(v) => (v.0.to_vec(), v.1),
-            Err(e) => {
-                println!("Error: {}", e);
-                return Err(UCFBError::NotAUCFBFile);
-            }
+    if !remaining.is_empty() && parent.depth >= MAX_NESTING_DEPTH {
+        return Err(UCFBError::TooDeeplyNested(parent.clone()));
+    }
+    while !remaining.is_empty() {
+        let offset = buffer_offset + (buffer.len() - remaining.len()) as u64;
+        (remaining, current_chunk_header) = match parse_chunk_header(remaining, endianness) {
+            Ok(v) => v,
+            Err(_) => return Err(UCFBError::NotAUCFBFile),
         };
-        chunk_data = buffer
-            .drain(0..current_chunk_header.size.to_usize())
-            .collect();
-        chunks.push(Chunk {
+        let index = sibling_index(&mut sibling_counts, &current_chunk_header);
+        let location = parent.child(&current_chunk_header, index, offset);
+        let size = current_chunk_header.size.to_usize();
+        let chunk_data = match remaining.get(..size) {
+            Some(v) => v,
+            None => return Err(UCFBError::ChunkOutOfBounds(location)),
+        };
+        chunks.push(ChunkRef {
             header: current_chunk_header,
+            location,
             data: chunk_data,
-            deciphered_chunk: None,
         });
-        // align by 4 bytes
-        match buffer.len() % 4 {
-            0 | 4 => {}
-            _ => {
-                buffer.drain(0..(buffer.len() % 4));
-                ()
-            }
-        }
+        // align by 4 bytes, the last chunk may not be padded
+        let aligned_size = (size + 3) & !3;
+        remaining = remaining.get(aligned_size..).unwrap_or(&[]);
     }
     Ok(chunks)
 }
 
-/// Try to figure out what the data stored in the chunks is and parse if possible
-pub fn visit_chunks_from_vec(chunks: &mut Vec<Chunk>) -> Result<(), VisitError> {
+/// Extract little endian chunks from a byte array of chunks
+///
+/// The byte array is copied once and shared by the chunks,
+/// the locations of the chunks are relative to the start of the byte array
+pub fn extract_chunks_bytearray(buffer: &[u8]) -> Result<Vec<Chunk>, UCFBError> {
+    let data = ChunkData::from(buffer);
+    Ok(extract_chunk_refs(&data)?
+        .iter()
+        .map(|c| c.to_shared_chunk(&data))
+        .collect())
+}
+
+fn write_chunk_header<W: Write>(
+    writer: &mut W,
+    name: FourCC,
+    size: usize,
+    endianness: Endianness,
+) -> Result<(), UCFBError> {
+    let size = u32::try_from(size).map_err(|_| UCFBError::ChunkTooLarge)?;
+    writer
+        .write_all(name.as_bytes())
+        .map_err(UCFBError::IOError)?;
+    writer
+        .write_all(&endianness.write_u32(size))
+        .map_err(UCFBError::IOError)?;
+    Ok(())
+}
+
+/// Write a list of chunks, padding each one to 4 bytes
+pub fn write_chunks<W: Write>(chunks: &[Chunk], writer: &mut W) -> Result<(), UCFBError> {
     for chunk in chunks {
-        chunk.deciphered_chunk = match chunk.header.name.as_str() {
-            "scr_" => Some(DecipheredChunk::Script(
-                Script::from_chunk(chunk.clone()).map_err(|e| VisitError::ScriptError(e))?,
-            )),
-            "\x60\x70\x1F\x2F" => Some(DecipheredChunk::Movie(
-                Movie::from_chunk(chunk.clone()).map_err(|e| VisitError::MovieError(e))?,
-            )),
-            "ucfb" => Some(DecipheredChunk::UCFB(UCFBFile {
-                header: UCFBHeader {
-                    size: chunk.header.size,
-                },
-                chunks: match extract_chunks_bytearray(&mut chunk.data) {
-                    Ok(mut v) => match visit_chunks_from_vec(&mut v) {
-                        Ok(_) => v,
-                        Err(e) => return Err(VisitError::UCFBSubchunkVisitationError(Box::new(e))),
-                    },
-                    Err(e) => return Err(VisitError::UCFBError(e)),
-                },
-            })),
-            "lvl_" => Some(DecipheredChunk::Level(
-                match Level::from_chunk(chunk.clone()) {
-                    Ok(mut v) => match visit_chunks_from_vec(&mut v.chunks) {
-                        Ok(_) => v,
-                        Err(e) => {
-                            return Err(VisitError::LevelSubchunkVisitationError(Box::new(e)))
-                        }
-                    },
-                    Err(e) => {
-                        return Err(VisitError::LevelError(e));
-                    }
-                },
-            )),
-            "tex_" => Some(DecipheredChunk::Texture(
-                TextureContainer::from_chunk(chunk.clone())
-                    .map_err(|e| VisitError::TextureVisitationError(e))?,
-            )),
-            "entc" | "expc" | "ordc" | "wpnc" => Some(DecipheredChunk::PropertyContainer(
-                PropertyContainer::from_chunk(chunk.clone())
-                    .map_err(|e| VisitError::PropertyContainerVisitError(e))?,
-            )),
-            _ => {
-                //return Err(VisitError::InvalidChunk(chunk.header.name.clone()));
-                None
+        chunk.write(writer)?;
+    }
+    Ok(())
+}
+
+/// Serialize a list of chunks to a byte array
+///
+/// This is the inverse of `extract_chunks_bytearray`
+pub fn chunks_to_bytes(chunks: &[Chunk]) -> Result<Vec<u8>, UCFBError> {
+    let mut buffer: Vec<u8> = vec![];
+    write_chunks(chunks, &mut buffer)?;
+    Ok(buffer)
+}
+
+impl<'a> ChunkRef<'a> {
+    /// Extract the subchunks stored in this chunk's data, borrowing from the same buffer
+    pub fn subchunks(&self) -> Result<Vec<ChunkRef<'a>>, UCFBError> {
+        let skip = self.header.subchunk_offset();
+        extract_chunk_refs_in(
+            self.data.get(skip..).unwrap_or(&[]),
+            &self.location,
+            self.location.offset + 8 + skip as u64,
+            self.header.endianness,
+        )
+    }
+    /// Copy the chunk into an owned `Chunk`
+    pub fn to_chunk(&self) -> Chunk {
+        self.to_shared_chunk(&ChunkData::default())
+    }
+    /// Turn the chunk into an owned `Chunk` sharing the data it borrows from
+    ///
+    /// The chunk data is only copied if it isn't borrowed from `buffer`
+    pub fn to_shared_chunk(&self, buffer: &ChunkData) -> Chunk {
+        Chunk {
+            header: self.header.clone(),
+            location: self.location.clone(),
+            data: buffer.slice_ref(self.data),
+            deciphered_chunk: None,
+            diagnostics: vec![],
+        }
+    }
+}
+
+impl<'a> UCFBFileRef<'a> {
+    /// Parse a ucfb file from a byte array without copying chunk data, detecting its byte order
+    pub fn from_bytes(buffer: &'a [u8]) -> Result<Self, UCFBError> {
+        let endianness =
+            Endianness::detect(buffer, buffer.len() as u64).ok_or(UCFBError::FileTooSmall)?;
+        Self::from_bytes_with(buffer, endianness)
+    }
+    /// Parse a ucfb file stored in the given byte order from a byte array without copying chunk data
+    pub fn from_bytes_with(buffer: &'a [u8], endianness: Endianness) -> Result<Self, UCFBError> {
+        let (body, header) =
+            parse_header(buffer, endianness).map_err(|_| UCFBError::NotAUCFBFile)?;
+        if body.len() != header.size.to_usize() {
+            return Err(UCFBError::WrongHeaderSize);
+        }
+        Ok(UCFBFileRef {
+            header,
+            chunks: extract_chunk_refs_in(body, &ChunkLocation::root(0), 8, endianness)?,
+        })
+    }
+    /// Copy the ucfb file into an owned `UCFBFile`
+    pub fn to_ucfb_file(&self) -> UCFBFile {
+        UCFBFile {
+            header: self.header.clone(),
+            chunks: self.chunks.iter().map(ChunkRef::to_chunk).collect(),
+            game_version: None,
+        }
+    }
+}
+
+/// Memory-map a file so it can be parsed with `UCFBFileRef::from_bytes` without reading it all in
+///
+/// The file must not be modified by another process while the map is alive
+#[cfg(feature = "mmap")]
+pub fn map_file(file_name: String) -> Result<memmap2::Mmap, UCFBError> {
+    let le_file = File::open(file_name).map_err(UCFBError::IOError)?;
+    // SAFETY: the map is read-only and the caller is told not to modify the file while it is mapped
+    unsafe { memmap2::Mmap::map(&le_file) }.map_err(UCFBError::IOError)
+}
+
+impl Chunk {
+    /// Create an undeciphered chunk holding the data
+    pub fn new(name: FourCC, data: Vec<u8>) -> Self {
+        Chunk {
+            header: ChunkHeader {
+                name,
+                // Too large chunks are caught when they are written
+                size: u32::try_from(data.len()).unwrap_or(u32::MAX),
+                endianness: Endianness::Little,
+            },
+            location: ChunkLocation::default(),
+            data: data.into(),
+            deciphered_chunk: None,
+            diagnostics: vec![],
+        }
+    }
+    /// Borrow the chunk as a `ChunkRef`
+    pub fn as_chunk_ref(&self) -> ChunkRef<'_> {
+        ChunkRef {
+            header: self.header.clone(),
+            location: self.location.clone(),
+            data: &self.data,
+        }
+    }
+    /// Extract the subchunks stored in this chunk's data, sharing it instead of copying it
+    pub fn subchunks(&self) -> Result<Vec<Chunk>, UCFBError> {
+        Ok(self
+            .as_chunk_ref()
+            .subchunks()?
+            .iter()
+            .map(|c| c.to_shared_chunk(&self.data))
+            .collect())
+    }
+    /// Get the body of the chunk as it would be written out
+    ///
+    /// Embedded ucfb and level chunks are rebuilt from their subchunks so their sizes are recomputed,
+    /// everything else is written from the raw data
+    pub fn body(&self) -> Result<Cow<'_, [u8]>, UCFBError> {
+        Ok(match &self.deciphered_chunk {
+            Some(DecipheredChunk::UCFB(f)) => Cow::Owned(chunks_to_bytes(&f.chunks)?),
+            Some(DecipheredChunk::Level(l)) => Cow::Owned(l.to_bytes(self.header.endianness)?),
+            _ => Cow::Borrowed(&self.data[..]),
+        })
+    }
+    /// Update the raw data and size from the decoded subchunks after they have been edited
+    pub fn rebuild(&mut self) -> Result<(), UCFBError> {
+        let body = self.body()?.into_owned();
+        self.header.size = u32::try_from(body.len()).map_err(|_| UCFBError::ChunkTooLarge)?;
+        if let Some(DecipheredChunk::UCFB(f)) = &mut self.deciphered_chunk {
+            f.header.size = self.header.size;
+        }
+        self.data = body.into();
+        Ok(())
+    }
+    /// Convert the chunk and its subchunks to another byte order
+    ///
+    /// Chunk sizes, level name hashes and sizes and the property hashes in classes are converted.
+    /// Other numbers in the data, such as the texture format info, are left as they are
+    pub fn set_endianness(&mut self, endianness: Endianness) -> Result<(), UCFBError> {
+        match self.deciphered_chunk.as_mut() {
+            Some(DecipheredChunk::UCFB(f)) => {
+                f.header.endianness = endianness;
+                for chunk in &mut f.chunks {
+                    chunk.set_endianness(endianness)?;
+                }
             }
+            Some(DecipheredChunk::Level(l)) => {
+                for chunk in &mut l.chunks {
+                    chunk.set_endianness(endianness)?;
+                }
+            }
+            _ => self.data = convert_endianness(&self.as_chunk_ref(), endianness)?.into(),
+        }
+        self.header.endianness = endianness;
+        self.rebuild()
+    }
+    /// Write the chunk header, body and alignment padding
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), UCFBError> {
+        let body = self.body()?;
+        write_chunk_header(writer, self.header.name, body.len(), self.header.endianness)?;
+        writer.write_all(&body).map_err(UCFBError::IOError)?;
+        // align by 4 bytes
+        let padding = (4 - body.len() % 4) % 4;
+        writer
+            .write_all(&[0; 3][..padding])
+            .map_err(UCFBError::IOError)?;
+        Ok(())
+    }
+    /// Serialize the chunk to a byte array
+    pub fn to_bytes(&self) -> Result<Vec<u8>, UCFBError> {
+        let mut buffer: Vec<u8> = vec![];
+        self.write(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Get the data of a chunk converted to another byte order, see `Chunk::set_endianness`
+fn convert_endianness(chunk: &ChunkRef, endianness: Endianness) -> Result<Vec<u8>, UCFBError> {
+    let convert_u32 = |bytes: &[u8]| match chunk.header.endianness.read_u32_from(bytes) {
+        Some(v) => endianness.write_u32(v).to_vec(),
+        None => bytes.to_vec(),
+    };
+    if !chunk.header.is_known_container() || chunk.header.endianness == endianness {
+        return Ok(chunk.data.to_vec());
+    }
+    let is_class = matches!(
+        chunk.header.name,
+        FourCC::GAME_OBJECT_CLASS
+            | FourCC::EXPLOSION_CLASS
+            | FourCC::ORDNANCE_CLASS
+            | FourCC::WEAPON_CLASS
+    );
+    // Levels store their name hash and size before their subchunks
+    let mut buffer: Vec<u8> = vec![];
+    for number in chunk.data[..chunk.header.subchunk_offset().min(chunk.data.len())].chunks(4) {
+        buffer.extend(convert_u32(number));
+    }
+    for subchunk in chunk.subchunks()? {
+        let data = if is_class && subchunk.header.name == FourCC::PROPERTY {
+            // Properties start with the hash of their name
+            let hash = subchunk.data.get(..4).unwrap_or(subchunk.data);
+            let mut data = convert_u32(hash);
+            data.extend_from_slice(subchunk.data.get(4..).unwrap_or(&[]));
+            data
+        } else {
+            convert_endianness(&subchunk, endianness)?
         };
+        let mut converted = Chunk::new(subchunk.header.name, data);
+        converted.header.endianness = endianness;
+        converted.write(&mut buffer)?;
     }
-    Ok(())
+    Ok(buffer)
+}
+
+/// Try to figure out what the data stored in the chunks is and parse if possible
+///
+/// This uses the built-in decoders, use `DecoderRegistry::visit` to decode other chunks
+pub fn visit_chunks_from_vec(chunks: &mut [Chunk]) -> Result<(), VisitError> {
+    DecoderRegistry::default().visit(chunks)
 }
 
 impl UCFBFile {
@@ -268,36 +973,122 @@ impl UCFBFile {
             Ok(v) => v,
             Err(e) => return Err(UCFBError::IOError(e)),
         };
-        let mut buffer: Vec<u8> = vec![0; 8];
-        let header: UCFBHeader;
-        match le_file.read(&mut buffer) {
-            Err(e) => return Err(UCFBError::IOError(e)),
-            Ok(_) => {}
-        }
-        (_, header) = match parse_header(&mut buffer) {
-            Ok(v) => (v.0.to_vec(), v.1),
-            Err(_) => return Err(UCFBError::NotAUCFBFile),
-        };
-        if header.size < 8 {
-            return Err(UCFBError::FileTooSmall);
-        } else if header.size == 8 {
-            // The file is empty
-            return Ok(UCFBFile {
-                header: header,
-                chunks: vec![],
-            });
-        }
-        if le_file.metadata().unwrap().len() - 8 != header.size.into() {
+        Self::from_reader(&mut le_file)
+    }
+    /// Create a new object from a byte array
+    pub fn from_bytes(buffer: &[u8]) -> Result<Self, UCFBError> {
+        Self::from_reader(&mut Cursor::new(buffer))
+    }
+    /// Create a new object from any seekable reader, starting at its current position
+    ///
+    /// The byte order is detected from the header, see `Endianness::detect`
+    pub fn from_reader<R: Read + Seek>(reader: &mut R) -> Result<Self, UCFBError> {
+        let start = reader.stream_position().map_err(UCFBError::IOError)?;
+        let mut buffer = [0; 8];
+        reader
+            .read_exact(&mut buffer)
+            .map_err(|_| UCFBError::FileTooSmall)?;
+        let length = reader.seek(SeekFrom::End(0)).map_err(UCFBError::IOError)?;
+        let endianness = Endianness::detect(&buffer, length.saturating_sub(start))
+            .ok_or(UCFBError::FileTooSmall)?;
+        reader
+            .seek(SeekFrom::Start(start))
+            .map_err(UCFBError::IOError)?;
+        Self::from_reader_with(reader, endianness)
+    }
+    /// Create a new object from any seekable reader holding a file stored in the given byte order
+    pub fn from_reader_with<R: Read + Seek>(
+        reader: &mut R,
+        endianness: Endianness,
+    ) -> Result<Self, UCFBError> {
+        let start = reader.stream_position().map_err(UCFBError::IOError)?;
+        let mut buffer = [0; 8];
+        reader
+            .read_exact(&mut buffer)
+            .map_err(|_| UCFBError::FileTooSmall)?;
+        let (_, header) = parse_header(&buffer, endianness).map_err(|_| UCFBError::NotAUCFBFile)?;
+        // The size is the size of the chunks after the header, like in `UCFBFileRef::from_bytes_with`
+        let length = reader.seek(SeekFrom::End(0)).map_err(UCFBError::IOError)?;
+        if length.saturating_sub(start + 8) != u64::from(header.size) {
             return Err(UCFBError::WrongHeaderSize);
         }
+        reader
+            .seek(SeekFrom::Start(start + 8))
+            .map_err(UCFBError::IOError)?;
 
         Ok(UCFBFile {
-            header: header,
-            chunks: extract_chunks(&mut le_file)?,
+            header,
+            chunks: extract_chunks_in(reader, &ChunkLocation::root(start), endianness)?,
+            game_version: None,
         })
     }
+    /// Get the game the file was made for, detecting it and storing it the first time
+    pub fn game_version(&mut self) -> GameVersion {
+        match self.game_version {
+            Some(v) => v,
+            None => {
+                let game_version = self.detect_game_version();
+                self.game_version = Some(game_version);
+                game_version
+            }
+        }
+    }
     /// Try to figure out what the data stored in the chunks is and parse if possible
+    ///
+    /// The game the file comes from is detected and passed to the decoders, see `GameVersion::detect`
     pub fn visit_chunks(&mut self) -> Result<(), VisitError> {
-        visit_chunks_from_vec(&mut self.chunks)
+        let mut registry = DecoderRegistry::default();
+        registry.set_game_version(self.game_version());
+        registry.visit(&mut self.chunks)
+    }
+    /// Decode the chunks with the decoders in the registry
+    pub fn visit_chunks_with(&mut self, registry: &DecoderRegistry) -> Result<(), VisitError> {
+        registry.visit(&mut self.chunks)
+    }
+    /// Decode every chunk that can be decoded, leaving the rest undeciphered
+    ///
+    /// Problems are attached to the chunks they were found in and returned as a report
+    pub fn visit_chunks_lenient(&mut self) -> VisitReport {
+        let mut registry = DecoderRegistry::default();
+        registry.set_mode(VisitMode::Lenient);
+        registry.set_game_version(self.game_version());
+        // Lenient visitation never fails
+        let _ = registry.visit(&mut self.chunks);
+        self.report()
+    }
+    /// Decode the chunks with the built-in decoders, decoding sibling chunks in parallel
+    #[cfg(feature = "parallel")]
+    pub fn visit_chunks_parallel(&mut self) -> Result<(), VisitError> {
+        let mut registry = DecoderRegistry::default();
+        registry.set_parallel(true);
+        registry.set_game_version(self.game_version());
+        registry.visit(&mut self.chunks)
+    }
+    /// Set the locations of all chunks to where they are when the file is written out
+    ///
+    /// Edits on the file do this, call it after editing a level in the file directly
+    pub fn update_locations(&mut self) {
+        update_locations(&mut self.chunks, &ChunkLocation::root(0), 8);
+    }
+    /// Collect the diagnostics attached to all chunks in the file
+    pub fn report(&self) -> VisitReport {
+        VisitReport::from_chunks(&self.chunks)
+    }
+    /// Write the ucfb file, recomputing the sizes of all chunks
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), UCFBError> {
+        let body = chunks_to_bytes(&self.chunks)?;
+        write_chunk_header(writer, FourCC::UCFB, body.len(), self.header.endianness)?;
+        writer.write_all(&body).map_err(UCFBError::IOError)
+    }
+    /// Serialize the ucfb file to a byte array
+    pub fn to_bytes(&self) -> Result<Vec<u8>, UCFBError> {
+        let mut buffer: Vec<u8> = vec![];
+        self.write(&mut buffer)?;
+        Ok(buffer)
+    }
+    /// Write the ucfb file to disk
+    pub fn save(&self, file_name: String) -> Result<(), UCFBError> {
+        let mut le_file = File::create(file_name).map_err(UCFBError::IOError)?;
+        self.write(&mut le_file)
     }
 }
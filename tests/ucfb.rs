@@ -0,0 +1,1155 @@
+use libzeroengine::crack::{HashHarvest, HashKind, Variants};
+use libzeroengine::decoder::DecoderRegistry;
+use libzeroengine::dedup::ContentIndex;
+use libzeroengine::diagnostic::{describe_error, DiagnosticKind};
+use libzeroengine::diff::{diff, Detail};
+use libzeroengine::edit::EditError;
+#[cfg(feature = "serialize")]
+use libzeroengine::export::to_json_with_external_bodies;
+use libzeroengine::fourcc::FourCC;
+use libzeroengine::game::GameVersion;
+use libzeroengine::hash::{fnv1a, HashDictionary, NameHash};
+use libzeroengine::pack::{ChunkSource, Manifest, PackError};
+use libzeroengine::prop::{PropertyContainer, PropertyKey};
+use libzeroengine::stream::{ChunkEntry, ChunkStream};
+use libzeroengine::ucfb::{
+    extract_chunks_bytearray, Chunk, ChunkHeader, ChunkLocation, DecipheredChunk, Endianness,
+    UCFBError, UCFBFile, UCFBFileRef, UCFBHeader, MAX_NESTING_DEPTH,
+};
+use std::sync::Arc;
+
+fn chunk_bytes(name: &[u8; 4], body: &[u8]) -> Vec<u8> {
+    let mut bytes = name.to_vec();
+    bytes.extend_from_slice(&(body.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(body);
+    bytes.resize(bytes.len() + (4 - body.len() % 4) % 4, 0);
+    bytes
+}
+
+fn level_chunk_bytes(name_hash: u32, chunks: &[u8]) -> Vec<u8> {
+    let mut body = name_hash.to_le_bytes().to_vec();
+    body.extend((chunks.len() as u32).to_le_bytes());
+    body.extend(chunks);
+    chunk_bytes(b"lvl_", &body)
+}
+
+fn synthetic_file() -> Vec<u8> {
+    let mut inner = chunk_bytes(b"NAME", b"inner\0");
+    inner.extend(chunk_bytes(b"DATA", &[1, 2, 3, 4, 5, 6, 7]));
+    let mut body = chunk_bytes(b"ucfb", &inner);
+    body.extend(chunk_bytes(b"RAW_", &[0xAA; 9]));
+    body.extend(chunk_bytes(b"\x5C\xD9\xA0\x80", &[0x80, 0xFF]));
+    chunk_bytes(b"ucfb", &body)
+}
+
+#[test]
+fn round_trip_is_byte_identical() {
+    let original = synthetic_file();
+    let file = UCFBFile::from_bytes(&original).unwrap();
+
+    assert_eq!(file.to_bytes().unwrap(), original);
+}
+
+#[test]
+fn round_trip_after_visiting_is_byte_identical() {
+    let original = synthetic_file();
+    let mut file = UCFBFile::from_bytes(&original).unwrap();
+    file.visit_chunks().unwrap();
+
+    assert_eq!(file.to_bytes().unwrap(), original);
+}
+
+#[test]
+fn empty_files_and_chunks_round_trip() {
+    for original in [
+        chunk_bytes(b"ucfb", &[]),
+        chunk_bytes(b"ucfb", &chunk_bytes(b"NAME", &[])),
+    ] {
+        let file = UCFBFile::from_bytes(&original).unwrap();
+        let borrowed = UCFBFileRef::from_bytes(&original).unwrap();
+        assert_eq!(file.chunks.len(), borrowed.chunks.len());
+        assert_eq!(file.to_bytes().unwrap(), original);
+    }
+    let file = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &chunk_bytes(b"NAME", &[]))).unwrap();
+    assert_eq!(file.chunks[0].header.name, FourCC::NAME);
+    assert!(file.chunks[0].data.is_empty());
+}
+
+#[test]
+fn decoded_levels_share_the_data_of_the_file() {
+    let mut file = UCFBFile::from_bytes(&level_file()).unwrap();
+    // The textures in the level are incomplete
+    file.visit_chunks_lenient();
+
+    let outer = file.chunks[0].data.as_ptr_range();
+    let level = match &file.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::Level(v)) => v,
+        v => panic!("level wasn't decoded: {:?}", v.is_some()),
+    };
+    for chunk in &level.chunks {
+        assert!(outer.contains(&chunk.data.as_ptr()));
+    }
+    assert_eq!(level.chunks[0].data, chunk_bytes(b"NAME", b"first\0"));
+}
+
+#[test]
+fn sizes_and_padding_are_recomputed() {
+    let file = UCFBFile {
+        header: UCFBHeader {
+            size: 0,
+            endianness: Endianness::Little,
+        },
+        chunks: vec![
+            Chunk {
+                header: ChunkHeader {
+                    name: FourCC::NAME,
+                    size: 0,
+                    endianness: Endianness::Little,
+                },
+                location: ChunkLocation::default(),
+                data: b"abcde".to_vec().into(),
+                deciphered_chunk: None,
+                diagnostics: vec![],
+            },
+            Chunk {
+                header: ChunkHeader {
+                    name: FourCC::BODY,
+                    size: 100,
+                    endianness: Endianness::Little,
+                },
+                location: ChunkLocation::default(),
+                data: vec![1, 2, 3, 4].into(),
+                deciphered_chunk: None,
+                diagnostics: vec![],
+            },
+        ],
+        game_version: None,
+    };
+    let mut expected = chunk_bytes(b"NAME", b"abcde");
+    expected.extend(chunk_bytes(b"BODY", &[1, 2, 3, 4]));
+    let expected = chunk_bytes(b"ucfb", &expected);
+
+    let bytes = file.to_bytes().unwrap();
+    assert_eq!(bytes, expected);
+
+    let reparsed = UCFBFile::from_bytes(&bytes).unwrap();
+    assert_eq!(reparsed.header.size, 28);
+    assert_eq!(reparsed.chunks[0].header.size, 5);
+    assert_eq!(reparsed.chunks[0].data, b"abcde");
+    assert_eq!(reparsed.chunks[1].header.size, 4);
+}
+
+#[test]
+fn chunk_names_are_parsed_and_displayed() {
+    assert_eq!("lvl_".parse::<FourCC>().unwrap(), FourCC::LEVEL);
+    assert_eq!("0x60701F2F".parse::<FourCC>().unwrap(), FourCC::MOVIE);
+    assert!("TOOLONG".parse::<FourCC>().is_err());
+    assert_eq!(FourCC::MOVIE.to_string(), "0x60701F2F");
+    assert_eq!(FourCC::AUDIO_DATA.known_name(), Some("audio data"));
+
+    let mut file = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    file.visit_chunks().unwrap();
+    // Bytes above 0x7F must survive as-is
+    assert_eq!(file.chunks[2].header.name, FourCC([0x5C, 0xD9, 0xA0, 0x80]));
+    assert_eq!(file.chunks[2].header.name.to_string(), "0x5CD9A080");
+}
+
+#[test]
+fn parses_from_reader_at_offset() {
+    let mut stream = vec![0xEE; 4];
+    stream.extend(synthetic_file());
+    let mut reader = std::io::Cursor::new(stream);
+    reader.set_position(4);
+    let file = UCFBFile::from_reader(&mut reader).unwrap();
+
+    assert_eq!(file.chunks.len(), 3);
+    assert_eq!(file.to_bytes().unwrap(), synthetic_file());
+
+    // Padding is relative to the chunks, not to the stream
+    let mut stream = vec![0xEE; 2];
+    stream.extend(synthetic_file());
+    let mut reader = std::io::Cursor::new(stream);
+    reader.set_position(2);
+    let file = UCFBFile::from_reader(&mut reader).unwrap();
+
+    assert_eq!(file.chunks.len(), 3);
+    assert_eq!(
+        file.chunks[2].location.offset,
+        file.chunks[1].location.offset + 8 + 12
+    );
+    assert_eq!(file.to_bytes().unwrap(), synthetic_file());
+}
+
+#[test]
+fn stream_lists_headers_and_reads_bodies_on_demand() {
+    let mut level_body = vec![0x11; 8];
+    level_body.extend(chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"tex\0")));
+    let mut body = chunk_bytes(b"lvl_", &level_body);
+    body.extend(chunk_bytes(b"RAW_", &[0xAA; 9]));
+    let mut reader = std::io::Cursor::new(chunk_bytes(b"ucfb", &body));
+
+    let mut stream = ChunkStream::new(&mut reader).unwrap();
+    let level = stream.next().unwrap().unwrap();
+    assert_eq!(level.header.name, FourCC::LEVEL);
+    assert_eq!(level.location.offset, 8);
+    assert_eq!(level.location.path, "ucfb/lvl_[0]");
+    let textures: Vec<ChunkEntry> = stream.subchunks(&level).collect::<Result<_, _>>().unwrap();
+    assert_eq!(textures.len(), 1);
+    assert_eq!(textures[0].header.name, FourCC::TEXTURE);
+    assert_eq!(textures[0].location.offset, 24);
+    assert_eq!(textures[0].location.path, "ucfb/lvl_[0]/tex_[0]");
+    assert_eq!(textures[0].location.depth, 2);
+    let raw = stream.next().unwrap().unwrap();
+    assert_eq!(stream.read_body(&raw).unwrap(), vec![0xAA; 9]);
+    assert!(stream.next().is_none());
+}
+
+#[test]
+fn locations_are_recorded() {
+    let mut file = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    file.visit_chunks().unwrap();
+
+    let raw = &file.chunks[2].location;
+    assert_eq!(raw.offset, 68);
+    assert_eq!(raw.depth, 1);
+    assert_eq!(raw.path, "ucfb/0x5CD9A080[0]");
+    let nested = match &file.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::UCFB(v)) => &v.chunks[1].location,
+        _ => panic!("embedded ucfb was not visited"),
+    };
+    assert_eq!(nested.offset, 32);
+    assert_eq!(nested.depth, 2);
+    assert_eq!(nested.path, "ucfb/ucfb[0]/DATA[0]");
+}
+
+#[test]
+fn custom_decoders_can_be_registered() {
+    let mut registry = DecoderRegistry::default();
+    registry.register(FourCC(*b"RAW_"), |chunk: &Chunk, _: &DecoderRegistry| {
+        Ok(DecipheredChunk::Custom(Arc::new(chunk.data.len())))
+    });
+    let mut file = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    file.visit_chunks_with(&registry).unwrap();
+
+    let decoded = file.chunks[1].deciphered_chunk.as_ref().unwrap();
+    assert_eq!(decoded.custom::<usize>(), Some(&9));
+    assert!(matches!(
+        file.chunks[0].deciphered_chunk,
+        Some(DecipheredChunk::UCFB(_))
+    ));
+}
+
+#[test]
+fn lenient_visitation_keeps_going_after_corrupt_chunks() {
+    let mut level_body = vec![0; 8];
+    level_body.extend(chunk_bytes(b"scr_", &chunk_bytes(b"NAME", b"broken\0")));
+    level_body.extend(chunk_bytes(b"lvl_", &[0; 8]));
+    let mut body = chunk_bytes(b"lvl_", &level_body);
+    body.extend(chunk_bytes(b"ucfb", &[]));
+    let bytes = chunk_bytes(b"ucfb", &body);
+
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    let error = file.visit_chunks().unwrap_err();
+    assert_eq!(error.location().unwrap().path, "ucfb/lvl_[0]/scr_[0]");
+
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    let report = file.visit_chunks_lenient();
+    assert_eq!(report.diagnostics.len(), 1);
+    let diagnostic = &report.diagnostics[0];
+    assert_eq!(diagnostic.kind, DiagnosticKind::DecodeFailed);
+    assert_eq!(diagnostic.location.path, "ucfb/lvl_[0]/scr_[0]");
+    assert_eq!(diagnostic.location.offset, 24);
+    let level = match &file.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::Level(v)) => v,
+        _ => panic!("level was not visited"),
+    };
+    assert!(level.chunks[0].deciphered_chunk.is_none());
+    assert!(matches!(
+        level.chunks[1].deciphered_chunk,
+        Some(DecipheredChunk::Level(_))
+    ));
+    assert!(matches!(
+        file.chunks[1].deciphered_chunk,
+        Some(DecipheredChunk::UCFB(_))
+    ));
+}
+
+#[test]
+fn deeply_nested_files_fail_without_overflowing_the_stack() {
+    // 10000 ucfb chunks each holding the next one
+    let depth = 10_000;
+    let mut bytes = Vec::with_capacity(8 * (depth + 1));
+    for i in 0..=depth {
+        bytes.extend(b"ucfb");
+        bytes.extend((8 * (depth - i) as u32).to_le_bytes());
+    }
+
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    assert_eq!(file.detect_game_version(), GameVersion::Unknown);
+    let error = file.visit_chunks().unwrap_err();
+    assert!(
+        describe_error(&error).contains(&format!("nested more than {} deep", MAX_NESTING_DEPTH))
+    );
+    let report = file.visit_chunks_lenient();
+    assert_eq!(report.diagnostics.len(), 1);
+    assert_eq!(report.diagnostics[0].location.depth, MAX_NESTING_DEPTH);
+    // The innermost chunk that is kept can't be decoded
+    assert_eq!(file.select("**/ucfb").unwrap().len(), MAX_NESTING_DEPTH);
+
+    fn first_error<R: std::io::Read + std::io::Seek>(stream: &mut ChunkStream<R>) -> UCFBError {
+        match stream.next().unwrap() {
+            Ok(entry) => first_error(&mut stream.subchunks(&entry)),
+            Err(e) => e,
+        }
+    }
+    let mut reader = std::io::Cursor::new(&bytes);
+    let error = first_error(&mut ChunkStream::new(&mut reader).unwrap());
+    assert!(matches!(error, UCFBError::TooDeeplyNested(_)));
+    assert!(UCFBFileRef::from_bytes(&bytes).is_ok());
+}
+
+#[test]
+fn errors_describe_their_causes() {
+    let bytes = chunk_bytes(
+        b"ucfb",
+        &chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"a\0")),
+    );
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    let error = file.visit_chunks().unwrap_err();
+
+    assert_eq!(
+        describe_error(&error),
+        "failed to decode chunk at 0x8, ucfb/tex_[0]: failed to decode texture: texture is corrupt"
+    );
+
+    // A chunk header cut off by the end of the file fails instead of ending the chunks early
+    let mut body = chunk_bytes(b"NAME", &[]);
+    body.extend(b"DATA");
+    let error = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &body)).unwrap_err();
+    assert!(matches!(error, UCFBError::ReadError(0x10, _)));
+    assert!(describe_error(&error).starts_with("failed to read the chunk at 0x10: "));
+    assert!(UCFBFileRef::from_bytes(&chunk_bytes(b"ucfb", &body)).is_err());
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn parallel_visitation_matches_sequential() {
+    let mut sequential = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    sequential.visit_chunks().unwrap();
+    let mut parallel = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    parallel.visit_chunks_parallel().unwrap();
+
+    assert_eq!(format!("{:?}", parallel), format!("{:?}", sequential));
+
+    // A corrupt texture among many chunks fails strict parallel visitation the same way
+    let mut body = chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"a\0"));
+    for _ in 0..100 {
+        body.extend(script_chunk_bytes("setup", b"\x1bLua\x50\0"));
+    }
+    let bytes = chunk_bytes(b"ucfb", &body);
+    let mut sequential = UCFBFile::from_bytes(&bytes).unwrap();
+    let mut parallel = UCFBFile::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        describe_error(&parallel.visit_chunks_parallel().unwrap_err()),
+        describe_error(&sequential.visit_chunks().unwrap_err())
+    );
+}
+
+fn level_file() -> Vec<u8> {
+    let mut class = chunk_bytes(b"BASE", b"soldier\0");
+    class.extend(chunk_bytes(b"TYPE", b"rep_inf_ep3_rifleman\0"));
+    class.extend(chunk_bytes(b"PROP", b"\x4A\x6B\xC8\x47rep_inf_trooper\0"));
+    let mut level_body = vec![0; 8];
+    level_body.extend(chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"first\0")));
+    level_body.extend(chunk_bytes(b"entc", &class));
+    level_body.extend(chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"second\0")));
+    chunk_bytes(b"ucfb", &chunk_bytes(b"lvl_", &level_body))
+}
+
+#[test]
+fn queries_select_chunks_by_path_and_predicate() {
+    let bytes = level_file();
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks_lenient();
+
+    let textures = file.select("ucfb/lvl_/tex_").unwrap();
+    assert_eq!(textures.len(), 2);
+    assert_eq!(textures[1].location.path, "ucfb/lvl_[0]/tex_[1]");
+    let path = &textures[1].location.path;
+    assert_eq!(file.select(path).unwrap()[0].location, textures[1].location);
+
+    let classes = file.select("**/entc[TYPE=rep_inf_ep3_rifleman]").unwrap();
+    assert_eq!(classes.len(), 1);
+    assert!(matches!(
+        classes[0].deciphered_chunk,
+        Some(DecipheredChunk::PropertyContainer(_))
+    ));
+    assert_eq!(
+        file.select("**/*[GeometryName=rep_inf_trooper]")
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(file.select("**/entc[TYPE=other]").unwrap().is_empty());
+    assert_eq!(
+        file.select("**/entc[TYPE=rep_inf_ep3_rifleman][GeometryName=rep_inf_trooper]")
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(file
+        .select("**/entc[TYPE=rep_inf_ep3_rifleman][GeometryName=other]")
+        .unwrap()
+        .is_empty());
+    assert!(file.select("ucfb/lvl_[").is_err());
+    // Chunks reached through both ** are only returned once
+    assert_eq!(file.select("**/lvl_/**/tex_").unwrap().len(), 2);
+    assert_eq!(
+        file.select("**/*/**").unwrap().len(),
+        file.select("**/*/*").unwrap().len()
+    );
+
+    // Borrowed files are searched inside every known container
+    let file = UCFBFileRef::from_bytes(&bytes).unwrap();
+    let names = file.select("**/tex_/NAME").unwrap();
+    assert_eq!(names.len(), 2);
+    assert_eq!(names[0].data, b"first\0");
+    assert_eq!(file.select("**/**/NAME").unwrap().len(), names.len());
+}
+
+#[test]
+fn edits_rebuild_the_chunks_containing_them() {
+    let mut file = UCFBFile::from_bytes(&level_file()).unwrap();
+    file.visit_chunks_lenient();
+
+    let texture = Chunk::new(FourCC::TEXTURE, chunk_bytes(b"NAME", b"replaced\0"));
+    let old = file.replace("ucfb/lvl_/tex_[1]", texture).unwrap();
+    assert_eq!(old.data, chunk_bytes(b"NAME", b"second\0"));
+    file.remove("**/entc").unwrap();
+    file.insert_before("ucfb/lvl_", Chunk::new(FourCC(*b"RAW_"), vec![1, 2, 3]))
+        .unwrap();
+    file.rename("ucfb/lvl_/tex_[0]", FourCC(*b"tex2")).unwrap();
+    assert!(matches!(
+        file.remove("**/tex_[7]"),
+        Err(EditError::NotFound(_))
+    ));
+    assert!(matches!(
+        file.remove("ucfb/lvl_/*"),
+        Err(EditError::Ambiguous(_, 2))
+    ));
+
+    let mut subchunks = chunk_bytes(b"tex2", &chunk_bytes(b"NAME", b"first\0"));
+    subchunks.extend(chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"replaced\0")));
+    let level = level_chunk_bytes(0, &subchunks);
+    let mut expected = chunk_bytes(b"RAW_", &[1, 2, 3]);
+    expected.extend(&level);
+    let expected = chunk_bytes(b"ucfb", &expected);
+
+    let bytes = file.to_bytes().unwrap();
+    assert_eq!(bytes, expected);
+    assert_eq!(file.header.size as usize, bytes.len() - 8);
+    assert_eq!(file.chunks[1].data, level[8..]);
+}
+
+#[test]
+fn level_names_are_kept_and_resolved() {
+    // A level whose name is in the dictionary and one whose name isn't
+    let mut body = level_chunk_bytes(NameHash::new("tat2_conquest").0, &chunk_bytes(b"RAW_", &[]));
+    body.extend(level_chunk_bytes(NameHash::new("mym1_conquest").0, &[]));
+    let bytes = chunk_bytes(b"ucfb", &body);
+
+    let mut dictionary = HashDictionary::new();
+    dictionary.insert("tat2_conquest");
+    let mut registry = DecoderRegistry::default();
+    registry.set_dictionary(dictionary);
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks_with(&registry).unwrap();
+    let levels: Vec<_> = file.levels().collect();
+    assert_eq!(levels.len(), 2);
+    assert_eq!(levels[0].name.as_deref(), Some("tat2_conquest"));
+    assert_eq!(levels[1].name, None);
+    let unknown_hash = format!("{:#010X}", NameHash::new("mym1_conquest").0);
+    assert_eq!(levels[1].display_name(), unknown_hash);
+    assert_eq!(file.level("TAT2_Conquest").unwrap().chunks.len(), 1);
+    assert_eq!(file.level(&unknown_hash).unwrap().chunks.len(), 0);
+
+    // Queries compare names by hash like `level` does
+    let select = |query: &str| file.select(query).unwrap().len();
+    assert_eq!(select("ucfb/lvl_[name=tat2_conquest]"), 1);
+    assert_eq!(select("ucfb/lvl_[name=Tat2_Conquest]"), 1);
+    assert_eq!(select("ucfb/lvl_[name=MYM1_CONQUEST]"), 1);
+    assert_eq!(select(&format!("ucfb/lvl_[name={}]", unknown_hash)), 1);
+    assert_eq!(select("ucfb/lvl_[name=tat2_ctf]"), 0);
+    assert_eq!(file.to_bytes().unwrap(), bytes);
+}
+
+#[test]
+fn diffs_match_chunks_by_decoded_name() {
+    let mut old = UCFBFile::from_bytes(&level_file()).unwrap();
+    old.visit_chunks_lenient();
+
+    let mut class = chunk_bytes(b"BASE", b"soldier\0");
+    class.extend(chunk_bytes(b"TYPE", b"rep_inf_ep3_rifleman\0"));
+    class.extend(chunk_bytes(b"PROP", b"\x4A\x6B\xC8\x47rep_inf_sniper\0"));
+    let mut level_body = vec![0; 8];
+    level_body.extend(chunk_bytes(b"entc", &class));
+    level_body.extend(chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"first\0")));
+    level_body.extend(chunk_bytes(b"tex_", &chunk_bytes(b"NAME", b"third\0")));
+    let mut new =
+        UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &chunk_bytes(b"lvl_", &level_body))).unwrap();
+    new.visit_chunks_lenient();
+
+    let changes = diff(&old, &new);
+    let lines: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+    // The textures fail to decode, so they are matched in order and the first one is unchanged
+    assert_eq!(
+        lines,
+        [
+            "~ ucfb/lvl_[0]/entc[0] (rep_inf_ep3_rifleman)",
+            "~ ucfb/lvl_[0]/tex_[1]",
+        ]
+    );
+    assert_eq!(
+        changes[0].details,
+        [Detail::PropertyChanged {
+            key: "GeometryName".to_string(),
+            old: "rep_inf_trooper".to_string(),
+            new: "rep_inf_sniper".to_string(),
+        }]
+    );
+    assert!(matches!(
+        changes[1].details[0],
+        Detail::Data {
+            first_difference: 4,
+            ..
+        }
+    ));
+    assert!(diff(&old, &old).is_empty());
+}
+
+fn big_endian_chunk_bytes(name: &[u8; 4], body: &[u8]) -> Vec<u8> {
+    let mut bytes = chunk_bytes(name, body);
+    bytes[4..8].copy_from_slice(&(body.len() as u32).to_be_bytes());
+    bytes
+}
+
+#[test]
+fn big_endian_files_are_detected_and_round_trip() {
+    let mut level_body = 0x1234ABCDu32.to_be_bytes().to_vec();
+    let texture = big_endian_chunk_bytes(b"tex_", &big_endian_chunk_bytes(b"NAME", b"a\0"));
+    level_body.extend((texture.len() as u32).to_be_bytes());
+    level_body.extend(texture);
+    let mut body = big_endian_chunk_bytes(b"lvl_", &level_body);
+    body.extend(big_endian_chunk_bytes(b"RAW_", &[0xAA; 9]));
+    let bytes = big_endian_chunk_bytes(b"ucfb", &body);
+
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    assert_eq!(file.header.endianness, Endianness::Big);
+    assert_eq!(file.chunks[1].header.size, 9);
+    file.visit_chunks_lenient();
+    assert_eq!(file.levels().next().unwrap().name_hash, 0x1234ABCD);
+    assert_eq!(file.select("ucfb/lvl_/tex_").unwrap().len(), 1);
+    assert_eq!(file.to_bytes().unwrap(), bytes);
+
+    let file = UCFBFileRef::from_bytes(&bytes).unwrap();
+    assert_eq!(file.select("**/NAME").unwrap()[0].data, b"a\0");
+    let mut reader = std::io::Cursor::new(&bytes);
+    let mut stream = ChunkStream::new(&mut reader).unwrap();
+    assert_eq!(stream.endianness(), Endianness::Big);
+    assert_eq!(stream.nth(1).unwrap().unwrap().header.size, 9);
+}
+
+#[test]
+fn inserted_chunks_take_the_byte_order_and_locations_of_the_file() {
+    let mut level_body = 0x1234ABCDu32.to_be_bytes().to_vec();
+    let texture = big_endian_chunk_bytes(b"tex_", &big_endian_chunk_bytes(b"NAME", b"a\0"));
+    level_body.extend((texture.len() as u32).to_be_bytes());
+    level_body.extend(texture);
+    let bytes = big_endian_chunk_bytes(b"ucfb", &big_endian_chunk_bytes(b"lvl_", &level_body));
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks_lenient();
+
+    let odf = "[GameObjectClass]\nClassParent = soldier\n[Properties]\nMaxHealth = 300\n";
+    let class = PropertyContainer::from_odf("rep_inf", odf).unwrap();
+    file.insert_after("ucfb/lvl_/tex_", class.to_chunk().unwrap())
+        .unwrap();
+    file.insert_before("ucfb/lvl_", Chunk::new(FourCC(*b"RAW_"), vec![1, 2, 3]))
+        .unwrap();
+
+    let mut read = UCFBFile::from_bytes(&file.to_bytes().unwrap()).unwrap();
+    read.visit_chunks_lenient();
+    let class = read.select("ucfb/lvl_/entc").unwrap()[0];
+    match &class.deciphered_chunk {
+        Some(DecipheredChunk::PropertyContainer(v)) => assert_eq!(v.get("MaxHealth"), Some("300")),
+        _ => panic!("inserted class was not decoded"),
+    }
+    let locations = |file: &UCFBFile| -> Vec<(u64, String)> {
+        file.select("**/*")
+            .unwrap()
+            .iter()
+            .map(|c| (c.location.offset, c.location.path.clone()))
+            .collect()
+    };
+    assert_eq!(locations(&file), locations(&read));
+    assert_eq!(file.select("**/entc").unwrap()[0].location.offset, 56);
+}
+
+#[test]
+fn truncated_files_are_read_as_little_endian() {
+    let bytes = synthetic_file();
+    let truncated = &bytes[..bytes.len() - 4];
+    let length = truncated.len() as u64;
+    assert_eq!(
+        Endianness::detect(truncated, length),
+        Some(Endianness::Little)
+    );
+    assert!(matches!(
+        UCFBFileRef::from_bytes(truncated),
+        Err(UCFBError::WrongHeaderSize)
+    ));
+
+    // The big endian reading of this size fits in the file, but doesn't match its length
+    let mut bytes = b"ucfb".to_vec();
+    bytes.extend(0x0100_0000u32.to_le_bytes());
+    bytes.extend(chunk_bytes(b"RAW_", &[1, 2, 3, 4]));
+    let length = bytes.len() as u64;
+    assert_eq!(Endianness::detect(&bytes, length), Some(Endianness::Little));
+    assert_eq!(Endianness::detect(&bytes[..4], length), None);
+
+    let big = big_endian_chunk_bytes(b"ucfb", &big_endian_chunk_bytes(b"RAW_", &[1, 2, 3, 4]));
+    let length = big.len() as u64;
+    assert_eq!(Endianness::detect(&big, length), Some(Endianness::Big));
+    assert_eq!(
+        Endianness::detect(&big, length - 4),
+        Some(Endianness::Little)
+    );
+}
+
+fn script_chunk_bytes(name: &str, bytecode: &[u8]) -> Vec<u8> {
+    let mut script = chunk_bytes(b"NAME", format!("{}\0", name).as_bytes());
+    script.extend(chunk_bytes(b"INFO", &[1]));
+    script.extend(chunk_bytes(b"BODY", bytecode));
+    chunk_bytes(b"scr_", &script)
+}
+
+#[test]
+fn game_version_is_detected_and_passed_to_decoders() {
+    let script = script_chunk_bytes("setup", b"\x1bLua\x40\0");
+    let mut body = level_chunk_bytes(0, &script);
+    body.extend(chunk_bytes(b"RAW_", &[0xAA; 9]));
+    let bytes = chunk_bytes(b"ucfb", &body);
+    assert_eq!(
+        UCFBFileRef::from_bytes(&bytes)
+            .unwrap()
+            .detect_game_version(),
+        GameVersion::Battlefront
+    );
+
+    let mut file = UCFBFile::from_bytes(&chunk_bytes(
+        b"ucfb",
+        &script_chunk_bytes("setup", b"\x1bLua\x50\0"),
+    ))
+    .unwrap();
+    assert_eq!(file.detect_game_version(), GameVersion::Battlefront2);
+    let mut registry = DecoderRegistry::default();
+    registry.set_game_version(file.detect_game_version());
+    registry.register(FourCC::SCRIPT, |_: &Chunk, registry: &DecoderRegistry| {
+        Ok(DecipheredChunk::Custom(Arc::new(registry.game_version())))
+    });
+    file.visit_chunks_with(&registry).unwrap();
+    let decoded = file.chunks[0].deciphered_chunk.as_ref().unwrap();
+    assert_eq!(
+        decoded.custom::<GameVersion>(),
+        Some(&GameVersion::Battlefront2)
+    );
+
+    let file = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    assert_eq!(file.detect_game_version(), GameVersion::Unknown);
+}
+
+#[test]
+fn game_version_is_detected_from_chunk_types_and_texture_layouts() {
+    let detect = |body: &[u8]| {
+        UCFBFileRef::from_bytes(&chunk_bytes(b"ucfb", body))
+            .unwrap()
+            .detect_game_version()
+    };
+    let texture = |info_size: usize| {
+        let mut format = chunk_bytes(b"INFO", &vec![0; info_size]);
+        format.extend(chunk_bytes(b"FACE", &[]));
+        let mut texture = chunk_bytes(b"NAME", b"sky\0");
+        texture.extend(chunk_bytes(b"INFO", &1u32.to_le_bytes()));
+        texture.extend(chunk_bytes(b"FMT_", &format));
+        chunk_bytes(b"tex_", &texture)
+    };
+    assert_eq!(detect(&texture(12)), GameVersion::Battlefront);
+    assert_eq!(detect(&texture(16)), GameVersion::Battlefront2);
+    assert_eq!(detect(&texture(20)), GameVersion::Unknown);
+    assert_eq!(
+        detect(&chunk_bytes(b"comb", b"combo")),
+        GameVersion::Battlefront2
+    );
+
+    // The first chunk that gives the game away is used, and is stored in the file
+    let mut body = script_chunk_bytes("setup", b"\x1bLua\x40\0");
+    body.extend(script_chunk_bytes("setup", b"\x1bLua\x50\0"));
+    let mut file = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &body)).unwrap();
+    assert_eq!(file.game_version, None);
+    let report = file.visit_chunks_lenient();
+    assert_eq!(file.game_version, Some(GameVersion::Battlefront));
+    // Scripts compiled for another game still decode
+    assert!(report.diagnostics.is_empty());
+    assert!(file.chunks[1].deciphered_chunk.is_some());
+
+    // A stored game isn't detected again
+    file.game_version = Some(GameVersion::Battlefront2);
+    file.visit_chunks_lenient();
+    assert_eq!(file.game_version(), GameVersion::Battlefront2);
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn chunk_trees_serialize_with_inline_or_external_bodies() {
+    let mut file = UCFBFile::from_bytes(&synthetic_file()).unwrap();
+    file.visit_chunks().unwrap();
+
+    let json = serde_json::to_value(&file).unwrap();
+    let chunks = &json["chunks"];
+    assert_eq!(chunks[0]["header"]["name"], "ucfb");
+    // The data of containers is rebuilt from their subchunks, so it's left out
+    assert!(chunks[0].get("data").is_none());
+    let inner = &chunks[0]["deciphered_chunk"]["UCFB"]["chunks"];
+    assert_eq!(inner[1]["location"]["path"], "ucfb/ucfb[0]/DATA[0]");
+    assert_eq!(chunks[1]["data"]["base64"], "qqqqqqqqqqqq");
+    assert_eq!(chunks[2]["header"]["name"], "0x5CD9A080");
+
+    let directory =
+        std::env::temp_dir().join(format!("libzeroengine-bodies-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let json = to_json_with_external_bodies(&file, &directory).unwrap();
+    let body = json["chunks"][1]["data"]["file"].as_str().unwrap();
+    assert_eq!(std::fs::read(directory.join(body)).unwrap(), [0xAA; 9]);
+
+    // Scripts hold their bytecode so their data is left out
+    let mut body = script_chunk_bytes("setup", b"\x1bLua\x50\0");
+    body.extend(class_chunk_bytes(
+        "rep_inf",
+        &[(b"\x1B\x1F\x97\x19", b"300\0")],
+    ));
+    let mut file = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &body)).unwrap();
+    file.visit_chunks().unwrap();
+    let json = serde_json::to_value(&file).unwrap();
+    assert!(json["chunks"][0].get("data").is_none());
+    let script = &json["chunks"][0]["deciphered_chunk"]["Script"];
+    assert_eq!(script["body"]["base64"], "G0x1YVA=");
+    assert!(json["chunks"][1].get("data").is_some());
+    let json = to_json_with_external_bodies(&file, &directory).unwrap();
+    let script = &json["chunks"][0]["deciphered_chunk"]["Script"];
+    assert_eq!(script["body"]["file"], "body_0.bin");
+    let properties = &json["chunks"][1]["deciphered_chunk"]["PropertyContainer"]["properties"];
+    assert_eq!(properties[0][0], "MaxHealth");
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+/// Rebuild a serialized chunk from its data, or from its subchunks if it is an embedded ucfb file
+#[cfg(feature = "serialize")]
+fn chunk_bytes_from_json(json: &serde_json::Value) -> Vec<u8> {
+    use base64::Engine;
+    let name: FourCC = json["header"]["name"].as_str().unwrap().parse().unwrap();
+    let body: Vec<u8> = match json.get("data") {
+        Some(data) => base64::engine::general_purpose::STANDARD
+            .decode(data["base64"].as_str().unwrap())
+            .unwrap(),
+        None => json["deciphered_chunk"]["UCFB"]["chunks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(chunk_bytes_from_json)
+            .collect(),
+    };
+    chunk_bytes(name.as_bytes(), &body)
+}
+
+#[cfg(feature = "serialize")]
+#[test]
+fn textures_and_movies_serialize_with_all_their_data() {
+    let mut info = 21u32.to_le_bytes().to_vec();
+    info.extend([1, 0, 1, 0, 1, 0, 1, 0, 0, 0, 0, 0]);
+    let mut mip_level = chunk_bytes(b"INFO", &[0; 8]);
+    mip_level.extend(chunk_bytes(b"BODY", &[0x11, 0x22, 0x33, 0x44]));
+    let mut format = chunk_bytes(b"INFO", &info);
+    format.extend(chunk_bytes(b"FACE", &chunk_bytes(b"LVL_", &mip_level)));
+    let mut texture = chunk_bytes(b"NAME", b"sky\0");
+    texture.extend(chunk_bytes(b"INFO", &1u32.to_le_bytes()));
+    texture.extend(chunk_bytes(b"FMT_", &format));
+    // The movie header and the padding after the bink file aren't kept by the decoded movie
+    let mut movie = vec![0xEE; 0x7F0];
+    movie.extend(b"BIKi");
+    movie.extend(4u32.to_le_bytes());
+    movie.extend([0x55; 16]);
+    let mut body = chunk_bytes(b"tex_", &texture);
+    body.extend(chunk_bytes(b"\x60\x70\x1F\x2F", &movie));
+    let bytes = chunk_bytes(b"ucfb", &chunk_bytes(b"ucfb", &body));
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks().unwrap();
+    let inner = match &file.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::UCFB(v)) => v,
+        _ => panic!("embedded ucfb was not visited"),
+    };
+    assert!(
+        matches!(&inner.chunks[0].deciphered_chunk, Some(DecipheredChunk::Texture(v)) if v.skipped_formats.is_empty())
+    );
+    assert!(matches!(
+        inner.chunks[1].deciphered_chunk,
+        Some(DecipheredChunk::Movie(_))
+    ));
+
+    let json = serde_json::to_value(&file).unwrap();
+    let chunks: Vec<u8> = json["chunks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(chunk_bytes_from_json)
+        .collect();
+    let rebuilt = chunk_bytes(b"ucfb", &chunks);
+    assert_eq!(rebuilt, bytes);
+    assert_eq!(
+        UCFBFile::from_bytes(&rebuilt).unwrap().to_bytes().unwrap(),
+        bytes
+    );
+}
+
+#[test]
+fn manifests_pack_files_and_unpacked_files_pack_back() {
+    let directory = std::env::temp_dir().join(format!("libzeroengine-pack-{}", std::process::id()));
+    std::fs::create_dir_all(directory.join("lvl")).unwrap();
+    std::fs::write(directory.join("lvl/setup.luac"), b"\x1bLua\x50code").unwrap();
+    std::fs::write(
+        directory.join("lvl/rep_inf.odf"),
+        "[GameObjectClass]\nClassLabel = soldier\n\n[Properties]\nMaxHealth = 300\nGeometryName = \"rep_inf\"\n",
+    )
+    .unwrap();
+    std::fs::write(directory.join("raw.bin"), [0xAA; 9]).unwrap();
+    let manifest: Manifest = "# A level\nlvl_ name=0x1234ABCD\n  scr_ script=lvl/setup.luac\n  entc odf=lvl/rep_inf.odf\nRAW_ file=raw.bin\n"
+        .parse()
+        .unwrap();
+    let packed = manifest.pack(&directory).unwrap();
+
+    let mut file = UCFBFile::from_bytes(&packed.to_bytes().unwrap()).unwrap();
+    file.visit_chunks().unwrap();
+    let level = file.level("0x1234ABCD").unwrap();
+    match &level.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::Script(v)) => {
+            assert_eq!(
+                (v.name.as_str(), v.info, v.lua_version()),
+                ("setup", 1, Some(0x50))
+            )
+        }
+        v => panic!("expected a script, found {:?}", v),
+    }
+    match &level.chunks[1].deciphered_chunk {
+        Some(DecipheredChunk::PropertyContainer(v)) => {
+            assert_eq!(v.name, "rep_inf");
+            assert_eq!(v.class_label.as_deref(), Some("soldier"));
+            assert_eq!(v.get("MaxHealth"), Some("300"));
+            assert_eq!(v.get("GeometryName"), Some("rep_inf"));
+        }
+        v => panic!("expected a class, found {:?}", v),
+    }
+    assert_eq!(file.chunks[1].data, [0xAA; 9]);
+
+    let unpacked = Manifest::unpack(&file.chunks, &directory.join("unpacked")).unwrap();
+    let repacked: Manifest = unpacked.to_string().parse().unwrap();
+    let repacked = repacked.pack(&directory.join("unpacked")).unwrap();
+    assert_eq!(repacked.to_bytes().unwrap(), file.to_bytes().unwrap());
+
+    // Known level names with spaces are written as their hash
+    let hash = NameHash::new("spa1_Assault Paths");
+    assert_eq!(hash.name(), Some("spa1_Assault Paths"));
+    let manifest: Manifest = format!("lvl_ name={:#010X}\n  RAW_ file=raw.bin\n", hash.0)
+        .parse()
+        .unwrap();
+    let written = manifest.to_string();
+    assert!(!written.contains("Assault"));
+    let read: Manifest = written.parse().unwrap();
+    assert_eq!(read.chunks[0].source, ChunkSource::Level(hash.0));
+    assert_eq!(read.chunks[0].children.len(), 1);
+
+    assert!(matches!(
+        "lvl_ file=a odf=b".parse::<Manifest>(),
+        Err(PackError::InvalidManifest(1, _))
+    ));
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+fn class_chunk_bytes(name: &str, properties: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+    let mut class = chunk_bytes(b"BASE", b"soldier\0");
+    class.extend(chunk_bytes(b"TYPE", format!("{}\0", name).as_bytes()));
+    for (hash, value) in properties {
+        class.extend(chunk_bytes(b"PROP", &[&hash[..], value].concat()));
+    }
+    chunk_bytes(b"entc", &class)
+}
+
+#[test]
+fn unpacked_files_stay_in_their_directory_and_pack_back() {
+    let max_health: (&[u8; 4], &[u8]) = (b"\x1B\x1F\x97\x19", b"300\0");
+    let more_health: (&[u8; 4], &[u8]) = (b"\x1B\x1F\x97\x19", b"400\0");
+    let geometry: (&[u8; 4], &[u8]) = (b"\x4A\x6B\xC8\x47", b"rep_inf\0");
+    let mut body = class_chunk_bytes("../evil", &[max_health]);
+    body.extend(class_chunk_bytes("rep_inf", &[max_health]));
+    body.extend(class_chunk_bytes("rep_inf", &[geometry]));
+    body.extend(class_chunk_bytes("rep inf", &[max_health]));
+    // Repeated keys are kept in order
+    body.extend(class_chunk_bytes(
+        "rep_hero",
+        &[geometry, max_health, more_health],
+    ));
+    // GeometryName is written in the class section, so this order can't be read back
+    body.extend(class_chunk_bytes("rep_ai", &[max_health, geometry]));
+    let mut file = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &body)).unwrap();
+    file.visit_chunks().unwrap();
+
+    let directory =
+        std::env::temp_dir().join(format!("libzeroengine-unpack-{}", std::process::id()));
+    let unpacked = Manifest::unpack(&file.chunks, &directory.join("unpacked")).unwrap();
+    let mut files: Vec<String> = std::fs::read_dir(directory.join("unpacked"))
+        .unwrap()
+        .map(|e| e.unwrap().file_name().into_string().unwrap())
+        .collect();
+    files.sort();
+    assert_eq!(
+        files,
+        [
+            "0.___evil.odf",
+            "1.rep_inf.odf",
+            "2.rep_inf.odf",
+            "3.entc.chunk",
+            "4.rep_hero.odf",
+            "5.entc.chunk"
+        ]
+    );
+    assert_eq!(std::fs::read_dir(&directory).unwrap().count(), 1);
+    let sources: Vec<String> = unpacked
+        .chunks
+        .iter()
+        .map(|e| match &e.source {
+            ChunkSource::Odf { name, .. } => name.clone(),
+            ChunkSource::File(path) => path.display().to_string(),
+            v => panic!("expected an odf or a file, found {:?}", v),
+        })
+        .collect();
+    assert_eq!(
+        sources,
+        [
+            "../evil",
+            "rep_inf",
+            "rep_inf",
+            "3.entc.chunk",
+            "rep_hero",
+            "5.entc.chunk"
+        ]
+    );
+
+    let repacked: Manifest = unpacked.to_string().parse().unwrap();
+    let repacked = repacked.pack(&directory.join("unpacked")).unwrap();
+    assert_eq!(repacked.to_bytes().unwrap(), file.to_bytes().unwrap());
+    std::fs::remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+fn duplicated_assets_are_found_across_files() {
+    let max_health: (&[u8; 4], &[u8]) = (b"\x1B\x1F\x97\x19", b"300\0");
+    let geometry: (&[u8; 4], &[u8]) = (b"\x4A\x6B\xC8\x47", b"rep_inf\0");
+    let mut first = script_chunk_bytes("setup", b"\x1bLua\x50\0");
+    first.extend(class_chunk_bytes("rep_inf", &[max_health, geometry]));
+    let first = chunk_bytes(b"ucfb", &level_chunk_bytes(0x1234ABCD, &first));
+    // The same class with its properties stored in another order
+    let mut second = class_chunk_bytes("rep_inf", &[geometry, max_health]);
+    second.extend(script_chunk_bytes("setup", b"\x1bLua\x40\0"));
+    let second = chunk_bytes(b"ucfb", &second);
+
+    let mut index = ContentIndex::new();
+    for (name, bytes) in [("first.lvl", &first), ("second.lvl", &second)] {
+        let mut file = UCFBFile::from_bytes(bytes).unwrap();
+        file.visit_chunks().unwrap();
+        index.add_file(name, &file);
+    }
+    let duplicates = index.duplicates();
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].name, FourCC::GAME_OBJECT_CLASS);
+    assert_eq!(duplicates[0].decoded_name.as_deref(), Some("rep_inf"));
+    assert_eq!(duplicates[0].files(), ["first.lvl", "second.lvl"]);
+    assert_eq!(duplicates[0].occurrences[0].levels, ["0x1234ABCD"]);
+    assert_eq!(duplicates[0].wasted_bytes(), duplicates[0].size);
+}
+
+#[test]
+fn names_hash_like_the_engine() {
+    // Entries of the table of known names, stored as little endian bytes
+    for (bytes, name) in [
+        (*b"\x55\xB2\x1A\x1C", "--AttachOdf"),
+        (*b"\x4A\x6B\xC8\x47", "GeometryName"),
+        (*b"\x1B\x1F\x97\x19", "MaxHealth"),
+        (*b"\xB5\x1F\xCD\x9C", "soldier"),
+    ] {
+        let hash = NameHash::new(name);
+        assert_eq!(hash, NameHash::from_le_bytes(bytes));
+        assert_eq!(hash.name(), Some(name));
+        assert_eq!(hash.to_string(), name);
+    }
+    assert_eq!(NameHash::new("MAXHEALTH"), NameHash::new("maxhealth"));
+    assert_eq!(fnv1a(b""), 0x811C9DC5);
+    assert_eq!(
+        "0x1234ABCD".parse::<NameHash>().unwrap(),
+        NameHash(0x1234ABCD)
+    );
+    assert_eq!(NameHash(0x1234ABCD).to_string(), "0x1234ABCD");
+
+    let level = level_chunk_bytes(NameHash::new("my_mod_level").0, &[]);
+    let mut file = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &level)).unwrap();
+    file.visit_chunks().unwrap();
+    assert!(file.level("My_Mod_Level").is_some());
+}
+
+#[test]
+fn names_loaded_into_a_dictionary_are_used_by_decoders() {
+    let custom = NameHash::new("MyModRegenRate").to_le_bytes();
+    let bytes = chunk_bytes(b"ucfb", &class_chunk_bytes("rep_inf", &[(&custom, b"5\0")]));
+
+    // The name isn't built in, so only its hash is known
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks().unwrap();
+    match &file.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::PropertyContainer(v)) => assert_eq!(v.get("MyModRegenRate"), None),
+        v => panic!("class wasn't decoded: {:?}", v.is_some()),
+    }
+
+    let mut dictionary = HashDictionary::new();
+    let count = dictionary.load_str("# mod names\n\"MyModRegenRate\"\n\nMaxHealth\n");
+    assert_eq!(count, 2);
+    assert_eq!(dictionary.added_names().count(), 1);
+    assert_eq!(
+        dictionary.get(NameHash::new("maxhealth")),
+        Some("MaxHealth")
+    );
+    let mut registry = DecoderRegistry::default();
+    registry.set_dictionary(dictionary);
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks_with(&registry).unwrap();
+    match &file.chunks[0].deciphered_chunk {
+        Some(DecipheredChunk::PropertyContainer(v)) => {
+            assert_eq!(v.get("MyModRegenRate"), Some("5"))
+        }
+        v => panic!("class wasn't decoded: {:?}", v.is_some()),
+    }
+}
+
+#[test]
+fn unknown_properties_are_kept_through_odf_files() {
+    let max_health: (&[u8; 4], &[u8]) = (b"\x1B\x1F\x97\x19", b"300\0");
+    let unknown: (&[u8; 4], &[u8]) = (b"\x55\xB2\x1A\x10", b"modded\0");
+    let bytes = class_chunk_bytes("rep_inf", &[max_health, unknown]);
+    let chunk = extract_chunks_bytearray(&bytes).unwrap().remove(0);
+    let class = PropertyContainer::from_chunk(chunk.clone()).unwrap();
+    assert_eq!(class.get("MaxHealth"), Some("300"));
+    assert_eq!(
+        class.properties[1],
+        (PropertyKey::Unknown(0x101AB255), "modded".to_string())
+    );
+
+    let odf = class.get_odf();
+    assert!(odf.contains("\n0x101AB255 = \"modded\"\n"));
+    let read = PropertyContainer::from_odf(&class.name, &odf).unwrap();
+    assert_eq!(read.properties, class.properties);
+    assert_eq!(read.to_chunk().unwrap().data, chunk.data);
+
+    // Classes without properties read back from the chunks they are packed to
+    let odf = "[GameObjectClass]\nClassLabel = soldier\n";
+    let chunk = PropertyContainer::from_odf("rep_empty", odf)
+        .unwrap()
+        .to_chunk()
+        .unwrap();
+    let read = PropertyContainer::from_chunk(chunk.clone()).unwrap();
+    assert!(read.properties.is_empty());
+    assert_eq!(read.class_label.as_deref(), Some("soldier"));
+    assert_eq!(read.to_chunk().unwrap().data, chunk.data);
+
+    let geometry: (&[u8; 4], &[u8]) = (b"\x4A\x6B\xC8\x47", b"rep_inf\0");
+    let bytes = class_chunk_bytes("rep_inf", &[max_health, geometry]);
+    let mut class =
+        PropertyContainer::from_chunk(extract_chunks_bytearray(&bytes).unwrap().remove(0)).unwrap();
+    let odf = class.get_odf();
+    let (class_section, properties) = odf.split_once("[Properties]").unwrap();
+    assert!(class_section.contains("GeometryName = \"rep_inf\""));
+    assert!(!properties.contains("GeometryName"));
+    class.class_parent = None;
+    assert!(!class.get_odf().contains("ClassParent"));
+}
+
+#[test]
+fn unresolved_hashes_are_cracked_from_harvested_strings() {
+    let regen_rate = NameHash::new("MyModRegenRate2").to_le_bytes();
+    let geometry: (&[u8; 4], &[u8]) = (b"\x4A\x6B\xC8\x47", b"MyModRegenRate.msh\0");
+    // A string constant the way lua 5.0 bytecode stores it, after its length
+    let mut level_chunks = script_chunk_bytes("mymod", b"\x1bLua\x50\x0d\0\0\0MyMod_Level\0");
+    level_chunks.extend(class_chunk_bytes(
+        "rep_inf",
+        &[(&regen_rate, b"5\0"), geometry],
+    ));
+    let level = level_chunk_bytes(NameHash::new("mymod_level").0, &level_chunks);
+    let mut file = UCFBFile::from_bytes(&chunk_bytes(b"ucfb", &level)).unwrap();
+    file.visit_chunks().unwrap();
+
+    let mut harvest = HashHarvest::new();
+    harvest.add_file(&file);
+    let unresolved: Vec<HashKind> = harvest.unresolved().iter().map(|h| h.kind).collect();
+    assert_eq!(unresolved.len(), 2);
+    assert!(unresolved.contains(&HashKind::Level) && unresolved.contains(&HashKind::Property));
+    assert!(harvest.candidates().contains(&"MyModRegenRate"));
+
+    // Without numbered variants only the level name is found
+    let plain = Variants {
+        max_number: None,
+        ..Variants::default()
+    };
+    let cracked = harvest.crack(&plain);
+    assert_eq!(cracked.len(), 1);
+    assert_eq!(cracked[0].name, "MyMod_Level");
+    assert_eq!(cracked[0].kind, HashKind::Level);
+
+    let cracked = harvest.crack(&Variants::default());
+    let property = cracked
+        .iter()
+        .find(|c| c.kind == HashKind::Property)
+        .unwrap();
+    assert_eq!(property.hash, NameHash::new("MyModRegenRate2"));
+    assert_eq!(property.name, "MyModRegenRate2");
+}
+
+#[test]
+fn plain_names_win_over_numbered_names_with_the_same_hash() {
+    // These two names really do have the same hash
+    assert_eq!(
+        NameHash::new("MyModRatezdmlkeb"),
+        NameHash::new("MyModRatezdmlkeb4")
+    );
+    let rate = NameHash::new("MyModRatezdmlkeb").to_le_bytes();
+    let geometry: (&[u8; 4], &[u8]) = (b"\x4A\x6B\xC8\x47", b"MyModRate.msh\0");
+    let bytes = chunk_bytes(
+        b"ucfb",
+        &class_chunk_bytes("rep_inf", &[(&rate, b"5\0"), geometry]),
+    );
+    let mut file = UCFBFile::from_bytes(&bytes).unwrap();
+    file.visit_chunks().unwrap();
+
+    let mut harvest = HashHarvest::new();
+    harvest.add_file(&file);
+    let variants = Variants {
+        suffixes: vec!["zdmlkeb".to_string()],
+        ..Variants::default()
+    };
+    let cracked = harvest.crack(&variants);
+    assert_eq!(cracked.len(), 1);
+    assert_eq!(cracked[0].name, "MyModRatezdmlkeb");
+}